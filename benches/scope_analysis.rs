@@ -0,0 +1,72 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+
+//! Measures how much linting with two rules that use the scope analysis saves
+//! by sharing one analysis per file.
+//!
+//! Linting with each rule in its own `Linter` runs the scope analysis once per
+//! rule, as if it was computed eagerly by every rule. The time of linting
+//! without rules is subtracted from it, so that the file is parsed only once
+//! in both measurements.
+//!
+//! Run with `cargo bench --bench scope_analysis`.
+
+use deno_lint::linter::LinterBuilder;
+use deno_lint::rules::no_new_wrappers::NoNewWrappers;
+use deno_lint::rules::no_undef_init::NoUndefInit;
+use deno_lint::rules::LintRule;
+use std::time::{Duration, Instant};
+
+const RUNS: u32 = 20;
+
+const SNIPPET: &str = r#"
+function f{n}(a, b) {
+  let value = undefined;
+  const wrapped = new String(a);
+  for (let i = 0; i < b.length; i++) {
+    const item = b[i];
+    if (item === a) {
+      value = { item, wrapped, index: i };
+    }
+  }
+  return value;
+}
+"#;
+
+fn source() -> String {
+  (0..2000)
+    .map(|n| SNIPPET.replace("{n}", &n.to_string()))
+    .collect()
+}
+
+fn lint(source: &str, rules: fn() -> Vec<Box<dyn LintRule>>) -> Duration {
+  let start = Instant::now();
+  for _ in 0..RUNS {
+    let mut linter = LinterBuilder::default().rules(rules()).build();
+    linter
+      .lint("bench.ts".to_string(), source.to_string())
+      .expect("Failed to lint");
+  }
+  start.elapsed() / RUNS
+}
+
+fn main() {
+  let source = source();
+
+  let parse_only = lint(&source, Vec::new);
+  let no_undef_init = lint(&source, || vec![NoUndefInit::new()]);
+  let no_new_wrappers = lint(&source, || vec![NoNewWrappers::new()]);
+  let shared = lint(&source, || vec![NoUndefInit::new(), NoNewWrappers::new()]);
+
+  let eager = (no_undef_init + no_new_wrappers)
+    .checked_sub(parse_only)
+    .unwrap_or_default();
+  println!("parse only:                 {:?}", parse_only);
+  println!("no-undef-init:              {:?}", no_undef_init);
+  println!("no-new-wrappers:            {:?}", no_new_wrappers);
+  println!("eager (analysis per rule):  {:?}", eager);
+  println!("lazy (shared analysis):     {:?}", shared);
+  println!(
+    "speedup:                    {:.2}x",
+    eager.as_secs_f64() / shared.as_secs_f64()
+  );
+}
@@ -2,19 +2,11 @@
 
 use anyhow::bail;
 use anyhow::Error as AnyError;
-use deno_lint::rules::{get_all_rules, LintRule};
+use deno_lint::rules::{resolve_rule_set, LintRulesConfig};
 use serde::Deserialize;
 use std::path::Path;
 use std::path::PathBuf;
 
-#[derive(Debug, Default, Deserialize)]
-#[serde(default)]
-pub struct RulesConfig {
-  pub tags: Vec<String>,
-  pub include: Vec<String>,
-  pub exclude: Vec<String>,
-}
-
 #[derive(Debug, Default, Deserialize)]
 #[serde(default)]
 pub struct FilesConfig {
@@ -25,47 +17,15 @@ pub struct FilesConfig {
 #[derive(Debug, Default, Deserialize)]
 #[serde(default)]
 pub struct Config {
-  pub rules: RulesConfig,
+  pub rules: LintRulesConfig,
   pub files: FilesConfig,
 }
 
 impl Config {
-  pub fn get_rules(&self) -> Vec<Box<dyn LintRule>> {
-    let mut rules = get_all_rules();
-
-    if !self.rules.tags.is_empty() {
-      rules = rules
-        .into_iter()
-        .filter(|rule| {
-          for tag in rule.tags().to_owned() {
-            if self.rules.tags.contains(&tag.to_string()) {
-              return true;
-            }
-          }
-          false
-        })
-        .collect();
-    }
-
-    if !self.rules.exclude.is_empty() {
-      rules = rules
-        .into_iter()
-        .filter(|rule| !self.rules.exclude.contains(&rule.code().to_string()))
-        .collect();
-    }
-
-    if !self.rules.include.is_empty() {
-      for include_rule in &self.rules.include {
-        if let Some(rule) = get_all_rules()
-          .into_iter()
-          .find(|rule| rule.code() == include_rule)
-        {
-          rules.push(rule);
-        }
-      }
-    }
-
-    rules
+  /// Returns the codes of the rules selected by the config.
+  pub fn get_rule_codes(&self) -> Result<Vec<&'static str>, AnyError> {
+    let rules = resolve_rule_set(self.rules.clone())?;
+    Ok(rules.iter().map(|rule| rule.code()).collect())
   }
 
   pub fn get_files(&self) -> Result<Vec<PathBuf>, AnyError> {
@@ -0,0 +1,55 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+
+use similar::TextDiff;
+
+/// Returns a unified diff from `original` to `fixed`, or `None` if they're
+/// the same.
+pub fn fix_diff(
+  file_name: &str,
+  original: &str,
+  fixed: &str,
+) -> Option<String> {
+  if original == fixed {
+    return None;
+  }
+  let diff = TextDiff::from_lines(original, fixed)
+    .unified_diff()
+    .header(file_name, file_name)
+    .to_string();
+  Some(diff)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use deno_lint::linter::LinterBuilder;
+  use deno_lint::rules::valid_typeof::ValidTypeof;
+  use deno_lint::rules::LintRule;
+
+  #[test]
+  fn test_fix_diff() {
+    let src = "const a = 1;\nif (typeof a === \"strnig\") {}\n";
+    let mut linter = LinterBuilder::default()
+      .rules(vec![ValidTypeof::new()])
+      .build();
+    let (fixed, _) = linter
+      .lint_and_fix("foo.ts".to_string(), src.to_string())
+      .expect("Failed to lint");
+
+    assert_eq!(
+      fix_diff("foo.ts", src, &fixed).unwrap(),
+      r#"--- foo.ts
++++ foo.ts
+@@ -1,2 +1,2 @@
+ const a = 1;
+-if (typeof a === "strnig") {}
++if (typeof a === "string") {}
+"#
+    );
+  }
+
+  #[test]
+  fn test_fix_diff_unchanged() {
+    assert_eq!(fix_diff("foo.ts", "const a = 1;\n", "const a = 1;\n"), None);
+  }
+}
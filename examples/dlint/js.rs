@@ -9,6 +9,7 @@ use deno_core::RuntimeOptions;
 use deno_core::ZeroCopyBuf;
 use deno_lint::control_flow::ControlFlow;
 use deno_lint::linter::{Context, Plugin};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::cell::RefCell;
@@ -39,13 +40,23 @@ struct Code {
 type Diagnostics = HashMap<String, Vec<InnerDiagnostics>>;
 type Codes = HashSet<String>;
 
+/// Parses the arguments a plugin passed to the op `op_name`. An error is
+/// thrown in the plugin instead of panicking.
+fn parse_args<T: DeserializeOwned>(
+  op_name: &str,
+  args: Value,
+) -> Result<T, AnyError> {
+  serde_json::from_value(args)
+    .with_context(|| format!("Invalid arguments passed to {}", op_name))
+}
+
 fn op_add_diagnostics(
   state: &mut OpState,
   args: Value,
   _bufs: &mut [ZeroCopyBuf],
 ) -> anyhow::Result<Value> {
   let DiagnosticsFromJS { code, diagnostics } =
-    serde_json::from_value(args).unwrap();
+    parse_args("op_add_diagnostics", args)?;
 
   let mut stored = state.try_take::<Diagnostics>().unwrap_or_else(HashMap::new);
   // TODO(magurotuna): should add some prefix to `code` to prevent from conflicting with builtin
@@ -61,7 +72,7 @@ fn op_add_rule_code(
   args: Value,
   _bufs: &mut [ZeroCopyBuf],
 ) -> Result<Value, AnyError> {
-  let code_from_js: Code = serde_json::from_value(args).unwrap();
+  let code_from_js: Code = parse_args("op_add_rule_code", args)?;
 
   let mut stored = state.try_take::<Codes>().unwrap_or_else(HashSet::new);
   stored.insert(code_from_js.code);
@@ -83,7 +94,8 @@ fn op_query_control_flow_by_span(
   struct SpanFromJS {
     span: Span,
   }
-  let span_from_js: SpanFromJS = serde_json::from_value(args).unwrap();
+  let span_from_js: SpanFromJS =
+    parse_args("op_query_control_flow_by_span", args)?;
   let meta = control_flow.meta(span_from_js.span.lo());
 
   let is_reachable = meta.map(|m| !m.unreachable);
@@ -103,6 +115,7 @@ fn op_query_control_flow_by_span(
 }
 
 pub struct JsRuleRunner {
+  plugin_path: String,
   runtime: JsRuntime,
   module_id: i32,
 }
@@ -141,7 +154,11 @@ impl JsRuleRunner {
       ))
       .unwrap();
 
-    Box::new(Self { runtime, module_id })
+    Box::new(Self {
+      plugin_path: plugin_path.to_string(),
+      runtime,
+      module_id,
+    })
   }
 }
 
@@ -188,12 +205,25 @@ impl Plugin for JsRuleRunner {
     &mut self,
     context: &mut Context,
     program: Program,
+  ) -> Result<(), AnyError> {
+    let plugin_path = self.plugin_path.clone();
+    self
+      .run_rules(context, program)
+      .with_context(|| format!("Plugin {} failed", plugin_path))
+  }
+}
+
+impl JsRuleRunner {
+  fn run_rules(
+    &mut self,
+    context: &mut Context,
+    program: Program,
   ) -> Result<(), AnyError> {
     self
       .runtime
       .op_state()
       .borrow_mut()
-      .put(context.control_flow.clone());
+      .put(context.control_flow().clone());
 
     deno_core::futures::executor::block_on(
       self.runtime.mod_evaluate(self.module_id),
@@ -212,8 +242,8 @@ impl Plugin for JsRuleRunner {
       "runPlugins",
       &format!(
         "runPlugins({ast}, {rule_codes});",
-        ast = serde_json::to_string(&program).unwrap(),
-        rule_codes = serde_json::to_string(&codes).unwrap()
+        ast = serde_json::to_string(&program)?,
+        rule_codes = serde_json::to_string(&codes)?
       ),
     )?;
 
@@ -269,6 +299,19 @@ registerRule(Plugin);
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_parse_args_malformed() {
+    let args = serde_json::json!({ "code": "foo", "diagnostics": [{}] });
+    let err = parse_args::<DiagnosticsFromJS>("op_add_diagnostics", args)
+      .err()
+      .expect("Malformed diagnostics were accepted");
+    assert!(format!("{:#}", err)
+      .starts_with("Invalid arguments passed to op_add_diagnostics: "));
+
+    let args = serde_json::json!({ "code": "foo" });
+    assert!(parse_args::<Code>("op_add_rule_code", args).is_ok());
+  }
+
   #[test]
   fn test_create_dummy_source() {
     assert_eq!(
@@ -8,25 +8,43 @@ use clap::App;
 use clap::AppSettings;
 use clap::Arg;
 use clap::SubCommand;
+use deno_lint::diagnostic::FileDiagnostics;
 use deno_lint::diagnostic::LintDiagnostic;
 use deno_lint::diagnostic::Range;
+use deno_lint::graph::ModuleGraph;
+use deno_lint::linter::summarize;
 use deno_lint::linter::LinterBuilder;
 use deno_lint::linter::SourceFile;
-use deno_lint::rules::{get_all_rules, get_recommended_rules, LintRule};
+use deno_lint::metrics::FileMetrics;
+use deno_lint::rules::{
+  describe_all, get_all_rules, get_recommended_rules, options_schema,
+  RuleDescription,
+};
 use log::debug;
 use rayon::prelude::*;
-use serde::Serialize;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
-use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 
 mod config;
+mod diff;
 mod js;
 
 fn create_cli_app<'a, 'b>() -> App<'a, 'b> {
   App::new("dlint")
-    .setting(AppSettings::SubcommandRequiredElseHelp)
+    .setting(AppSettings::ArgRequiredElseHelp)
+    .arg(
+      Arg::with_name("DETECT_CYCLES")
+        .long("detect-cycles")
+        .help("Report import cycles in modules reachable from an entry module")
+        .value_name("ENTRY")
+        .takes_value(true),
+    )
+    .arg(
+      Arg::with_name("PRINT_SCHEMA")
+        .long("print-schema")
+        .help("Print a JSON schema of the options of all rules"),
+    )
     .subcommand(
       SubCommand::with_name("rules")
         .arg(
@@ -61,6 +79,19 @@ fn create_cli_app<'a, 'b>() -> App<'a, 'b> {
             .help("Specify plugin paths")
             .multiple(true)
             .takes_value(true),
+        )
+        .arg(
+          Arg::with_name("FIX_DRY_RUN")
+            .long("fix-dry-run")
+            .help("Print the changes fixes would make instead of diagnostics"),
+        )
+        .arg(
+          Arg::with_name("FORMAT")
+            .long("format")
+            .help("Set the output format; \"metrics\" prints the metrics of each file as JSON instead of diagnostics")
+            .possible_values(&["pretty", "metrics"])
+            .default_value("pretty")
+            .takes_value(true),
         ),
     )
 }
@@ -141,14 +172,30 @@ fn display_diagnostics(
     };
     let display_list = display_list::DisplayList::from(snippet);
     eprintln!("{}", display_list);
+    for related in &diagnostic.related {
+      eprintln!(
+        "  {}:{}:{}: {}",
+        diagnostic.filename,
+        related.range.start.line,
+        related.range.start.col + 1,
+        related.message
+      );
+    }
   }
 }
 
+enum OutputFormat {
+  Pretty,
+  Metrics,
+}
+
 fn run_linter(
   paths: Vec<String>,
   filter_rule_name: Option<&str>,
   maybe_config: Option<Arc<config::Config>>,
   plugin_paths: Vec<&str>,
+  fix_dry_run: bool,
+  format: OutputFormat,
 ) -> Result<(), AnyError> {
   let mut paths: Vec<PathBuf> = paths.iter().map(PathBuf::from).collect();
 
@@ -156,30 +203,36 @@ fn run_linter(
     paths.extend(config.get_files()?);
   }
 
-  let error_counts = Arc::new(AtomicUsize::new(0));
-  let output_lock = Arc::new(Mutex::new(())); // prevent threads outputting at the same time
+  let mut rules = if let Some(config) = &maybe_config {
+    let rule_codes = config.get_rule_codes()?;
+    get_all_rules()
+      .into_iter()
+      .filter(|r| rule_codes.contains(&r.code()))
+      .collect()
+  } else {
+    get_recommended_rules()
+  };
+
+  if let Some(rule_name) = filter_rule_name {
+    rules = rules
+      .into_iter()
+      .filter(|r| r.code() == rule_name)
+      .collect()
+  };
+
+  debug!("Configured rules: {}", rules.len());
+  let rules = Arc::new(rules);
+
+  // Also prevents threads from outputting at the same time
+  let results = Arc::new(Mutex::new(Vec::new()));
+  let file_metrics = Arc::new(Mutex::new(Vec::new()));
 
   paths.par_iter().for_each(|file_path| {
     let source_code =
       std::fs::read_to_string(&file_path).expect("Failed to load file");
 
-    let mut rules = if let Some(config) = maybe_config.clone() {
-      config.get_rules()
-    } else {
-      get_recommended_rules()
-    };
-
-    if let Some(rule_name) = filter_rule_name {
-      rules = rules
-        .into_iter()
-        .filter(|r| r.code() == rule_name)
-        .collect()
-    };
-
-    debug!("Configured rules: {}", rules.len());
-
     let mut linter_builder = LinterBuilder::default()
-      .rules(rules)
+      .shared_rules(Arc::clone(&rules))
       .lint_unknown_rules(true)
       .lint_unused_ignore_directives(true);
 
@@ -189,31 +242,75 @@ fn run_linter(
     }
 
     let mut linter = linter_builder.build();
+    let file_name = file_path.to_string_lossy().to_string();
+
+    if fix_dry_run {
+      let (fixed, _) = linter
+        .lint_and_fix(file_name.clone(), source_code.clone())
+        .expect("Failed to lint");
+      if let Some(diff) = diff::fix_diff(&file_name, &source_code, &fixed) {
+        let _guard = results.lock().unwrap();
+        print!("{}", diff);
+      }
+      return;
+    }
 
     let (source_file, file_diagnostics) = linter
-      .lint(file_path.to_string_lossy().to_string(), source_code)
+      .lint(file_name.clone(), source_code)
       .expect("Failed to lint");
 
-    error_counts.fetch_add(file_diagnostics.len(), Ordering::Relaxed);
-    let _g = output_lock.lock().unwrap();
+    if let OutputFormat::Metrics = format {
+      file_metrics.lock().unwrap().push(FileMetrics {
+        filename: file_name,
+        metrics: linter.metrics().clone(),
+      });
+      return;
+    }
 
+    let mut results = results.lock().unwrap();
     display_diagnostics(&file_diagnostics, source_file);
+    results.push(FileDiagnostics {
+      filename: file_name,
+      diagnostics: file_diagnostics,
+    });
   });
 
-  let err_count = error_counts.load(Ordering::Relaxed);
-  if err_count > 0 {
-    eprintln!("Found {} problems", err_count);
-    std::process::exit(1);
+  if let OutputFormat::Metrics = format {
+    let mut file_metrics = file_metrics.lock().unwrap();
+    file_metrics.sort_by(|a, b| a.filename.cmp(&b.filename));
+    println!("{}", serde_json::to_string_pretty(&*file_metrics)?);
+    return Ok(());
+  }
+
+  let summary = summarize(&results.lock().unwrap());
+  if summary.error_count + summary.warning_count > 0 {
+    eprintln!("{}", summary);
+  }
+  if summary.exit_code() != 0 {
+    std::process::exit(summary.exit_code());
   }
 
   Ok(())
 }
 
-#[derive(Clone, Copy, Serialize)]
-struct Rule {
-  code: &'static str,
-  docs: &'static str,
-  tags: &'static [&'static str],
+fn detect_cycles(entry: &str) -> Result<(), AnyError> {
+  let graph = ModuleGraph::build(Path::new(entry))?;
+  let cycles = graph.find_cycles();
+  for cycle in &cycles {
+    let modules: Vec<String> = cycle
+      .iter()
+      .chain(cycle.first())
+      .map(|path| path.display().to_string())
+      .collect();
+    eprintln!("Import cycle: {}", modules.join(" -> "));
+  }
+
+  if !cycles.is_empty() {
+    eprintln!("Found {} import cycles", cycles.len());
+    std::process::exit(1);
+  }
+
+  Ok(())
 }
 
 enum RuleTag {
@@ -221,32 +318,26 @@ enum RuleTag {
   All,
 }
 
-fn get_rules_by_tag(tag: RuleTag) -> Vec<Rule> {
-  fn to_rule(rule: Box<dyn LintRule>) -> Rule {
-    Rule {
-      code: rule.code(),
-      docs: rule.docs(),
-      tags: rule.tags(),
-    }
-  }
-
+fn get_rules_by_tag(tag: RuleTag) -> Vec<RuleDescription> {
+  let rules = describe_all();
   match tag {
-    RuleTag::Recommended => {
-      get_recommended_rules().into_iter().map(to_rule).collect()
-    }
-    RuleTag::All => get_all_rules().into_iter().map(to_rule).collect(),
+    RuleTag::Recommended => rules
+      .into_iter()
+      .filter(|r| r.tags.contains(&"recommended"))
+      .collect(),
+    RuleTag::All => rules,
   }
 }
 
 trait RuleFormatter {
-  fn format(rules: &mut [Rule]) -> Result<String, &'static str>;
+  fn format(rules: &mut [RuleDescription]) -> Result<String, &'static str>;
 }
 
 enum JsonFormatter {}
 enum PrettyFormatter {}
 
 impl RuleFormatter for JsonFormatter {
-  fn format(rules: &mut [Rule]) -> Result<String, &'static str> {
+  fn format(rules: &mut [RuleDescription]) -> Result<String, &'static str> {
     if rules.is_empty() {
       return Err("Rule not found!");
     }
@@ -255,7 +346,7 @@ impl RuleFormatter for JsonFormatter {
 }
 
 impl RuleFormatter for PrettyFormatter {
-  fn format(rules: &mut [Rule]) -> Result<String, &'static str> {
+  fn format(rules: &mut [RuleDescription]) -> Result<String, &'static str> {
     if rules.is_empty() {
       return Err("Rule not found!");
     }
@@ -284,7 +375,7 @@ impl RuleFormatter for PrettyFormatter {
   }
 }
 
-fn print_rules<F: RuleFormatter>(rules: &mut [Rule]) {
+fn print_rules<F: RuleFormatter>(rules: &mut [RuleDescription]) {
   match F::format(rules) {
     Err(e) => {
       eprintln!("{}", e);
@@ -296,7 +387,10 @@ fn print_rules<F: RuleFormatter>(rules: &mut [Rule]) {
   }
 }
 
-fn filter_rules(rules: Vec<Rule>, rule_name: &str) -> Vec<Rule> {
+fn filter_rules(
+  rules: Vec<RuleDescription>,
+  rule_name: &str,
+) -> Vec<RuleDescription> {
   rules.into_iter().filter(|r| r.code == rule_name).collect()
 }
 
@@ -306,6 +400,15 @@ fn main() -> Result<(), AnyError> {
   let cli_app = create_cli_app();
   let matches = cli_app.get_matches();
 
+  if let Some(entry) = matches.value_of("DETECT_CYCLES") {
+    return detect_cycles(entry);
+  }
+
+  if matches.is_present("PRINT_SCHEMA") {
+    println!("{}", serde_json::to_string_pretty(&options_schema())?);
+    return Ok(());
+  }
+
   match matches.subcommand() {
     ("run", Some(run_matches)) => {
       let maybe_config = if let Some(p) = run_matches.value_of("CONFIG") {
@@ -337,6 +440,11 @@ fn main() -> Result<(), AnyError> {
         run_matches.value_of("RULE_CODE"),
         maybe_config,
         plugins,
+        run_matches.is_present("FIX_DRY_RUN"),
+        match run_matches.value_of("FORMAT") {
+          Some("metrics") => OutputFormat::Metrics,
+          _ => OutputFormat::Pretty,
+        },
       )?;
     }
     ("rules", Some(rules_matches)) => {
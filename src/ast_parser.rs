@@ -49,6 +49,15 @@ pub fn get_default_ts_config() -> Syntax {
   Syntax::Typescript(ts_config)
 }
 
+/// Same as `get_default_ts_config`, but with JSX enabled.
+pub fn get_default_tsx_config() -> Syntax {
+  let mut ts_config = TsConfig::default();
+  ts_config.dynamic_import = true;
+  ts_config.decorators = true;
+  ts_config.tsx = true;
+  Syntax::Typescript(ts_config)
+}
+
 #[derive(Clone, Debug)]
 pub struct SwcDiagnosticBuffer {
   pub diagnostics: Vec<String>,
@@ -0,0 +1,145 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+
+//! Cache of lint results, so that tools which lint the same files repeatedly,
+//! such as watchers, can skip files that haven't changed.
+use crate::diagnostic::LintDiagnostic;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+
+/// Identifies a lint result by the linted file and the linter configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheKey {
+  /// Hash of the file name and its full source.
+  pub content_hash: u64,
+  /// Hash of the enabled rules and the linter settings.
+  pub rules_hash: u64,
+}
+
+impl CacheKey {
+  pub fn new(file_name: &str, source_code: &str, rules_hash: u64) -> Self {
+    // The file name is part of the key because diagnostics refer to it.
+    let mut hasher = DefaultHasher::new();
+    file_name.hash(&mut hasher);
+    source_code.hash(&mut hasher);
+    CacheKey {
+      content_hash: hasher.finish(),
+      rules_hash,
+    }
+  }
+}
+
+type CacheEntry = (CacheKey, Vec<LintDiagnostic>);
+
+/// Maps a `CacheKey` to the diagnostics the linter produced for it.
+///
+/// The hashes are only stable for a given build of the linter, so a cache
+/// saved to disk should be discarded when the linter is upgraded.
+#[derive(Clone, Default, Serialize, Deserialize)]
+// Serialized as a list of entries, since JSON object keys must be strings
+#[serde(from = "Vec<CacheEntry>", into = "Vec<CacheEntry>")]
+pub struct LintCache {
+  entries: HashMap<CacheKey, Vec<LintDiagnostic>>,
+}
+
+impl LintCache {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn get(&self, key: &CacheKey) -> Option<&Vec<LintDiagnostic>> {
+    self.entries.get(key)
+  }
+
+  pub fn insert(&mut self, key: CacheKey, diagnostics: Vec<LintDiagnostic>) {
+    self.entries.insert(key, diagnostics);
+  }
+
+  pub fn len(&self) -> usize {
+    self.entries.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.entries.is_empty()
+  }
+
+  /// Reads a cache written by `save`.
+  pub fn load(path: &Path) -> anyhow::Result<Self> {
+    let contents = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&contents)?)
+  }
+
+  pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+    fs::write(path, serde_json::to_string(self)?)?;
+    Ok(())
+  }
+}
+
+impl From<Vec<CacheEntry>> for LintCache {
+  fn from(entries: Vec<CacheEntry>) -> Self {
+    LintCache {
+      entries: entries.into_iter().collect(),
+    }
+  }
+}
+
+impl From<LintCache> for Vec<CacheEntry> {
+  fn from(cache: LintCache) -> Self {
+    cache.entries.into_iter().collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::diagnostic::{Position, Range, Severity};
+
+  fn diagnostic() -> LintDiagnostic {
+    let position = Position {
+      line: 1,
+      col: 0,
+      byte_pos: 0,
+    };
+    LintDiagnostic {
+      range: Range {
+        start: position,
+        end: position,
+      },
+      filename: "cache_test.ts".to_string(),
+      message: "message".to_string(),
+      code: "code".to_string(),
+      hint: None,
+      severity: Severity::Warning,
+      fix: None,
+      related: vec![],
+    }
+  }
+
+  #[test]
+  fn cache_key() {
+    let key = CacheKey::new("a.ts", "let a;", 0);
+    assert_eq!(key, CacheKey::new("a.ts", "let a;", 0));
+    assert_ne!(key, CacheKey::new("b.ts", "let a;", 0));
+    assert_ne!(key, CacheKey::new("a.ts", "let b;", 0));
+    assert_ne!(key, CacheKey::new("a.ts", "let a;", 1));
+  }
+
+  #[test]
+  fn cache_round_trip() {
+    let key = CacheKey::new("cache_test.ts", "debugger;", 42);
+    let mut cache = LintCache::new();
+    cache.insert(key, vec![diagnostic()]);
+
+    let json = serde_json::to_string(&cache).unwrap();
+    let cache: LintCache = serde_json::from_str(&json).unwrap();
+    assert_eq!(cache.len(), 1);
+    let diagnostics = cache.get(&key).unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, "code");
+    assert_eq!(diagnostics[0].severity, Severity::Warning);
+  }
+}
@@ -9,12 +9,17 @@ use swc_ecmascript::{
   visit::{noop_visit_type, Node, Visit, VisitWith},
 };
 
+/// Reachability of the statements of a program.
+///
+/// Rules usually get it from `Context::control_flow()`, which computes it once
+/// per file, but it can be built for any program with `ControlFlow::analyze`.
 #[derive(Debug, Clone)]
 pub struct ControlFlow {
   meta: BTreeMap<BytePos, Metadata>,
 }
 
 impl ControlFlow {
+  /// Analyzes the control flow of `program`.
   pub fn analyze(program: &Program) -> Self {
     let mut v = Analyzer {
       scope: Scope::new(None, BlockKind::Program),
@@ -24,6 +29,9 @@ impl ControlFlow {
     ControlFlow { meta: v.info }
   }
 
+  /// Returns the metadata of the node starting at `lo`, or `None` if no node
+  /// the analysis tracks starts there.
+  ///
   /// lo can be extracted from span of
   ///
   /// - All statements (including stmt.span())
@@ -53,8 +61,11 @@ pub enum BlockKind {
   Finally,
 }
 
+/// Control flow information about a statement or a switch case.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Metadata {
+  /// True if the node can never be executed, e.g. a statement following a
+  /// `return`.
   pub unreachable: bool,
   end: Option<End>,
 }
@@ -1298,4 +1309,20 @@ throw err;
     assert_flow!(flow, 54, false, Some(End::Forced)); // return stmt
     assert_flow!(flow, 70, false, Some(End::Forced)); // throw stmt
   }
+
+  #[test]
+  fn early_return() {
+    let src = r#"
+function foo() {
+  return 1;
+  bar();
+}
+"#;
+    let flow = analyze_flow(src);
+    let return_stmt = flow.meta(BytePos(20)).unwrap();
+    assert!(!return_stmt.unreachable);
+    assert!(return_stmt.stops_execution());
+    let bar = flow.meta(BytePos(32)).unwrap();
+    assert!(bar.unreachable);
+  }
 }
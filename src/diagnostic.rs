@@ -1,8 +1,8 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::convert::TryInto;
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Position {
   pub line: usize,
@@ -24,17 +24,66 @@ impl Position {
   }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Range {
   pub start: Position,
   pub end: Position,
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Severity {
+  Error,
+  Warning,
+}
+
+impl Default for Severity {
+  fn default() -> Self {
+    Severity::Error
+  }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LintDiagnostic {
   pub range: Range,
   pub filename: String,
   pub message: String,
   pub code: String,
   pub hint: Option<String>,
+  pub severity: Severity,
+  pub fix: Option<LintFix>,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub related: Vec<RelatedInformation>,
+}
+
+/// Another location that is relevant to a diagnostic, such as the earlier
+/// declaration a duplicate conflicts with.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RelatedInformation {
+  pub range: Range,
+  pub message: String,
+}
+
+/// A suggested change to the source code that resolves a diagnostic.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LintFix {
+  pub description: String,
+  pub changes: Vec<LintFixChange>,
+  /// When fixes overlap, the one with the highest priority is applied.
+  #[serde(default)]
+  pub priority: u8,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintFixChange {
+  pub new_text: String,
+  pub range: Range,
+}
+
+/// Diagnostics produced for a single file.
+#[derive(Clone, Debug, Serialize)]
+pub struct FileDiagnostics {
+  pub filename: String,
+  pub diagnostics: Vec<LintDiagnostic>,
 }
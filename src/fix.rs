@@ -0,0 +1,155 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+
+//! Application of the fixes suggested by diagnostics to the source code.
+
+use crate::diagnostic::{LintFix, LintFixChange};
+use std::cmp::Reverse;
+
+/// Outcome of `apply_fixes`.
+#[derive(Debug)]
+pub struct FixReport<'a> {
+  /// The source with the applied fixes.
+  pub source: String,
+  /// Fixes applied to the source.
+  pub applied: Vec<&'a LintFix>,
+  /// Fixes left out because they overlap with an applied fix.
+  pub skipped: Vec<&'a LintFix>,
+}
+
+/// Applies `fixes` to `source`.
+///
+/// The changes of a fix are applied all together or not at all. Fixes are
+/// considered from the highest priority to the lowest, and in the given order
+/// for the same priority. A fix that overlaps with a fix applied before it is
+/// skipped; linting the fixed source again suggests it anew if it still
+/// applies.
+pub fn apply_fixes<'a>(
+  source: &str,
+  fixes: impl IntoIterator<Item = &'a LintFix>,
+) -> FixReport<'a> {
+  let mut fixes: Vec<&LintFix> = fixes.into_iter().collect();
+  // The sort is stable, so the given order decides between equal priorities
+  fixes.sort_by_key(|fix| Reverse(fix.priority));
+
+  let mut accepted: Vec<&LintFixChange> = Vec::new();
+  let mut applied = Vec::new();
+  let mut skipped = Vec::new();
+  for fix in fixes {
+    let overlaps = fix
+      .changes
+      .iter()
+      .any(|change| accepted.iter().any(|other| ranges_overlap(change, other)));
+    if overlaps {
+      skipped.push(fix);
+    } else {
+      accepted.extend(fix.changes.iter());
+      applied.push(fix);
+    }
+  }
+  accepted.sort_by_key(|change| change.range.start.byte_pos);
+
+  let mut fixed = String::with_capacity(source.len());
+  let mut last_pos = 0;
+  for change in accepted {
+    fixed.push_str(&source[last_pos..change.range.start.byte_pos]);
+    fixed.push_str(&change.new_text);
+    last_pos = change.range.end.byte_pos;
+  }
+  fixed.push_str(&source[last_pos..]);
+  FixReport {
+    source: fixed,
+    applied,
+    skipped,
+  }
+}
+
+/// Checks if two changes touch the same part of the source. Two insertions
+/// at the same position overlap too, since their order would be ambiguous.
+fn ranges_overlap(a: &LintFixChange, b: &LintFixChange) -> bool {
+  let (a_start, a_end) = (a.range.start.byte_pos, a.range.end.byte_pos);
+  let (b_start, b_end) = (b.range.start.byte_pos, b.range.end.byte_pos);
+  (a_start < b_end && b_start < a_end) || a_start == b_start
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::diagnostic::{Position, Range};
+
+  fn fix(changes: &[(usize, usize, &str)]) -> LintFix {
+    fix_with_priority(changes, 0)
+  }
+
+  fn fix_with_priority(
+    changes: &[(usize, usize, &str)],
+    priority: u8,
+  ) -> LintFix {
+    let position = |byte_pos| Position {
+      line: 1,
+      col: byte_pos,
+      byte_pos,
+    };
+    LintFix {
+      description: "fix".to_string(),
+      changes: changes
+        .iter()
+        .map(|(start, end, new_text)| LintFixChange {
+          new_text: new_text.to_string(),
+          range: Range {
+            start: position(*start),
+            end: position(*end),
+          },
+        })
+        .collect(),
+      priority,
+    }
+  }
+
+  #[test]
+  fn apply_fixes_in_source_order() {
+    let fixes = vec![fix(&[(8, 9, "c")]), fix(&[(0, 3, "let")])];
+    let report = apply_fixes("var a = b;", &fixes);
+    assert_eq!(report.source, "let a = c;");
+    assert_eq!(report.applied.len(), 2);
+    assert!(report.skipped.is_empty());
+  }
+
+  #[test]
+  fn apply_fixes_skips_overlapping() {
+    let fixes = vec![
+      fix(&[(0, 5, "x")]),
+      fix(&[(4, 6, "y"), (8, 9, "z")]),
+      fix(&[(5, 5, "!")]),
+      fix(&[(5, 5, "?")]),
+    ];
+    let report = apply_fixes("abcdefghij", &fixes);
+    assert_eq!(report.source, "x!fghij");
+    assert_eq!(report.applied.len(), 2);
+    assert_eq!(report.skipped.len(), 2);
+    assert_eq!(report.skipped[0].changes[0].new_text, "y");
+    assert_eq!(report.skipped[1].changes[0].new_text, "?");
+  }
+
+  #[test]
+  fn apply_fixes_prefers_higher_priority() {
+    let fixes = vec![
+      fix_with_priority(&[(0, 5, "x")], 0),
+      fix_with_priority(&[(4, 6, "y")], 2),
+      fix_with_priority(&[(5, 7, "z")], 1),
+    ];
+    let report = apply_fixes("abcdefghij", &fixes);
+    assert_eq!(report.source, "abcdyghij");
+    assert_eq!(report.applied.len(), 1);
+    assert_eq!(report.applied[0].priority, 2);
+    let skipped: Vec<u8> =
+      report.skipped.iter().map(|fix| fix.priority).collect();
+    assert_eq!(skipped, vec![1, 0]);
+  }
+
+  #[test]
+  fn apply_no_fixes() {
+    let report = apply_fixes("foo;", std::iter::empty());
+    assert_eq!(report.source, "foo;");
+    assert!(report.applied.is_empty());
+  }
+}
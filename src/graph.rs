@@ -0,0 +1,326 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use crate::ast_parser::get_default_ts_config;
+use crate::ast_parser::AstParser;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Component, Path, PathBuf};
+use swc_ecmascript::ast::{ModuleDecl, ModuleItem, Program};
+
+/// Graph of the modules reachable from an entry module through static imports
+/// and re-exports.
+///
+/// Only relative specifiers (`./` and `../`) are followed; remote and bare
+/// specifiers are left out of the graph.
+#[derive(Debug, Clone)]
+pub struct ModuleGraph {
+  entry: PathBuf,
+  /// Dependencies of every module, in the order they are imported.
+  modules: BTreeMap<PathBuf, Vec<PathBuf>>,
+}
+
+impl ModuleGraph {
+  /// Builds the graph of modules reachable from `entry`, reading modules from
+  /// the file system.
+  pub fn build(entry: &Path) -> anyhow::Result<Self> {
+    Self::build_with_loader(entry, |path| {
+      std::fs::read_to_string(path).map_err(anyhow::Error::from)
+    })
+  }
+
+  /// Builds the graph of modules reachable from `entry`, using `load` to get
+  /// the source of every module.
+  pub fn build_with_loader<F>(entry: &Path, mut load: F) -> anyhow::Result<Self>
+  where
+    F: FnMut(&Path) -> anyhow::Result<String>,
+  {
+    let ast_parser = AstParser::new();
+    let entry = normalize_path(entry);
+    let mut modules = BTreeMap::new();
+    let mut pending = vec![entry.clone()];
+
+    while let Some(path) = pending.pop() {
+      if modules.contains_key(&path) {
+        continue;
+      }
+      let source_code = load(&path)?;
+      let (program, _) = ast_parser.parse_program(
+        &path.to_string_lossy(),
+        get_default_ts_config(),
+        &source_code,
+      )?;
+      let dependencies: Vec<PathBuf> = import_specifiers(&program)
+        .iter()
+        .filter_map(|specifier| resolve_specifier(&path, specifier))
+        .collect();
+      pending.extend(dependencies.iter().rev().cloned());
+      modules.insert(path, dependencies);
+    }
+
+    Ok(ModuleGraph { entry, modules })
+  }
+
+  /// The entry module the graph was built from.
+  pub fn entry(&self) -> &Path {
+    &self.entry
+  }
+
+  /// All modules in the graph, sorted by path.
+  pub fn modules(&self) -> impl Iterator<Item = &Path> {
+    self.modules.keys().map(PathBuf::as_path)
+  }
+
+  /// Modules imported by `path`, or `None` if it's not in the graph.
+  pub fn dependencies(&self, path: &Path) -> Option<&[PathBuf]> {
+    self.modules.get(path).map(Vec::as_slice)
+  }
+
+  /// Modules that import `path` directly, sorted by path.
+  pub fn importers(&self, path: &Path) -> Vec<&Path> {
+    self
+      .modules
+      .iter()
+      .filter(|(_, dependencies)| dependencies.iter().any(|d| d == path))
+      .map(|(module, _)| module.as_path())
+      .collect()
+  }
+
+  /// Modules to lint again after `path` has changed: `path` itself, followed
+  /// by the modules that import it directly, since rules may depend on the
+  /// modules they import.
+  pub fn affected_by<'a>(&'a self, path: &'a Path) -> Vec<&'a Path> {
+    let mut affected = vec![path];
+    affected.extend(self.importers(path).into_iter().filter(|p| *p != path));
+    affected
+  }
+
+  /// Finds import cycles with a depth-first search from the entry module.
+  ///
+  /// Every cycle is reported once, as the list of modules on it starting with
+  /// the module first reached by the search. The module importing the first
+  /// one again is the last in the list.
+  pub fn find_cycles(&self) -> Vec<Vec<PathBuf>> {
+    let mut cycles = vec![];
+    let mut states = HashMap::new();
+    let mut stack = vec![];
+    self.visit(&self.entry, &mut states, &mut stack, &mut cycles);
+    cycles
+  }
+
+  fn visit<'a>(
+    &'a self,
+    path: &'a Path,
+    states: &mut HashMap<&'a Path, VisitState>,
+    stack: &mut Vec<&'a Path>,
+    cycles: &mut Vec<Vec<PathBuf>>,
+  ) {
+    states.insert(path, VisitState::InProgress);
+    stack.push(path);
+
+    for dependency in self.dependencies(path).unwrap_or_default() {
+      match states.get(dependency.as_path()) {
+        None => self.visit(dependency, states, stack, cycles),
+        Some(VisitState::InProgress) => {
+          let start = stack
+            .iter()
+            .position(|p| *p == dependency.as_path())
+            .unwrap();
+          cycles.push(stack[start..].iter().map(|p| p.to_path_buf()).collect());
+        }
+        Some(VisitState::Done) => {}
+      }
+    }
+
+    stack.pop();
+    states.insert(path, VisitState::Done);
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum VisitState {
+  InProgress,
+  Done,
+}
+
+/// Returns the specifiers of all static imports and re-exports of `program`.
+fn import_specifiers(program: &Program) -> Vec<String> {
+  let module = match program {
+    Program::Module(module) => module,
+    Program::Script(_) => return vec![],
+  };
+
+  module
+    .body
+    .iter()
+    .filter_map(|item| match item {
+      ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) => {
+        Some(import_decl.src.value.to_string())
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export_all)) => {
+        Some(export_all.src.value.to_string())
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export)) => {
+        named_export.src.as_ref().map(|src| src.value.to_string())
+      }
+      _ => None,
+    })
+    .collect()
+}
+
+/// Resolves a relative `specifier` imported by the module at `referrer`.
+fn resolve_specifier(referrer: &Path, specifier: &str) -> Option<PathBuf> {
+  if !specifier.starts_with("./") && !specifier.starts_with("../") {
+    return None;
+  }
+  let base = referrer.parent().unwrap_or_else(|| Path::new(""));
+  Some(normalize_path(&base.join(specifier)))
+}
+
+/// Removes `.` and `..` components from `path` without accessing the file
+/// system.
+fn normalize_path(path: &Path) -> PathBuf {
+  let mut normalized = PathBuf::new();
+  for component in path.components() {
+    match component {
+      Component::CurDir => {}
+      Component::ParentDir => {
+        if !normalized.pop() {
+          normalized.push("..");
+        }
+      }
+      _ => normalized.push(component),
+    }
+  }
+  normalized
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn build(files: &[(&str, &str)]) -> ModuleGraph {
+    let files: HashMap<PathBuf, String> = files
+      .iter()
+      .map(|(path, source)| (PathBuf::from(path), source.to_string()))
+      .collect();
+    ModuleGraph::build_with_loader(Path::new("src/main.ts"), |path| {
+      files
+        .get(path)
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("Not found: {}", path.display()))
+    })
+    .expect("Failed to build module graph")
+  }
+
+  fn paths(paths: &[&str]) -> Vec<PathBuf> {
+    paths.iter().map(PathBuf::from).collect()
+  }
+
+  #[test]
+  fn module_graph_acyclic() {
+    let graph = build(&[
+      (
+        "src/main.ts",
+        "import { a } from './a.ts';\nexport * from './util/b.ts';",
+      ),
+      (
+        "src/a.ts",
+        "import { b } from './util/b.ts';\nexport const a = b;",
+      ),
+      (
+        "src/util/b.ts",
+        "import { c } from 'https://deno.land/x/c.ts';\nexport const b = c;",
+      ),
+    ]);
+
+    assert_eq!(graph.entry(), Path::new("src/main.ts"));
+    assert_eq!(
+      graph.modules().collect::<Vec<_>>(),
+      vec![
+        Path::new("src/a.ts"),
+        Path::new("src/main.ts"),
+        Path::new("src/util/b.ts"),
+      ]
+    );
+    assert_eq!(
+      graph.dependencies(Path::new("src/main.ts")).unwrap(),
+      paths(&["src/a.ts", "src/util/b.ts"]).as_slice()
+    );
+    assert!(graph
+      .dependencies(Path::new("src/util/b.ts"))
+      .unwrap()
+      .is_empty());
+    assert!(graph.find_cycles().is_empty());
+  }
+
+  #[test]
+  fn module_graph_two_file_cycle() {
+    let graph = build(&[
+      ("src/main.ts", "import './a.ts';"),
+      (
+        "src/a.ts",
+        "import { b } from './b.ts';\nexport const a = 1;",
+      ),
+      (
+        "src/b.ts",
+        "export { a } from '../src/a.ts';\nexport const b = 1;",
+      ),
+    ]);
+
+    assert_eq!(graph.find_cycles(), vec![paths(&["src/a.ts", "src/b.ts"])]);
+  }
+
+  #[test]
+  fn module_graph_cycles_reported_once() {
+    let graph = build(&[
+      ("src/main.ts", "import './a.ts';\nimport './b.ts';"),
+      ("src/a.ts", "import './b.ts';"),
+      ("src/b.ts", "import './a.ts';\nimport './main.ts';"),
+    ]);
+
+    assert_eq!(
+      graph.find_cycles(),
+      vec![
+        paths(&["src/a.ts", "src/b.ts"]),
+        paths(&["src/main.ts", "src/a.ts", "src/b.ts"]),
+      ]
+    );
+  }
+
+  #[test]
+  fn module_graph_importers() {
+    let graph = build(&[
+      ("src/main.ts", "import './a.ts';\nimport './util/b.ts';"),
+      ("src/a.ts", "import './util/b.ts';\nimport './a.ts';"),
+      ("src/util/b.ts", "import './c.ts';"),
+      ("src/util/c.ts", "export {};"),
+    ]);
+
+    assert_eq!(
+      graph.importers(Path::new("src/util/b.ts")),
+      vec![Path::new("src/a.ts"), Path::new("src/main.ts")]
+    );
+    assert!(graph.importers(Path::new("src/main.ts")).is_empty());
+
+    // Transitive importers aren't affected
+    assert_eq!(
+      graph.affected_by(Path::new("src/util/c.ts")),
+      vec![Path::new("src/util/c.ts"), Path::new("src/util/b.ts")]
+    );
+    // A module importing itself is listed once
+    assert_eq!(
+      graph.affected_by(Path::new("src/a.ts")),
+      vec![Path::new("src/a.ts"), Path::new("src/main.ts")]
+    );
+  }
+
+  #[test]
+  fn module_graph_missing_module() {
+    let result = ModuleGraph::build_with_loader(Path::new("main.ts"), |path| {
+      if path == Path::new("main.ts") {
+        Ok("import './missing.ts';".to_string())
+      } else {
+        Err(anyhow::anyhow!("Not found: {}", path.display()))
+      }
+    });
+    assert!(result.is_err());
+  }
+}
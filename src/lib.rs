@@ -5,22 +5,29 @@
 #[macro_use]
 extern crate log;
 
-#[cfg(test)]
 #[macro_use]
-mod test_util;
+pub mod test_util;
 
 pub mod ast_parser;
+pub mod cache;
 // TODO(magurotuna): Making control_flow public is just needed for implementing plugin prototype.
 // It will be likely possible to remove `pub` later.
 pub mod control_flow;
 pub mod diagnostic;
+pub mod fix;
 mod globals;
+pub mod graph;
 mod ignore_directives;
 mod js_regex;
+mod line_index;
 pub mod linter;
+pub mod metrics;
 pub mod rules;
+pub mod sarif;
 mod scopes;
 pub mod swc_util;
+pub mod timings;
+mod type_context;
 
 #[cfg(test)]
 mod lint_tests {
@@ -55,6 +62,34 @@ mod lint_tests {
     lint(source, unknown_rules, unused_dir, get_recommended_rules())
   }
 
+  /// Returns the span of the first statement of `program`.
+  fn first_statement_span(
+    program: &swc_ecmascript::ast::Program,
+  ) -> swc_common::Span {
+    use swc_common::Spanned;
+    use swc_ecmascript::ast::Program;
+
+    match program {
+      Program::Module(module) => module.body[0].span(),
+      Program::Script(script) => script.body[0].span(),
+    }
+  }
+
+  /// Reports the first statement of the program.
+  struct FirstStatementPlugin;
+
+  impl Plugin for FirstStatementPlugin {
+    fn run(
+      &mut self,
+      context: &mut Context,
+      program: swc_ecmascript::ast::Program,
+    ) -> anyhow::Result<()> {
+      let span = first_statement_span(&program);
+      context.add_diagnostic(span, "first-statement", "First statement");
+      Ok(())
+    }
+  }
+
   fn lint_specified_rule<T: LintRule + 'static>(
     source: &str,
     unknown_rules: bool,
@@ -209,4 +244,942 @@ const fooBar = 42;
     assert_eq!(diagnostics.len(), 1);
     assert_diagnostic(&diagnostics[0], "ban-unused-ignore", 4, 1, src);
   }
+
+  #[test]
+  fn lint_paths_preserves_order() {
+    use crate::rules::no_debugger::NoDebugger;
+    use std::path::PathBuf;
+
+    let sources = vec![
+      "debugger;\ndebugger;\n",
+      "const a = 1;\n",
+      "\n\ndebugger;\n",
+      "debugger;\n",
+    ];
+    let dir = std::env::temp_dir();
+    let paths: Vec<PathBuf> = sources
+      .iter()
+      .enumerate()
+      .map(|(i, source)| {
+        let path = dir.join(format!(
+          "deno_lint_lint_paths_{}_{}.ts",
+          std::process::id(),
+          i
+        ));
+        std::fs::write(&path, source).unwrap();
+        path
+      })
+      .collect();
+
+    let linter = LinterBuilder::default()
+      .rules(vec![NoDebugger::new()])
+      .build();
+    let results = linter.lint_paths(paths.clone()).expect("Failed to lint");
+
+    for path in &paths {
+      std::fs::remove_file(path).unwrap();
+    }
+
+    let result_paths: Vec<PathBuf> =
+      results.iter().map(|(path, _)| path.clone()).collect();
+    assert_eq!(result_paths, paths);
+
+    let diagnostics: Vec<Vec<LintDiagnostic>> = results
+      .into_iter()
+      .map(|(_, result)| result.expect("Failed to lint"))
+      .collect();
+    assert_eq!(diagnostics[0].len(), 2);
+    assert!(diagnostics[1].is_empty());
+    assert_eq!(diagnostics[2].len(), 1);
+    assert_eq!(diagnostics[2][0].range.start.line, 3);
+    assert_eq!(diagnostics[3].len(), 1);
+    assert_eq!(diagnostics[3][0].filename, paths[3].to_string_lossy());
+  }
+
+  #[test]
+  fn lint_paths_shares_rules() {
+    use crate::rules::max_lines::{MaxLines, MaxLinesOptions};
+    use swc_common::Spanned;
+    use swc_ecmascript::ast::Program;
+
+    // Isn't one of the builtin rules.
+    struct CustomRule;
+
+    impl LintRule for CustomRule {
+      fn new() -> Box<Self> {
+        Box::new(CustomRule)
+      }
+
+      fn code(&self) -> &'static str {
+        "custom-rule"
+      }
+
+      fn lint_program(&self, context: &mut Context, program: &Program) {
+        context.add_diagnostic(program.span(), "custom-rule", "Custom");
+      }
+    }
+
+    let path = std::env::temp_dir().join(format!(
+      "deno_lint_lint_paths_shares_rules_{}.ts",
+      std::process::id()
+    ));
+    std::fs::write(&path, "a;\nb;\n").unwrap();
+
+    let options = MaxLinesOptions {
+      max: 1,
+      ..Default::default()
+    };
+    let linter = LinterBuilder::default()
+      .rules(vec![CustomRule::new(), MaxLines::with_options(options)])
+      .build();
+    let results = linter
+      .lint_paths(vec![path.clone()])
+      .expect("Failed to lint");
+
+    let linter_with_plugin = LinterBuilder::default()
+      .add_plugin(Box::new(FirstStatementPlugin))
+      .build();
+    let plugin_result = linter_with_plugin.lint_paths(vec![path.clone()]);
+
+    std::fs::remove_file(&path).unwrap();
+
+    let (_, result) = results.into_iter().next().unwrap();
+    let mut codes: Vec<String> = result
+      .expect("Failed to lint")
+      .into_iter()
+      .map(|d| d.code)
+      .collect();
+    codes.sort();
+    assert_eq!(codes, vec!["custom-rule", "max-lines"]);
+    assert!(plugin_result.is_err());
+  }
+
+  #[test]
+  fn dedup_diagnostics_from_plugins() {
+    use crate::rules::no_debugger::NoDebugger;
+    use swc_ecmascript::ast::Program;
+
+    // Reports the same problem as `no-debugger`, plus a distinct one.
+    struct DebuggerPlugin;
+
+    impl Plugin for DebuggerPlugin {
+      fn run(
+        &mut self,
+        context: &mut Context,
+        program: Program,
+      ) -> anyhow::Result<()> {
+        let span = first_statement_span(&program);
+        context.add_diagnostic_with_hint(
+          span,
+          "no-debugger",
+          "`debugger` statement is not allowed",
+          "Remove the `debugger` statement",
+        );
+        context.add_diagnostic(span, "no-debugger", "Another message");
+        Ok(())
+      }
+    }
+
+    let mut linter = LinterBuilder::default()
+      .rules(vec![NoDebugger::new()])
+      .add_plugin(Box::new(DebuggerPlugin))
+      .build();
+    let (_, diagnostics) = linter
+      .lint("lint_test.ts".to_string(), "debugger;".to_string())
+      .expect("Failed to lint");
+
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(
+      diagnostics[0].message,
+      "`debugger` statement is not allowed"
+    );
+    assert_eq!(diagnostics[1].message, "Another message");
+  }
+
+  #[test]
+  fn lint_pre_parsed_program() {
+    use crate::ast_parser::get_default_ts_config;
+    use crate::rules::no_explicit_any::NoExplicitAny;
+    use crate::rules::no_undef::NoUndef;
+    use std::rc::Rc;
+    use swc_common::comments::SingleThreadedComments;
+    use swc_common::{FileName, SourceMap};
+    use swc_ecmascript::parser::lexer::Lexer;
+    use swc_ecmascript::parser::{JscTarget, Parser, StringInput};
+
+    let src = r#"// deno-lint-ignore no-explicit-any
+let a: any;
+let b: any = foo;
+"#;
+    let source_map = Rc::new(SourceMap::default());
+    let source_file = source_map.new_source_file(
+      FileName::Custom("pre_parsed.ts".to_string()),
+      src.to_string(),
+    );
+    let comments = SingleThreadedComments::default();
+    let lexer = Lexer::new(
+      get_default_ts_config(),
+      JscTarget::Es2019,
+      StringInput::from(&*source_file),
+      Some(&comments),
+    );
+    let program = Parser::new_from(lexer)
+      .parse_program()
+      .expect("Failed to parse");
+
+    let mut linter = LinterBuilder::default()
+      .rules(vec![NoExplicitAny::new(), NoUndef::new()])
+      .build();
+    let diagnostics = linter.lint_program(
+      "pre_parsed.ts".to_string(),
+      &program,
+      source_map,
+      comments,
+    );
+
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "no-explicit-any", 3, 7, src);
+    assert_diagnostic(&diagnostics[1], "no-undef", 3, 13, src);
+  }
+
+  #[test]
+  fn lint_rule_timings() {
+    use crate::timings::RuleTimings;
+    use std::sync::{Arc, Mutex};
+
+    let rule_timings = Arc::new(Mutex::new(RuleTimings::new()));
+    for src in &["debugger;", "var a = 1;"] {
+      let mut linter = LinterBuilder::default()
+        .rules(get_recommended_rules())
+        .rule_timings(Arc::clone(&rule_timings))
+        .build();
+      linter
+        .lint("lint_test.ts".to_string(), src.to_string())
+        .expect("Failed to lint");
+    }
+
+    let report = rule_timings.lock().unwrap().report();
+    let mut codes: Vec<&str> =
+      report.iter().map(|(code, _)| code.as_str()).collect();
+    codes.sort_unstable();
+    let mut expected: Vec<&str> =
+      get_recommended_rules().iter().map(|r| r.code()).collect();
+    expected.sort_unstable();
+    assert_eq!(codes, expected);
+  }
+
+  #[test]
+  fn lint_metrics() {
+    use crate::metrics::FileMetrics;
+    use crate::rules::cognitive_complexity::CognitiveComplexity;
+    use crate::rules::max_lines::MaxLines;
+    use serde_json::json;
+    use swc_ecmascript::ast::Program;
+
+    // Records a metric twice, to check that the values are summed up.
+    struct MetricRule;
+
+    impl LintRule for MetricRule {
+      fn new() -> Box<Self> {
+        Box::new(MetricRule)
+      }
+
+      fn code(&self) -> &'static str {
+        "metric-rule"
+      }
+
+      fn lint_program(&self, context: &mut Context, _program: &Program) {
+        context.add_metric("runs", 1.0);
+        context.add_metric("runs", 0.5);
+      }
+    }
+
+    let mut linter = LinterBuilder::default()
+      .rules(vec![
+        MetricRule::new(),
+        MaxLines::new(),
+        CognitiveComplexity::new(),
+      ])
+      .build();
+    let (_, diagnostics) = linter
+      .lint(
+        "lint_test.ts".to_string(),
+        "function f(a) {\n  if (a) {\n    return 1;\n  }\n}\n".to_string(),
+      )
+      .expect("Failed to lint");
+    assert!(diagnostics.is_empty());
+
+    let file_metrics = FileMetrics {
+      filename: "lint_test.ts".to_string(),
+      metrics: linter.metrics().clone(),
+    };
+    assert_eq!(
+      serde_json::to_value(&file_metrics).unwrap(),
+      json!({
+        "filename": "lint_test.ts",
+        "metrics": {
+          "cognitive-complexity": 1.0,
+          "lines": 5.0,
+          "runs": 1.5,
+        },
+      })
+    );
+  }
+
+  #[test]
+  fn lint_with_cache() {
+    use crate::cache::LintCache;
+    use crate::rules::no_debugger::NoDebugger;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use swc_ecmascript::ast::Program;
+
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+    // Counts how many times the linter actually runs rules.
+    struct CountingRule;
+
+    impl LintRule for CountingRule {
+      fn new() -> Box<Self> {
+        Box::new(CountingRule)
+      }
+
+      fn code(&self) -> &'static str {
+        "counting-rule"
+      }
+
+      fn lint_program(&self, _context: &mut Context, _program: &Program) {
+        RUNS.fetch_add(1, Ordering::SeqCst);
+      }
+    }
+
+    let cache = Rc::new(RefCell::new(LintCache::new()));
+    let lint_cached = |source: &str, rules: Vec<Box<dyn LintRule>>| {
+      let mut linter = LinterBuilder::default()
+        .rules(rules)
+        .cache(cache.clone())
+        .build();
+      let (source_file, diagnostics) = linter
+        .lint("lint_test.ts".to_string(), source.to_string())
+        .expect("Failed to lint");
+      assert_eq!(&*source_file.src, source);
+      diagnostics
+    };
+
+    let diagnostics =
+      lint_cached("debugger;", vec![NoDebugger::new(), CountingRule::new()]);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+    assert_eq!(cache.borrow().len(), 1);
+
+    // Same source and rules: served from the cache
+    let diagnostics =
+      lint_cached("debugger;", vec![CountingRule::new(), NoDebugger::new()]);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, "no-debugger");
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+
+    // Changed source
+    lint_cached("debugger; ", vec![NoDebugger::new(), CountingRule::new()]);
+    assert_eq!(RUNS.load(Ordering::SeqCst), 2);
+
+    // Changed rules
+    let diagnostics = lint_cached("debugger;", vec![CountingRule::new()]);
+    assert!(diagnostics.is_empty());
+    assert_eq!(RUNS.load(Ordering::SeqCst), 3);
+    assert_eq!(cache.borrow().len(), 3);
+  }
+
+  #[test]
+  fn lint_with_cache_rule_options() {
+    use crate::cache::LintCache;
+    use crate::rules::max_lines::{MaxLines, MaxLinesOptions};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let cache = Rc::new(RefCell::new(LintCache::new()));
+    let lint_cached = |max: usize| {
+      let options = MaxLinesOptions {
+        max,
+        ..Default::default()
+      };
+      let mut linter = LinterBuilder::default()
+        .rules(vec![MaxLines::with_options(options)])
+        .cache(cache.clone())
+        .build();
+      let (_, diagnostics) = linter
+        .lint("lint_test.ts".to_string(), "a;\nb;\n".to_string())
+        .expect("Failed to lint");
+      diagnostics
+    };
+
+    assert!(lint_cached(300).is_empty());
+    // Only the options changed, so the cached result must not be used
+    assert_eq!(lint_cached(1).len(), 1);
+    assert!(lint_cached(300).is_empty());
+    assert_eq!(cache.borrow().len(), 2);
+  }
+
+  #[test]
+  fn lint_with_related_information() {
+    use swc_common::Spanned;
+    use swc_ecmascript::ast::Program;
+
+    // Reports the second statement, pointing back at the first one.
+    struct RelatedRule;
+
+    impl LintRule for RelatedRule {
+      fn new() -> Box<Self> {
+        Box::new(RelatedRule)
+      }
+
+      fn code(&self) -> &'static str {
+        "related-rule"
+      }
+
+      fn lint_program(&self, context: &mut Context, program: &Program) {
+        let spans: Vec<_> = match program {
+          Program::Module(module) => {
+            module.body.iter().map(|item| item.span()).collect()
+          }
+          Program::Script(script) => {
+            script.body.iter().map(|stmt| stmt.span()).collect()
+          }
+        };
+        context.add_diagnostic_with_related(
+          spans[1],
+          "related-rule",
+          "Duplicated statement",
+          vec![(spans[0], "First seen here".to_string())],
+        );
+      }
+    }
+
+    let src = "foo();
+  foo();
+";
+    let diagnostics = lint(src, false, false, vec![RelatedRule::new()]);
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "related-rule", 2, 2, src);
+
+    let related = &diagnostics[0].related;
+    assert_eq!(related.len(), 1);
+    assert_eq!(related[0].message, "First seen here");
+    assert_eq!(related[0].range.start.line, 1);
+    assert_eq!(related[0].range.start.col, 0);
+    assert_eq!(related[0].range.end.col, 6);
+
+    let json = serde_json::to_value(&diagnostics[0]).unwrap();
+    assert_eq!(json["range"]["start"]["line"], 2);
+    assert_eq!(json["related"][0]["range"]["start"]["line"], 1);
+    assert_eq!(json["related"][0]["message"], "First seen here");
+  }
+
+  #[test]
+  fn lint_with_callback() {
+    use crate::rules::no_debugger::NoDebugger;
+
+    let src = r#"debugger;
+// deno-lint-ignore no-debugger
+debugger;
+debugger;
+"#;
+    let mut linter = LinterBuilder::default()
+      .rules(vec![NoDebugger::new()])
+      .add_plugin(Box::new(FirstStatementPlugin))
+      .build();
+    let mut diagnostics = vec![];
+    linter
+      .lint_with_callback("lint_test.ts".to_string(), src.to_string(), |d| {
+        diagnostics.push(d)
+      })
+      .expect("Failed to lint");
+
+    assert_eq!(diagnostics.len(), 3);
+    assert_diagnostic(&diagnostics[0], "no-debugger", 1, 0, src);
+    assert_diagnostic(&diagnostics[1], "no-debugger", 4, 0, src);
+    assert_diagnostic(&diagnostics[2], "first-statement", 1, 0, src);
+  }
+
+  #[test]
+  fn lint_filtered() {
+    use crate::rules::no_debugger::NoDebugger;
+    use crate::rules::no_var::NoVar;
+
+    let src = r#"debugger;
+// deno-lint-ignore no-var
+var a = 1;
+var b = 2;
+"#;
+    let lint_filtered = |predicate: &dyn Fn(&dyn LintRule) -> bool| {
+      let mut linter = LinterBuilder::default()
+        .rules(vec![NoDebugger::new(), NoVar::new()])
+        .add_plugin(Box::new(FirstStatementPlugin))
+        .build();
+      let (_, diagnostics) = linter
+        .lint_filtered("lint_test.ts".to_string(), src.to_string(), predicate)
+        .expect("Failed to lint");
+      diagnostics
+    };
+
+    // The ignore directive of the skipped rule isn't reported as unused
+    let diagnostics = lint_filtered(&|rule| rule.code() == "no-debugger");
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "no-debugger", 1, 0, src);
+
+    let diagnostics = lint_filtered(&|rule| rule.code() == "plugin");
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "first-statement", 1, 0, src);
+
+    let diagnostics = lint_filtered(&|rule| rule.code() != "plugin");
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "no-debugger", 1, 0, src);
+    assert_diagnostic(&diagnostics[1], "no-var", 4, 0, src);
+  }
+
+  #[test]
+  fn context_is_rule_enabled() {
+    use crate::rules::no_debugger::NoDebugger;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use swc_ecmascript::ast::Program;
+
+    static DEBUGGER_ENABLED: AtomicBool = AtomicBool::new(false);
+    static VAR_ENABLED: AtomicBool = AtomicBool::new(true);
+
+    // Records which other rules are enabled.
+    struct QueryRule;
+
+    impl LintRule for QueryRule {
+      fn new() -> Box<Self> {
+        Box::new(QueryRule)
+      }
+
+      fn code(&self) -> &'static str {
+        "query-rule"
+      }
+
+      fn lint_program(&self, context: &mut Context, _program: &Program) {
+        DEBUGGER_ENABLED
+          .store(context.is_rule_enabled("no-debugger"), Ordering::SeqCst);
+        VAR_ENABLED.store(context.is_rule_enabled("no-var"), Ordering::SeqCst);
+      }
+    }
+
+    lint(
+      "let a;",
+      false,
+      false,
+      vec![QueryRule::new(), NoDebugger::new()],
+    );
+    assert!(DEBUGGER_ENABLED.load(Ordering::SeqCst));
+    assert!(!VAR_ENABLED.load(Ordering::SeqCst));
+  }
+
+  #[test]
+  fn context_scope_computed_once() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use swc_ecmascript::ast::Program;
+
+    static SCOPE_ADDRS: [AtomicUsize; 2] =
+      [AtomicUsize::new(0), AtomicUsize::new(1)];
+
+    // Records the address of the scope analysis it gets.
+    struct ScopeRule(usize);
+
+    impl LintRule for ScopeRule {
+      fn new() -> Box<Self> {
+        Box::new(ScopeRule(0))
+      }
+
+      fn code(&self) -> &'static str {
+        "scope-rule"
+      }
+
+      fn lint_program(&self, context: &mut Context, _program: &Program) {
+        let addr = context.scope() as *const _ as usize;
+        SCOPE_ADDRS[self.0].store(addr, Ordering::SeqCst);
+      }
+    }
+
+    lint(
+      "let a = 1;",
+      false,
+      false,
+      vec![Box::new(ScopeRule(0)), Box::new(ScopeRule(1))],
+    );
+    assert_eq!(
+      SCOPE_ADDRS[0].load(Ordering::SeqCst),
+      SCOPE_ADDRS[1].load(Ordering::SeqCst)
+    );
+  }
+
+  #[test]
+  fn lint_deny_warnings() {
+    use crate::diagnostic::Severity;
+    use swc_ecmascript::ast::Program;
+
+    // Warns about the first statement of the program.
+    struct WarningRule;
+
+    impl LintRule for WarningRule {
+      fn new() -> Box<Self> {
+        Box::new(WarningRule)
+      }
+
+      fn code(&self) -> &'static str {
+        "warning-rule"
+      }
+
+      fn severity(&self) -> Severity {
+        Severity::Warning
+      }
+
+      fn lint_program(&self, context: &mut Context, program: &Program) {
+        let span = first_statement_span(program);
+        context.add_diagnostic(span, "warning-rule", "Warning");
+      }
+    }
+
+    // Warns about the first statement of the program.
+    struct WarningPlugin;
+
+    impl Plugin for WarningPlugin {
+      fn run(
+        &mut self,
+        context: &mut Context,
+        program: Program,
+      ) -> anyhow::Result<()> {
+        let span = first_statement_span(&program);
+        context.add_diagnostic(span, "warning-plugin", "Warning");
+        for diagnostic in context.diagnostics.iter_mut() {
+          diagnostic.severity = Severity::Warning;
+        }
+        Ok(())
+      }
+    }
+
+    for &deny_warnings in &[false, true] {
+      let mut linter = LinterBuilder::default()
+        .rules(vec![WarningRule::new()])
+        .add_plugin(Box::new(WarningPlugin))
+        .deny_warnings(deny_warnings)
+        .build();
+      let (_, diagnostics) = linter
+        .lint("lint_test.ts".to_string(), "let a;".to_string())
+        .expect("Failed to lint");
+
+      let expected = if deny_warnings {
+        Severity::Error
+      } else {
+        Severity::Warning
+      };
+      assert_eq!(diagnostics.len(), 2);
+      assert_eq!(diagnostics[0].code, "warning-rule");
+      assert_eq!(diagnostics[1].code, "warning-plugin");
+      for diagnostic in &diagnostics {
+        assert_eq!(diagnostic.severity, expected);
+      }
+    }
+  }
+
+  #[test]
+  fn lint_plugin_error() {
+    use anyhow::Context as _;
+    use swc_ecmascript::ast::Program;
+
+    // Fails to parse the diagnostics of its rules.
+    struct MalformedPlugin;
+
+    impl Plugin for MalformedPlugin {
+      fn run(
+        &mut self,
+        _context: &mut Context,
+        _program: Program,
+      ) -> anyhow::Result<()> {
+        serde_json::from_str::<Vec<String>>("[{}]")
+          .context("Plugin malformed.ts failed")?;
+        Ok(())
+      }
+    }
+
+    // Reports the start of the program.
+    struct StartPlugin;
+
+    impl Plugin for StartPlugin {
+      fn run(
+        &mut self,
+        context: &mut Context,
+        program: Program,
+      ) -> anyhow::Result<()> {
+        use swc_common::Spanned;
+        context.add_diagnostic(program.span(), "start", "Start");
+        Ok(())
+      }
+    }
+
+    let src = "let a;\nlet b;\n";
+    let mut linter = LinterBuilder::default()
+      .add_plugin(Box::new(MalformedPlugin))
+      .add_plugin(Box::new(StartPlugin))
+      .build();
+    let (_, diagnostics) = linter
+      .lint("lint_test.ts".to_string(), src.to_string())
+      .expect("Failed to lint");
+
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "plugin-error", 1, 0, src);
+    assert!(diagnostics[0]
+      .message
+      .starts_with("Plugin malformed.ts failed: "));
+    assert_diagnostic(&diagnostics[1], "start", 1, 0, src);
+  }
+
+  #[test]
+  fn summarize_results() {
+    use crate::diagnostic::{FileDiagnostics, Severity};
+    use crate::rules::no_debugger::NoDebugger;
+
+    let mut foo = lint(
+      "debugger;\ndebugger;\n",
+      false,
+      false,
+      vec![NoDebugger::new()],
+    );
+    foo[1].severity = Severity::Warning;
+    let mut bar = lint("debugger;\n", false, false, vec![NoDebugger::new()]);
+    bar[0].severity = Severity::Warning;
+    let results = vec![
+      FileDiagnostics {
+        filename: "foo.ts".to_string(),
+        diagnostics: foo,
+      },
+      FileDiagnostics {
+        filename: "bar.ts".to_string(),
+        diagnostics: bar,
+      },
+      FileDiagnostics {
+        filename: "baz.ts".to_string(),
+        diagnostics: vec![],
+      },
+    ];
+
+    let summary = summarize(&results);
+    assert_eq!(
+      summary,
+      Summary {
+        error_count: 1,
+        warning_count: 2,
+        file_count: 3,
+      }
+    );
+    assert_ne!(summary.exit_code(), 0);
+    assert_eq!(
+      summary.to_string(),
+      "Found 1 error and 2 warnings in 3 files"
+    );
+
+    let summary = summarize(&results[1..]);
+    assert_eq!(summary.error_count, 0);
+    assert_eq!(summary.exit_code(), 0);
+    assert_eq!(
+      summary.to_string(),
+      "Found 0 errors and 1 warning in 2 files"
+    );
+  }
+
+  #[test]
+  fn diagnostic_with_hint_and_fix() {
+    use crate::rules::valid_typeof::ValidTypeof;
+
+    let src = "typeof foo === 'strnig';\n";
+    let diagnostics = lint(src, false, false, vec![ValidTypeof::new()]);
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "valid-typeof", 1, 15, src);
+    assert_eq!(
+      diagnostics[0].hint.as_deref(),
+      Some("Did you mean \"string\"?")
+    );
+
+    let fix = diagnostics[0].fix.as_ref().expect("Fix should be stored");
+    assert_eq!(fix.description, "Replace with \"string\"");
+    assert_eq!(fix.changes.len(), 1);
+    assert_eq!(fix.changes[0].new_text, "'string'");
+    assert_eq!(fix.changes[0].range.start.col, 15);
+    assert_eq!(fix.changes[0].range.end.col, 23);
+
+    let mut linter = LinterBuilder::default()
+      .rules(vec![ValidTypeof::new()])
+      .build();
+    let (fixed, diagnostics) = linter
+      .lint_and_fix("lint_test.ts".to_string(), src.to_string())
+      .expect("Failed to lint");
+    assert_eq!(fixed, "typeof foo === 'string';\n");
+    assert!(diagnostics.is_empty());
+  }
+
+  #[test]
+  fn lint_and_fix_multiple_passes() {
+    use crate::rules::arrow_body_style::ArrowBodyStyle;
+    use crate::rules::no_debugger::NoDebugger;
+    use crate::rules::prefer_arrow_callback::PreferArrowCallback;
+
+    // The arrow function created by the first pass gets its body fixed by
+    // the second one
+    let src = "arr.map(function (x) { return x; });\ndebugger;\n";
+    let mut linter = LinterBuilder::default()
+      .rules(vec![
+        ArrowBodyStyle::new(),
+        NoDebugger::new(),
+        PreferArrowCallback::new(),
+      ])
+      .build();
+    let (fixed, diagnostics) = linter
+      .lint_and_fix("lint_test.ts".to_string(), src.to_string())
+      .expect("Failed to lint");
+
+    assert_eq!(fixed, "arr.map((x) => x);\ndebugger;\n");
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "no-debugger", 2, 0, &fixed);
+    assert!(diagnostics[0].fix.is_none());
+  }
+
+  #[test]
+  fn lint_and_fix_overlapping_fixes() {
+    use crate::diagnostic::LintFix;
+    use swc_ecmascript::ast::{Expr, ModuleItem, Program, Stmt};
+
+    // Suggests parenthesizing expression statements, and renaming `x` to `y`
+    // with a lower priority.
+    struct OverlappingFixRule;
+
+    impl LintRule for OverlappingFixRule {
+      fn new() -> Box<Self> {
+        Box::new(OverlappingFixRule)
+      }
+
+      fn code(&self) -> &'static str {
+        "overlapping-fix"
+      }
+
+      fn lint_program(&self, context: &mut Context, program: &Program) {
+        let stmts: Vec<&Stmt> = match program {
+          Program::Module(module) => module
+            .body
+            .iter()
+            .filter_map(|item| match item {
+              ModuleItem::Stmt(stmt) => Some(stmt),
+              ModuleItem::ModuleDecl(_) => None,
+            })
+            .collect(),
+          Program::Script(script) => script.body.iter().collect(),
+        };
+        for stmt in stmts {
+          let expr = match stmt {
+            Stmt::Expr(expr_stmt) => &*expr_stmt.expr,
+            _ => continue,
+          };
+          let ident = match expr {
+            Expr::Ident(ident) => {
+              let text = format!("({})", ident.sym);
+              let fix = LintFix {
+                description: "Parenthesize".to_string(),
+                changes: vec![context.create_fix_change(ident.span, text)],
+                priority: 1,
+              };
+              let code = "overlapping-fix";
+              context.add_diagnostic_with_fix(ident.span, code, "Paren", fix);
+              ident
+            }
+            Expr::Paren(paren_expr) => match &*paren_expr.expr {
+              Expr::Ident(ident) => ident,
+              _ => continue,
+            },
+            _ => continue,
+          };
+          if ident.sym == *"x" {
+            let fix = LintFix {
+              description: "Rename".to_string(),
+              changes: vec![context.create_fix_change(ident.span, "y")],
+              priority: 0,
+            };
+            let code = "overlapping-fix";
+            context.add_diagnostic_with_fix(ident.span, code, "Rename", fix);
+          }
+        }
+      }
+    }
+
+    // The rename overlaps with the parenthesizing, so it's applied in the
+    // second pass
+    let mut linter = LinterBuilder::default()
+      .rules(vec![OverlappingFixRule::new()])
+      .build();
+    let (fixed, diagnostics) = linter
+      .lint_and_fix("lint_test.ts".to_string(), "x;\nz;\n".to_string())
+      .expect("Failed to lint");
+    assert_eq!(fixed, "(y);\n(z);\n");
+    assert!(diagnostics.is_empty());
+
+    let mut linter = LinterBuilder::default()
+      .rules(vec![OverlappingFixRule::new()])
+      .max_fix_passes(1)
+      .build();
+    let (fixed, diagnostics) = linter
+      .lint_and_fix("lint_test.ts".to_string(), "x;".to_string())
+      .expect("Failed to lint");
+    assert_eq!(fixed, "(x);");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].message, "Rename");
+  }
+
+  #[test]
+  fn lint_and_fix_pass_limit() {
+    use crate::diagnostic::LintFix;
+    use swc_common::Spanned;
+    use swc_ecmascript::ast::Program;
+
+    // Always asks to prepend another semicolon.
+    struct EndlessFixRule;
+
+    impl LintRule for EndlessFixRule {
+      fn new() -> Box<Self> {
+        Box::new(EndlessFixRule)
+      }
+
+      fn code(&self) -> &'static str {
+        "endless-fix"
+      }
+
+      fn lint_program(&self, context: &mut Context, program: &Program) {
+        let span = program.span().shrink_to_lo();
+        let fix = LintFix {
+          description: "Prepend a semicolon".to_string(),
+          changes: vec![context.create_fix_change(span, ";")],
+          priority: 0,
+        };
+        context.add_diagnostic_with_fix(span, "endless-fix", "Endless", fix);
+      }
+    }
+
+    let mut linter = LinterBuilder::default()
+      .rules(vec![EndlessFixRule::new()])
+      .build();
+    let (fixed, diagnostics) = linter
+      .lint_and_fix("lint_test.ts".to_string(), "a;".to_string())
+      .expect("Failed to lint");
+
+    assert_eq!(fixed, format!("{}a;", ";".repeat(MAX_FIX_PASSES)));
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].fix.is_some());
+
+    let mut linter = LinterBuilder::default()
+      .rules(vec![EndlessFixRule::new()])
+      .max_fix_passes(2)
+      .build();
+    let (fixed, _) = linter
+      .lint_and_fix("lint_test.ts".to_string(), "a;".to_string())
+      .expect("Failed to lint");
+    assert_eq!(fixed, ";;a;");
+  }
 }
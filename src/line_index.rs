@@ -0,0 +1,88 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use crate::diagnostic::Position;
+use swc_common::sync::Lrc;
+use swc_common::BytePos;
+use swc_common::SourceFile;
+
+/// Resolves byte positions of a single file to lines and columns.
+///
+/// The offsets of all line starts are collected once, so that every lookup is
+/// a binary search followed by counting the characters before the position on
+/// its line.
+pub(crate) struct LineIndex {
+  src: Lrc<String>,
+  start_pos: BytePos,
+  /// Byte offsets of the start of every line, relative to `start_pos`.
+  line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+  pub fn new(source_file: &SourceFile) -> Self {
+    let line_starts = std::iter::once(0)
+      .chain(
+        source_file
+          .src
+          .bytes()
+          .enumerate()
+          .filter(|(_, b)| *b == b'\n')
+          .map(|(i, _)| i + 1),
+      )
+      .collect();
+    LineIndex {
+      src: source_file.src.clone(),
+      start_pos: source_file.start_pos,
+      line_starts,
+    }
+  }
+
+  /// Returns the position of `pos`, which must be in the file.
+  ///
+  /// Like swc's `SourceMap`, lines start at 1 and columns count characters
+  /// from the start of the line, starting at 0.
+  pub fn position(&self, pos: BytePos) -> Position {
+    let offset = (pos - self.start_pos).0 as usize;
+    let line = match self.line_starts.binary_search(&offset) {
+      Ok(line) => line,
+      Err(next_line) => next_line - 1,
+    };
+    let line_start = self.line_starts[line];
+    Position {
+      line: line + 1,
+      col: self.src[line_start..offset].chars().count(),
+      byte_pos: offset,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use swc_common::{FileName, SourceMap};
+
+  #[test]
+  fn line_index_matches_source_map() {
+    let source_map = SourceMap::default();
+    // Positions of the second file don't start at 0
+    source_map.new_source_file(
+      FileName::Custom("first.ts".to_string()),
+      "const a = 1;\n".to_string(),
+    );
+    let src = "let x = \"héllo\";\r\n\tfoo(x);\n\n// 日本語 🦕\nbar();";
+    let source_file = source_map
+      .new_source_file(FileName::Custom("second.ts".to_string()), src.into());
+    let line_index = LineIndex::new(&source_file);
+
+    let offsets = src
+      .char_indices()
+      .map(|(i, _)| i)
+      .chain(std::iter::once(src.len()));
+    for offset in offsets {
+      let pos = source_file.start_pos + BytePos(offset as u32);
+      let expected = Position::new(
+        source_map.lookup_byte_offset(pos).pos,
+        source_map.lookup_char_pos(pos),
+      );
+      assert_eq!(line_index.position(pos), expected, "offset {}", offset);
+    }
+  }
+}
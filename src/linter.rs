@@ -2,16 +2,32 @@
 use crate::ast_parser::get_default_ts_config;
 use crate::ast_parser::AstParser;
 use crate::ast_parser::SwcDiagnosticBuffer;
+use crate::cache::{CacheKey, LintCache};
 use crate::control_flow::ControlFlow;
-use crate::diagnostic::{LintDiagnostic, Position, Range};
+use crate::diagnostic::{
+  FileDiagnostics, LintDiagnostic, LintFix, LintFixChange, Range,
+  RelatedInformation, Severity,
+};
+use crate::fix::apply_fixes;
 use crate::ignore_directives::parse_ignore_comment;
 use crate::ignore_directives::parse_ignore_directives;
 use crate::ignore_directives::IgnoreDirective;
+use crate::line_index::LineIndex;
+use crate::metrics::Metrics;
 use crate::rules::{get_all_rules, LintRule};
 use crate::scopes::Scope;
+use crate::timings::{RuleTimings, PLUGIN_TIMING_CODE};
+use crate::type_context::TypeContext;
+use once_cell::unsync::OnceCell;
+use rayon::prelude::*;
 use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
 use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 use swc_common::comments::SingleThreadedComments;
 use swc_common::BytePos;
@@ -19,23 +35,36 @@ use swc_common::SourceMap;
 use swc_common::Span;
 use swc_common::Spanned;
 use swc_common::{comments::Comment, SyntaxContext};
+use swc_ecmascript::ast::Program;
 use swc_ecmascript::parser::Syntax;
+use swc_ecmascript::transforms::resolver::ts_resolver;
+use swc_ecmascript::visit::FoldWith;
 
 pub use swc_common::SourceFile;
 
+/// Default maximum number of times `Linter::lint_and_fix` applies fixes to a
+/// file.
+pub const MAX_FIX_PASSES: usize = 10;
+
 pub struct Context {
   pub file_name: String,
   pub diagnostics: Vec<LintDiagnostic>,
+  /// Codes of the builtin rules the linter runs.
+  rule_codes: HashSet<&'static str>,
   plugin_codes: HashSet<String>,
   pub source_map: Rc<SourceMap>,
   pub(crate) leading_comments: HashMap<BytePos, Vec<Comment>>,
   pub(crate) trailing_comments: HashMap<BytePos, Vec<Comment>>,
   pub ignore_directives: RefCell<Vec<IgnoreDirective>>,
-  pub(crate) scope: Scope,
-  // TODO(magurotuna): Making control_flow public is just needed for implementing plugin prototype.
-  // It will be likely possible to revert it to `pub(crate)` later.
-  pub control_flow: ControlFlow,
+  /// The program being linted, kept for the analyses computed on demand.
+  program: Rc<Program>,
+  scope: OnceCell<Scope>,
+  control_flow: OnceCell<ControlFlow>,
+  type_context: OnceCell<TypeContext>,
+  /// Built from the file of the first span a diagnostic is created for.
+  line_index: OnceCell<LineIndex>,
   pub(crate) top_level_ctxt: SyntaxContext,
+  metrics: Metrics,
 }
 
 impl Context {
@@ -62,6 +91,75 @@ impl Context {
     self.diagnostics.push(diagnostic);
   }
 
+  pub fn add_diagnostic_with_fix(
+    &mut self,
+    span: Span,
+    code: impl ToString,
+    message: impl ToString,
+    fix: LintFix,
+  ) {
+    let mut diagnostic = self.create_diagnostic(span, code, message, None);
+    diagnostic.fix = Some(fix);
+    self.diagnostics.push(diagnostic);
+  }
+
+  pub fn add_diagnostic_with_hint_and_fix(
+    &mut self,
+    span: Span,
+    code: impl ToString,
+    message: impl ToString,
+    hint: impl ToString,
+    fix: LintFix,
+  ) {
+    let mut diagnostic =
+      self.create_diagnostic(span, code, message, Some(hint.to_string()));
+    diagnostic.fix = Some(fix);
+    self.diagnostics.push(diagnostic);
+  }
+
+  /// Adds a diagnostic that also points at other locations, each with a
+  /// message explaining how it relates to the problem at `span`.
+  pub fn add_diagnostic_with_related(
+    &mut self,
+    span: Span,
+    code: impl ToString,
+    message: impl ToString,
+    related: Vec<(Span, String)>,
+  ) {
+    let mut diagnostic = self.create_diagnostic(span, code, message, None);
+    diagnostic.related = related
+      .into_iter()
+      .map(|(span, message)| RelatedInformation {
+        range: self.create_range(span),
+        message,
+      })
+      .collect();
+    self.diagnostics.push(diagnostic);
+  }
+
+  /// Creates a change for `LintFix` that replaces the source covered by `span`
+  /// with `new_text`.
+  pub fn create_fix_change(
+    &self,
+    span: Span,
+    new_text: impl ToString,
+  ) -> LintFixChange {
+    LintFixChange {
+      new_text: new_text.to_string(),
+      range: self.create_range(span),
+    }
+  }
+
+  fn create_range(&self, span: Span) -> Range {
+    let line_index = self.line_index.get_or_init(|| {
+      LineIndex::new(&self.source_map.lookup_byte_offset(span.lo()).sf)
+    });
+    Range {
+      start: line_index.position(span.lo()),
+      end: line_index.position(span.hi()),
+    }
+  }
+
   fn create_diagnostic(
     &self,
     span: Span,
@@ -70,21 +168,15 @@ impl Context {
     maybe_hint: Option<String>,
   ) -> LintDiagnostic {
     let time_start = Instant::now();
-    let start = Position::new(
-      self.source_map.lookup_byte_offset(span.lo()).pos,
-      self.source_map.lookup_char_pos(span.lo()),
-    );
-    let end = Position::new(
-      self.source_map.lookup_byte_offset(span.hi()).pos,
-      self.source_map.lookup_char_pos(span.hi()),
-    );
-
     let diagnostic = LintDiagnostic {
-      range: Range { start, end },
+      range: self.create_range(span),
       filename: self.file_name.clone(),
       message: message.to_string(),
       code: code.to_string(),
       hint: maybe_hint,
+      severity: Severity::Error,
+      fix: None,
+      related: vec![],
     };
 
     let time_end = Instant::now();
@@ -95,9 +187,75 @@ impl Context {
     diagnostic
   }
 
+  /// Adds `value` to the metric called `name`, which starts at zero. Metrics
+  /// measure the file rather than report problems, and can be read with
+  /// `Linter::metrics` once the file is linted.
+  pub fn add_metric(&mut self, name: &str, value: f64) {
+    *self.metrics.entry(name.to_string()).or_insert(0.0) += value;
+  }
+
   pub fn set_plugin_codes(&mut self, codes: HashSet<String>) {
     self.plugin_codes = codes;
   }
+
+  /// Scope analysis of the program, computed by the first rule asking for it
+  /// and shared with the other rules.
+  pub(crate) fn scope(&self) -> &Scope {
+    self.scope.get_or_init(|| Scope::analyze(&self.program))
+  }
+
+  /// Control flow analysis of the program, computed by the first rule asking
+  /// for it and shared with the other rules.
+  // TODO(magurotuna): Making control_flow public is just needed for implementing plugin prototype.
+  // It will be likely possible to revert it to `pub(crate)` later.
+  pub fn control_flow(&self) -> &ControlFlow {
+    self
+      .control_flow
+      .get_or_init(|| ControlFlow::analyze(&self.program))
+  }
+
+  /// Returns true if `span` is inside a TypeScript type-only part of the
+  /// program, like a type annotation or an interface declaration. Lets rules
+  /// that visit types tell them apart from runtime code.
+  ///
+  /// The type-only parts are collected by the first rule asking for them.
+  pub fn is_in_type_context(&self, span: Span) -> bool {
+    self
+      .type_context
+      .get_or_init(|| TypeContext::analyze(&self.program))
+      .contains(span)
+  }
+
+  /// Returns true if the rule with `code` is run on this file, so that rules
+  /// can leave problems to a more specific rule. Plugin rules are known only
+  /// once their plugin has set its codes.
+  pub fn is_rule_enabled(&self, code: &str) -> bool {
+    self.rule_codes.contains(code) || self.plugin_codes.contains(code)
+  }
+
+  /// Passes the diagnostics reported since the last flush to `emit`, except
+  /// the ones suppressed by an ignore directive and the ones already passed.
+  fn flush_diagnostics(
+    &mut self,
+    seen: &mut HashSet<DiagnosticKey>,
+    emit: &mut dyn FnMut(LintDiagnostic),
+  ) {
+    let start = Instant::now();
+    let mut ignore_directives = self.ignore_directives.borrow_mut();
+    for diagnostic in self.diagnostics.drain(..) {
+      if !seen.insert(diagnostic_key(&diagnostic)) {
+        continue;
+      }
+      let ignored = ignore_directives.iter_mut().any(|ignore_directive| {
+        ignore_directive.maybe_ignore_diagnostic(&diagnostic)
+      });
+      if !ignored {
+        emit(diagnostic);
+      }
+    }
+    let end = Instant::now();
+    debug!("Context::flush_diagnostics took {:#?}", end - start);
+  }
 }
 
 pub struct LinterBuilder {
@@ -105,9 +263,13 @@ pub struct LinterBuilder {
   ignore_diagnostic_directive: String,
   lint_unused_ignore_directives: bool,
   lint_unknown_rules: bool,
+  deny_warnings: bool,
   syntax: swc_ecmascript::parser::Syntax,
-  rules: Vec<Box<dyn LintRule>>,
+  rules: Arc<Vec<Box<dyn LintRule>>>,
   plugins: Vec<Box<dyn Plugin>>,
+  cache: Option<Rc<RefCell<LintCache>>>,
+  max_fix_passes: usize,
+  rule_timings: Option<Arc<Mutex<RuleTimings>>>,
 }
 
 impl LinterBuilder {
@@ -117,22 +279,18 @@ impl LinterBuilder {
       ignore_diagnostic_directive: "deno-lint-ignore".to_string(),
       lint_unused_ignore_directives: true,
       lint_unknown_rules: true,
+      deny_warnings: false,
       syntax: get_default_ts_config(),
-      rules: vec![],
+      rules: Arc::new(vec![]),
       plugins: vec![],
+      cache: None,
+      max_fix_passes: MAX_FIX_PASSES,
+      rule_timings: None,
     }
   }
 
   pub fn build(self) -> Linter {
-    Linter::new(
-      self.ignore_file_directive,
-      self.ignore_diagnostic_directive,
-      self.lint_unused_ignore_directives,
-      self.lint_unknown_rules,
-      self.syntax,
-      self.rules,
-      self.plugins,
-    )
+    Linter::new(self)
   }
 
   pub fn ignore_file_directive(mut self, directive: &str) -> Self {
@@ -158,12 +316,26 @@ impl LinterBuilder {
     self
   }
 
+  /// Reports every diagnostic with `Severity::Warning` as `Severity::Error`,
+  /// including the ones reported by plugins.
+  pub fn deny_warnings(mut self, deny_warnings: bool) -> Self {
+    self.deny_warnings = deny_warnings;
+    self
+  }
+
   pub fn syntax(mut self, syntax: Syntax) -> Self {
     self.syntax = syntax;
     self
   }
 
   pub fn rules(mut self, rules: Vec<Box<dyn LintRule>>) -> Self {
+    self.rules = Arc::new(rules);
+    self
+  }
+
+  /// Sets rules that are shared with other linters, e.g. ones linting other
+  /// files on other threads.
+  pub fn shared_rules(mut self, rules: Arc<Vec<Box<dyn LintRule>>>) -> Self {
     self.rules = rules;
     self
   }
@@ -172,6 +344,33 @@ impl LinterBuilder {
     self.plugins.push(plugin);
     self
   }
+
+  /// Sets a cache that `Linter::lint` looks results up in before parsing,
+  /// and stores new results in. The cache can be shared by linters with
+  /// different settings, which get separate entries.
+  ///
+  /// Results are keyed by the codes, severities and options of the rules.
+  /// The cache isn't used when plugins are added, since their results can't
+  /// be keyed.
+  pub fn cache(mut self, cache: Rc<RefCell<LintCache>>) -> Self {
+    self.cache = Some(cache);
+    self
+  }
+
+  /// Sets how many times `Linter::lint_and_fix` applies fixes to a file at
+  /// most. Defaults to `MAX_FIX_PASSES`.
+  pub fn max_fix_passes(mut self, max_fix_passes: usize) -> Self {
+    self.max_fix_passes = max_fix_passes;
+    self
+  }
+
+  /// Records the time each rule takes on every linted file in
+  /// `rule_timings`, which can be shared by several linters to sum up the
+  /// times over many files. Results served from the cache aren't timed.
+  pub fn rule_timings(mut self, rule_timings: Arc<Mutex<RuleTimings>>) -> Self {
+    self.rule_timings = Some(rule_timings);
+    self
+  }
 }
 
 pub struct Linter {
@@ -181,34 +380,45 @@ pub struct Linter {
   ignore_diagnostic_directive: String,
   lint_unused_ignore_directives: bool,
   lint_unknown_rules: bool,
+  deny_warnings: bool,
   syntax: Syntax,
-  rules: Vec<Box<dyn LintRule>>,
+  /// Shared with the linters of `lint_paths`.
+  rules: Arc<Vec<Box<dyn LintRule>>>,
   plugins: Vec<Box<dyn Plugin>>,
+  cache: Option<Rc<RefCell<LintCache>>>,
+  max_fix_passes: usize,
+  rule_timings: Option<Arc<Mutex<RuleTimings>>>,
+  metrics: Metrics,
 }
 
 impl Linter {
-  fn new(
-    ignore_file_directive: String,
-    ignore_diagnostic_directive: String,
-    lint_unused_ignore_directives: bool,
-    lint_unknown_rules: bool,
-    syntax: Syntax,
-    rules: Vec<Box<dyn LintRule>>,
-    plugins: Vec<Box<dyn Plugin>>,
-  ) -> Self {
+  fn new(builder: LinterBuilder) -> Self {
     Linter {
       has_linted: false,
       ast_parser: AstParser::new(),
-      ignore_file_directive,
-      ignore_diagnostic_directive,
-      lint_unused_ignore_directives,
-      lint_unknown_rules,
-      syntax,
-      rules,
-      plugins,
+      ignore_file_directive: builder.ignore_file_directive,
+      ignore_diagnostic_directive: builder.ignore_diagnostic_directive,
+      lint_unused_ignore_directives: builder.lint_unused_ignore_directives,
+      lint_unknown_rules: builder.lint_unknown_rules,
+      deny_warnings: builder.deny_warnings,
+      syntax: builder.syntax,
+      rules: builder.rules,
+      plugins: builder.plugins,
+      cache: builder.cache,
+      max_fix_passes: builder.max_fix_passes,
+      rule_timings: builder.rule_timings,
+      metrics: Metrics::new(),
     }
   }
 
+  /// Returns the metrics the rules and plugins recorded with
+  /// `Context::add_metric` while linting the file. Results served from the
+  /// cache have no metrics, and `lint_and_fix` keeps the ones of the last
+  /// linting.
+  pub fn metrics(&self) -> &Metrics {
+    &self.metrics
+  }
+
   pub fn lint(
     &mut self,
     file_name: String,
@@ -224,37 +434,333 @@ impl Linter {
     self.has_linted = true;
     let start = Instant::now();
 
+    let cache_key = if self.cache.is_some() && self.plugins.is_empty() {
+      Some(CacheKey::new(&file_name, &source_code, self.rules_hash()))
+    } else {
+      None
+    };
+    if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+      if let Some(diagnostics) = cache.borrow().get(key) {
+        debug!("Linter::lint used cached diagnostics for {}", file_name);
+        let source_file = self.ast_parser.source_map.new_source_file(
+          swc_common::FileName::Custom(file_name),
+          source_code,
+        );
+        return Ok((source_file, diagnostics.clone()));
+      }
+    }
+
+    let (program, comments) = self.parse_program(&file_name, &source_code)?;
+    let source_map = self.ast_parser.source_map.clone();
+    let diagnostics = self.lint_resolved_program(
+      file_name.clone(),
+      program,
+      comments,
+      source_map,
+      &|_| true,
+    );
+
+    let source_file = self.get_source_file(file_name);
+    if let (Some(cache), Some(key)) = (&self.cache, cache_key) {
+      cache.borrow_mut().insert(key, diagnostics.clone());
+    }
+    let end = Instant::now();
+    debug!("Linter::lint took {:#?}", end - start);
+    Ok((source_file, diagnostics))
+  }
+
+  /// Lints a file and applies the fixes of the diagnostics, repeating both
+  /// until no more fixes apply, like `eslint --fix`. Returns the fixed source
+  /// and the diagnostics of its last linting.
+  ///
+  /// Fixes may reveal or cause other problems, so the fixed source is linted
+  /// again after each pass. When fixes overlap, only the one with the highest
+  /// priority is applied in a pass. There are at most as many passes as set
+  /// with `LinterBuilder::max_fix_passes`, in case fixes keep reintroducing
+  /// problems; the diagnostics returned then may still have fixes. Otherwise
+  /// none of them has a fix. The cache isn't used.
+  pub fn lint_and_fix(
+    &mut self,
+    file_name: String,
+    source_code: String,
+  ) -> Result<(String, Vec<LintDiagnostic>), SwcDiagnosticBuffer> {
+    assert!(
+      !self.has_linted,
+      "Linter can be used only on a single module."
+    );
+    self.has_linted = true;
+    let start = Instant::now();
+
+    let mut source_code = source_code;
+    let mut passes = 0;
+    let diagnostics = loop {
+      let (program, comments) = self.parse_program(&file_name, &source_code)?;
+      let source_map = self.ast_parser.source_map.clone();
+      let diagnostics = self.lint_resolved_program(
+        file_name.clone(),
+        program,
+        comments,
+        source_map,
+        &|_| true,
+      );
+      if passes == self.max_fix_passes {
+        break diagnostics;
+      }
+
+      let report = apply_fixes(
+        &source_code,
+        diagnostics.iter().filter_map(|d| d.fix.as_ref()),
+      );
+      if report.applied.is_empty() {
+        break diagnostics;
+      }
+      for fix in &report.skipped {
+        debug!(
+          "Linter::lint_and_fix skipped overlapping fix: {}",
+          fix.description
+        );
+      }
+      source_code = report.source;
+      passes += 1;
+    };
+
+    let end = Instant::now();
+    debug!(
+      "Linter::lint_and_fix took {:#?} for {} passes",
+      end - start,
+      passes
+    );
+    Ok((source_code, diagnostics))
+  }
+
+  /// Lints a file like `lint`, but passes diagnostics to `callback` as they
+  /// are produced instead of collecting them, so that they don't all need to
+  /// be held in memory.
+  ///
+  /// Diagnostics suppressed by ignore directives are left out. The others are
+  /// passed in the order they were reported, rule by rule and then plugin by
+  /// plugin, rather than sorted by line. The cache isn't used.
+  pub fn lint_with_callback(
+    &mut self,
+    file_name: String,
+    source_code: String,
+    mut callback: impl FnMut(LintDiagnostic),
+  ) -> Result<Rc<swc_common::SourceFile>, SwcDiagnosticBuffer> {
+    assert!(
+      !self.has_linted,
+      "Linter can be used only on a single module."
+    );
+    self.has_linted = true;
+    let start = Instant::now();
+
+    let (program, comments) = self.parse_program(&file_name, &source_code)?;
+    let source_map = self.ast_parser.source_map.clone();
+    self.lint_resolved_program_with_callback(
+      file_name.clone(),
+      program,
+      comments,
+      source_map,
+      &|_| true,
+      &mut callback,
+    );
+
+    let end = Instant::now();
+    debug!("Linter::lint_with_callback took {:#?}", end - start);
+    Ok(self.get_source_file(file_name))
+  }
+
+  /// Lints a file like `lint`, but only runs the rules for which `predicate`
+  /// returns true, e.g. to run a cheap subset of rules while typing. The
+  /// plugins are passed to `predicate` together, as a rule with the code
+  /// `"plugin"`. The cache isn't used.
+  pub fn lint_filtered(
+    &mut self,
+    file_name: String,
+    source_code: String,
+    predicate: impl Fn(&dyn LintRule) -> bool,
+  ) -> Result<
+    (Rc<swc_common::SourceFile>, Vec<LintDiagnostic>),
+    SwcDiagnosticBuffer,
+  > {
+    assert!(
+      !self.has_linted,
+      "Linter can be used only on a single module."
+    );
+    self.has_linted = true;
+    let start = Instant::now();
+
+    let (program, comments) = self.parse_program(&file_name, &source_code)?;
+    let source_map = self.ast_parser.source_map.clone();
+    let diagnostics = self.lint_resolved_program(
+      file_name.clone(),
+      program,
+      comments,
+      source_map,
+      &predicate,
+    );
+
+    let end = Instant::now();
+    debug!("Linter::lint_filtered took {:#?}", end - start);
+    Ok((self.get_source_file(file_name), diagnostics))
+  }
+
+  /// Lints a program that has already been parsed, e.g. by a tool that uses
+  /// swc itself, skipping the parsing step of `lint`.
+  ///
+  /// `source_map` must be the one `program` was parsed with, and `comments`
+  /// the comments collected while parsing it; they are used to report
+  /// locations and to find ignore directives. `program` must not have been
+  /// processed by swc's resolver yet, since the linter applies its own.
+  pub fn lint_program(
+    &mut self,
+    file_name: String,
+    program: &swc_ecmascript::ast::Program,
+    source_map: Rc<SourceMap>,
+    comments: SingleThreadedComments,
+  ) -> Vec<LintDiagnostic> {
+    assert!(
+      !self.has_linted,
+      "Linter can be used only on a single module."
+    );
+    self.has_linted = true;
+    let start = Instant::now();
+
+    let top_level_mark = self.ast_parser.top_level_mark;
+    let program = swc_common::GLOBALS.set(&self.ast_parser.globals, || {
+      program.clone().fold_with(&mut ts_resolver(top_level_mark))
+    });
+    let diagnostics = self.lint_resolved_program(
+      file_name,
+      program,
+      comments,
+      source_map,
+      &|_| true,
+    );
+
+    let end = Instant::now();
+    debug!("Linter::lint_program took {:#?}", end - start);
+    diagnostics
+  }
+
+  /// Lints files at `paths` concurrently. Results are returned in the same
+  /// order as `paths`, regardless of the order in which linting completes.
+  ///
+  /// Every file is linted on a worker thread by a separate `Linter` with the
+  /// same settings and rules as this one, recording rule timings in the same
+  /// place. The cache isn't used. Plugins can't be shared between threads,
+  /// so an error is returned if any are set.
+  pub fn lint_paths(
+    &self,
+    paths: Vec<PathBuf>,
+  ) -> anyhow::Result<Vec<(PathBuf, anyhow::Result<Vec<LintDiagnostic>>)>> {
+    if !self.plugins.is_empty() {
+      anyhow::bail!("Plugins can't be run by Linter::lint_paths");
+    }
+
+    let rules = &self.rules;
+    let ignore_file_directive = self.ignore_file_directive.as_str();
+    let ignore_diagnostic_directive = self.ignore_diagnostic_directive.as_str();
+    let lint_unused_ignore_directives = self.lint_unused_ignore_directives;
+    let lint_unknown_rules = self.lint_unknown_rules;
+    let deny_warnings = self.deny_warnings;
+    let syntax = self.syntax;
+    let max_fix_passes = self.max_fix_passes;
+    let rule_timings = self.rule_timings.as_ref();
+
+    let results = paths
+      .into_par_iter()
+      .map(|path| {
+        let result = std::fs::read_to_string(&path)
+          .map_err(anyhow::Error::from)
+          .and_then(|source_code| {
+            let mut linter = Linter::new(LinterBuilder {
+              ignore_file_directive: ignore_file_directive.to_string(),
+              ignore_diagnostic_directive: ignore_diagnostic_directive
+                .to_string(),
+              lint_unused_ignore_directives,
+              lint_unknown_rules,
+              deny_warnings,
+              syntax,
+              rules: Arc::clone(rules),
+              plugins: vec![],
+              cache: None,
+              max_fix_passes,
+              rule_timings: rule_timings.cloned(),
+            });
+            let (_, diagnostics) =
+              linter.lint(path.to_string_lossy().to_string(), source_code)?;
+            Ok(diagnostics)
+          });
+        (path, result)
+      })
+      .collect();
+    Ok(results)
+  }
+
+  fn parse_program(
+    &self,
+    file_name: &str,
+    source_code: &str,
+  ) -> Result<
+    (swc_ecmascript::ast::Program, SingleThreadedComments),
+    SwcDiagnosticBuffer,
+  > {
+    let start = Instant::now();
     let parse_result =
       self
         .ast_parser
-        .parse_program(&file_name, self.syntax, &source_code);
-    let end_parse_program = Instant::now();
-    debug!(
-      "ast_parser.parse_program took {:#?}",
-      end_parse_program - start
-    );
-    let (program, comments) = parse_result?;
-    let diagnostics = self.lint_program(file_name.clone(), program, comments);
+        .parse_program(file_name, self.syntax, source_code);
+    let end = Instant::now();
+    debug!("ast_parser.parse_program took {:#?}", end - start);
+    parse_result
+  }
 
-    let source_file = self
+  fn get_source_file(&self, file_name: String) -> Rc<swc_common::SourceFile> {
+    self
       .ast_parser
       .source_map
       .get_source_file(&swc_common::FileName::Custom(file_name))
-      .unwrap();
-    let end = Instant::now();
-    debug!("Linter::lint took {:#?}", end - start);
-    Ok((source_file, diagnostics))
+      .unwrap()
   }
 
-  fn filter_diagnostics(&self, context: &mut Context) -> Vec<LintDiagnostic> {
-    let start = Instant::now();
-    let ignore_directives = context.ignore_directives.clone();
-    let diagnostics = &context.diagnostics;
+  /// Hashes everything besides the source that affects the result of `lint`.
+  fn rules_hash(&self) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    let mut rules: Vec<(&str, Severity, Option<String>)> = self
+      .rules
+      .iter()
+      .map(|rule| {
+        let options = rule.options().map(|options| options.to_string());
+        (rule.code(), rule.severity(), options)
+      })
+      .collect();
+    rules.sort_unstable_by_key(|(code, _, _)| *code);
+    rules.hash(&mut hasher);
+    self.ignore_file_directive.hash(&mut hasher);
+    self.ignore_diagnostic_directive.hash(&mut hasher);
+    self.lint_unused_ignore_directives.hash(&mut hasher);
+    self.lint_unknown_rules.hash(&mut hasher);
+    self.deny_warnings.hash(&mut hasher);
+    format!("{:?}", self.syntax).hash(&mut hasher);
+    hasher.finish()
+  }
+
+  /// Reports ignore directives that suppressed nothing or name unknown rules.
+  /// Must run after all rules, so that every diagnostic has been checked
+  /// against the directives.
+  fn report_ignore_directives(
+    &self,
+    context: &Context,
+    emit: &mut dyn FnMut(LintDiagnostic),
+  ) {
+    if !self.lint_unused_ignore_directives && !self.lint_unknown_rules {
+      return;
+    }
 
     let (executed_rule_codes, available_rule_codes) = {
       let mut executed = context.plugin_codes.clone();
       // builtin executed rules
-      executed.extend(self.rules.iter().map(|r| r.code().to_string()));
+      executed.extend(context.rule_codes.iter().map(|code| code.to_string()));
 
       let mut available = context.plugin_codes.clone();
       // builtin all available rules
@@ -263,70 +769,74 @@ impl Linter {
       (executed, available)
     };
 
-    let mut filtered_diagnostics: Vec<LintDiagnostic> = diagnostics
-      .as_slice()
-      .iter()
-      .cloned()
-      .filter(|diagnostic| {
-        !ignore_directives
-          .borrow_mut()
-          .iter_mut()
-          .any(|ignore_directive| {
-            ignore_directive.maybe_ignore_diagnostic(&diagnostic)
-          })
-      })
-      .collect();
+    for ignore_directive in context.ignore_directives.borrow().iter() {
+      for (code, used) in ignore_directive.used_codes.iter() {
+        if self.lint_unused_ignore_directives
+          && !used
+          && executed_rule_codes.contains(code)
+        {
+          emit(context.create_diagnostic(
+            ignore_directive.span,
+            "ban-unused-ignore",
+            format!("Ignore for code \"{}\" was not used.", code),
+            None,
+          ));
+        }
 
-    if self.lint_unused_ignore_directives || self.lint_unknown_rules {
-      for ignore_directive in ignore_directives.borrow().iter() {
-        for (code, used) in ignore_directive.used_codes.iter() {
-          if self.lint_unused_ignore_directives
-            && !used
-            && executed_rule_codes.contains(code)
-          {
-            let diagnostic = context.create_diagnostic(
-              ignore_directive.span,
-              "ban-unused-ignore",
-              format!("Ignore for code \"{}\" was not used.", code),
-              None,
-            );
-            filtered_diagnostics.push(diagnostic);
-          }
-
-          if self.lint_unknown_rules && !available_rule_codes.contains(code) {
-            filtered_diagnostics.push(context.create_diagnostic(
-              ignore_directive.span,
-              "ban-unknown-rule-code",
-              format!("Unknown rule for code \"{}\"", code),
-              None,
-            ))
-          }
+        if self.lint_unknown_rules && !available_rule_codes.contains(code) {
+          emit(context.create_diagnostic(
+            ignore_directive.span,
+            "ban-unknown-rule-code",
+            format!("Unknown rule for code \"{}\"", code),
+            None,
+          ));
         }
       }
     }
-
-    filtered_diagnostics
-      .sort_by(|a, b| a.range.start.line.cmp(&b.range.start.line));
-
-    let end = Instant::now();
-    debug!("Linter::filter_diagnostics took {:#?}", end - start);
-
-    filtered_diagnostics
   }
 
-  fn lint_program(
+  /// Lints `program` with the rules passing `rule_filter`, returning its
+  /// diagnostics sorted by line.
+  fn lint_resolved_program(
     &mut self,
     file_name: String,
     program: swc_ecmascript::ast::Program,
     comments: SingleThreadedComments,
+    source_map: Rc<SourceMap>,
+    rule_filter: &dyn Fn(&dyn LintRule) -> bool,
   ) -> Vec<LintDiagnostic> {
+    let mut diagnostics = vec![];
+    self.lint_resolved_program_with_callback(
+      file_name,
+      program,
+      comments,
+      source_map,
+      rule_filter,
+      &mut |diagnostic| diagnostics.push(diagnostic),
+    );
+    diagnostics.sort_by(|a, b| a.range.start.line.cmp(&b.range.start.line));
+    diagnostics
+  }
+
+  /// Lints `program` with the rules passing `rule_filter`, passing
+  /// diagnostics to `emit` as soon as the rule or plugin that reported them
+  /// has finished. The plugins are run if `PluginsRule` passes.
+  fn lint_resolved_program_with_callback(
+    &mut self,
+    file_name: String,
+    program: swc_ecmascript::ast::Program,
+    comments: SingleThreadedComments,
+    source_map: Rc<SourceMap>,
+    rule_filter: &dyn Fn(&dyn LintRule) -> bool,
+    emit: &mut dyn FnMut(LintDiagnostic),
+  ) {
     let start = Instant::now();
     let file_ignore_directive =
       comments.with_leading(program.span().lo(), |c| {
         c.iter().find_map(|comment| {
           parse_ignore_comment(
             &self.ignore_file_directive,
-            &*self.ast_parser.source_map,
+            &*source_map,
             comment,
             true,
           )
@@ -337,7 +847,7 @@ impl Linter {
     // whole file and skip linting it.
     if let Some(ignore_directive) = &file_ignore_directive {
       if ignore_directive.codes.is_empty() {
-        return vec![];
+        return;
       }
     }
 
@@ -353,7 +863,7 @@ impl Linter {
 
     let mut ignore_directives = parse_ignore_directives(
       &self.ignore_diagnostic_directive,
-      &self.ast_parser.source_map,
+      &source_map,
       &leading,
       &trailing,
     );
@@ -362,8 +872,15 @@ impl Linter {
       ignore_directives.insert(0, ignore_directive);
     }
 
-    let scope = Scope::analyze(&program);
-    let control_flow = ControlFlow::analyze(&program);
+    let rules: Vec<&dyn LintRule> = self
+      .rules
+      .iter()
+      .map(|rule| &**rule)
+      .filter(|rule| rule_filter(*rule))
+      .collect();
+    let run_plugins = rule_filter(&PluginsRule);
+
+    let program = Rc::new(program);
     let top_level_ctxt = swc_common::GLOBALS
       .set(&self.ast_parser.globals, || {
         SyntaxContext::empty().apply_mark(self.ast_parser.top_level_mark)
@@ -371,37 +888,172 @@ impl Linter {
 
     let mut context = Context {
       file_name,
-      source_map: self.ast_parser.source_map.clone(),
+      source_map,
       leading_comments: leading,
       trailing_comments: trailing,
       ignore_directives: RefCell::new(ignore_directives),
-      scope,
-      control_flow,
+      program: Rc::clone(&program),
+      scope: OnceCell::new(),
+      control_flow: OnceCell::new(),
+      type_context: OnceCell::new(),
+      line_index: OnceCell::new(),
       top_level_ctxt,
       diagnostics: Vec::new(),
+      rule_codes: rules.iter().map(|rule| rule.code()).collect(),
       plugin_codes: HashSet::new(),
+      metrics: Metrics::new(),
+    };
+    // Warnings are promoted as they are emitted, so that the ones reported by
+    // plugins are covered as well
+    let deny_warnings = self.deny_warnings;
+    let emit: &mut dyn FnMut(LintDiagnostic) =
+      &mut |mut diagnostic: LintDiagnostic| {
+        if deny_warnings && diagnostic.severity == Severity::Warning {
+          diagnostic.severity = Severity::Error;
+        }
+        emit(diagnostic);
+      };
+    // A plugin may report the same problem as a builtin rule
+    let mut seen = HashSet::new();
+
+    let rule_timings = self.rule_timings.as_ref();
+    let time = |code: &str, rule_start: Instant| {
+      if let Some(rule_timings) = rule_timings {
+        rule_timings.lock().unwrap().add(code, rule_start.elapsed());
+      }
     };
 
     // Run builtin rules
-    for rule in &self.rules {
+    for rule in rules {
+      let rule_start = Instant::now();
       rule.lint_program(&mut context, &program);
+      time(rule.code(), rule_start);
+      let severity = rule.severity();
+      for diagnostic in context.diagnostics.iter_mut() {
+        diagnostic.severity = severity;
+      }
+      context.flush_diagnostics(&mut seen, emit);
     }
 
     // Run plugin rules
-    for plugin in self.plugins.iter_mut() {
-      // Ignore any error
-      let _ = plugin.run(&mut context, program.clone());
+    let plugins: &mut [Box<dyn Plugin>] = if run_plugins {
+      &mut self.plugins
+    } else {
+      &mut []
+    };
+    for plugin in plugins {
+      let plugin_start = Instant::now();
+      let result = plugin.run(&mut context, (*program).clone());
+      time(PLUGIN_TIMING_CODE, plugin_start);
+      // A failing plugin is reported instead of stopping the lint run
+      if let Err(err) = result {
+        context.add_diagnostic(
+          program.span().shrink_to_lo(),
+          "plugin-error",
+          format!("{:#}", err),
+        );
+      }
+      context.flush_diagnostics(&mut seen, emit);
     }
 
-    let d = self.filter_diagnostics(&mut context);
+    self.report_ignore_directives(&context, emit);
+    self.metrics = context.metrics;
     let end = Instant::now();
     debug!("Linter::lint_module took {:#?}", end - start);
+  }
+}
+
+/// Stands for the plugins when `Linter::lint_filtered` asks its predicate
+/// which rules to run, so that they can be skipped. Its code is the one the
+/// time spent in plugins is recorded under.
+struct PluginsRule;
 
-    d
+impl LintRule for PluginsRule {
+  fn new() -> Box<Self> {
+    Box::new(PluginsRule)
+  }
+
+  fn code(&self) -> &'static str {
+    PLUGIN_TIMING_CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: &Program) {}
+}
+
+/// Identifies diagnostics with the same range, code and message.
+type DiagnosticKey = (usize, usize, String, String);
+
+fn diagnostic_key(diagnostic: &LintDiagnostic) -> DiagnosticKey {
+  (
+    diagnostic.range.start.byte_pos,
+    diagnostic.range.end.byte_pos,
+    diagnostic.code.clone(),
+    diagnostic.message.clone(),
+  )
+}
+
+/// Counts of the problems found in a lint run.
+///
+/// Front-ends should use it to print their summary line and pick their exit
+/// code, so that they all behave the same.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+  pub error_count: usize,
+  pub warning_count: usize,
+  /// Number of files linted, including those without diagnostics.
+  pub file_count: usize,
+}
+
+impl Summary {
+  /// Returns the exit code for the run, which is non-zero if any error was
+  /// found. Warnings alone don't fail a run.
+  pub fn exit_code(&self) -> i32 {
+    if self.error_count > 0 {
+      1
+    } else {
+      0
+    }
+  }
+}
+
+impl fmt::Display for Summary {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fn plural(count: usize, word: &str) -> String {
+      if count == 1 {
+        format!("{} {}", count, word)
+      } else {
+        format!("{} {}s", count, word)
+      }
+    }
+
+    write!(
+      f,
+      "Found {} and {} in {}",
+      plural(self.error_count, "error"),
+      plural(self.warning_count, "warning"),
+      plural(self.file_count, "file")
+    )
+  }
+}
+
+/// Counts the errors and warnings in `results`.
+pub fn summarize(results: &[FileDiagnostics]) -> Summary {
+  let mut summary = Summary {
+    file_count: results.len(),
+    ..Default::default()
+  };
+  for diagnostic in results.iter().flat_map(|file| &file.diagnostics) {
+    match diagnostic.severity {
+      Severity::Error => summary.error_count += 1,
+      Severity::Warning => summary.warning_count += 1,
+    }
   }
+  summary
 }
 
 pub trait Plugin {
+  /// Reports the diagnostics of the plugin's rules to `context`. An error is
+  /// reported as a `plugin-error` diagnostic, so it should name the plugin.
   fn run(
     &mut self,
     context: &mut Context,
@@ -0,0 +1,16 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+
+//! Numbers rules measure about a file, like its complexity, for dashboards.
+//! Unlike diagnostics, metrics don't point out problems.
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Value of each metric recorded with `Context::add_metric`, by name.
+pub type Metrics = BTreeMap<String, f64>;
+
+/// Metrics recorded for a single file.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct FileMetrics {
+  pub filename: String,
+  pub metrics: Metrics,
+}
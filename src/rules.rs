@@ -1,30 +1,51 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use crate::diagnostic::Severity;
 use crate::linter::Context;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
 use swc_ecmascript::ast::Program;
 
 pub mod adjacent_overload_signatures;
+pub mod arrow_body_style;
 pub mod ban_ts_comment;
 pub mod ban_types;
 pub mod ban_untagged_ignore;
 pub mod ban_untagged_todo;
 pub mod camelcase;
+pub mod cognitive_complexity;
 pub mod constructor_super;
 pub mod default_param_last;
 pub mod eqeqeq;
 pub mod explicit_function_return_type;
 pub mod explicit_module_boundary_types;
 pub mod for_direction;
+pub mod func_style;
 pub mod getter_return;
+pub mod handle_callback_err;
+pub mod jsx_no_useless_fragment;
+pub mod max_classes_per_file;
+pub mod max_lines;
+pub mod max_lines_per_function;
+pub mod max_nested_callbacks;
+pub mod max_statements;
+pub mod no_adjacent_identical_statements;
+pub mod no_anonymous_default_export;
 pub mod no_array_constructor;
+pub mod no_async_foreach;
 pub mod no_async_promise_executor;
+pub mod no_await_expression_member;
 pub mod no_await_in_loop;
+pub mod no_buffer_constructor;
 pub mod no_case_declarations;
 pub mod no_class_assign;
+pub mod no_collapsible_if;
 pub mod no_compare_neg_zero;
 pub mod no_cond_assign;
 pub mod no_const_assign;
 pub mod no_constant_condition;
+pub mod no_constant_condition_in_loop;
 pub mod no_control_regex;
+pub mod no_dead_property_store;
 pub mod no_debugger;
 pub mod no_delete_var;
 pub mod no_dupe_args;
@@ -32,6 +53,7 @@ pub mod no_dupe_class_members;
 pub mod no_dupe_else_if;
 pub mod no_dupe_keys;
 pub mod no_duplicate_case;
+pub mod no_duplicate_string;
 pub mod no_empty;
 pub mod no_empty_character_class;
 pub mod no_empty_interface;
@@ -45,22 +67,32 @@ pub mod no_extra_semi;
 pub mod no_fallthrough;
 pub mod no_func_assign;
 pub mod no_global_assign;
+pub mod no_gratuitous_expr;
+pub mod no_identical_functions;
 pub mod no_import_assign;
 pub mod no_inferrable_types;
 pub mod no_inner_declarations;
 pub mod no_invalid_regexp;
 pub mod no_irregular_whitespace;
 pub mod no_misused_new;
+pub mod no_mixed_requires;
 pub mod no_mixed_spaces_and_tabs;
+pub mod no_mutable_exports;
 pub mod no_namespace;
+pub mod no_negated_in_lhs;
+pub mod no_new_require;
 pub mod no_new_symbol;
+pub mod no_new_wrappers;
 pub mod no_non_null_asserted_optional_chain;
 pub mod no_non_null_assertion;
 pub mod no_obj_calls;
 pub mod no_octal;
+pub mod no_path_concat;
 pub mod no_prototype_builtins;
 pub mod no_redeclare;
 pub mod no_regex_spaces;
+pub mod no_restricted_exports;
+pub mod no_return_await;
 pub mod no_self_assign;
 pub mod no_setter_return;
 pub mod no_shadow_restricted_names;
@@ -69,24 +101,43 @@ pub mod no_this_alias;
 pub mod no_this_before_super;
 pub mod no_throw_literal;
 pub mod no_undef;
+pub mod no_undef_init;
 pub mod no_unreachable;
+pub mod no_unreachable_loop;
 pub mod no_unsafe_finally;
 pub mod no_unsafe_negation;
 pub mod no_unused_labels;
 pub mod no_unused_vars;
+pub mod no_useless_spread;
+pub mod no_useless_undefined;
 pub mod no_var;
+pub mod no_void;
 pub mod no_with;
+pub mod no_zero_fractions;
+pub mod object_shorthand;
+pub mod prefer_arrow_callback;
 pub mod prefer_as_const;
+pub mod prefer_at;
+pub mod prefer_await_to_then;
 pub mod prefer_const;
+pub mod prefer_date_now;
+pub mod prefer_destructuring;
+pub mod prefer_exponentiation_operator;
 pub mod prefer_namespace_keyword;
+pub mod prefer_number_properties;
+pub mod prefer_numeric_separators;
+pub mod prefer_promise_reject_errors;
 pub mod require_await;
 pub mod require_yield;
 pub mod single_var_declarator;
+pub mod sort_type_constituents;
 pub mod triple_slash_reference;
 pub mod use_isnan;
 pub mod valid_typeof;
 
-pub trait LintRule {
+/// A builtin or custom lint rule. Rules are shared by the threads
+/// `Linter::lint_paths` lints files on, so they must be `Send` and `Sync`.
+pub trait LintRule: Send + Sync {
   fn new() -> Box<Self>
   where
     Self: Sized;
@@ -98,33 +149,64 @@ pub trait LintRule {
   fn docs(&self) -> &'static str {
     ""
   }
+  fn severity(&self) -> Severity {
+    Severity::Error
+  }
+  /// Returns the options the rule was created with, in the same form as in a
+  /// config file, or `None` if it can't be configured.
+  fn options(&self) -> Option<Value> {
+    None
+  }
+  /// Returns a JSON schema of the options accepted by the rule, or `None` if
+  /// it can't be configured.
+  fn options_schema(&self) -> Option<Value> {
+    None
+  }
 }
 
 pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
   vec![
     adjacent_overload_signatures::AdjacentOverloadSignatures::new(),
+    arrow_body_style::ArrowBodyStyle::new(),
     ban_ts_comment::BanTsComment::new(),
     ban_types::BanTypes::new(),
     ban_untagged_ignore::BanUntaggedIgnore::new(),
     ban_untagged_todo::BanUntaggedTodo::new(),
     camelcase::Camelcase::new(),
+    cognitive_complexity::CognitiveComplexity::new(),
     constructor_super::ConstructorSuper::new(),
     default_param_last::DefaultParamLast::new(),
     eqeqeq::Eqeqeq::new(),
     explicit_function_return_type::ExplicitFunctionReturnType::new(),
     explicit_module_boundary_types::ExplicitModuleBoundaryTypes::new(),
     for_direction::ForDirection::new(),
+    func_style::FuncStyle::new(),
     getter_return::GetterReturn::new(),
+    handle_callback_err::HandleCallbackErr::new(),
+    jsx_no_useless_fragment::JsxNoUselessFragment::new(),
+    max_classes_per_file::MaxClassesPerFile::new(),
+    max_lines::MaxLines::new(),
+    max_lines_per_function::MaxLinesPerFunction::new(),
+    max_nested_callbacks::MaxNestedCallbacks::new(),
+    max_statements::MaxStatements::new(),
+    no_adjacent_identical_statements::NoAdjacentIdenticalStatements::new(),
+    no_anonymous_default_export::NoAnonymousDefaultExport::new(),
     no_array_constructor::NoArrayConstructor::new(),
+    no_async_foreach::NoAsyncForeach::new(),
     no_async_promise_executor::NoAsyncPromiseExecutor::new(),
+    no_await_expression_member::NoAwaitExpressionMember::new(),
     no_await_in_loop::NoAwaitInLoop::new(),
+    no_buffer_constructor::NoBufferConstructor::new(),
     no_case_declarations::NoCaseDeclarations::new(),
     no_class_assign::NoClassAssign::new(),
+    no_collapsible_if::NoCollapsibleIf::new(),
     no_compare_neg_zero::NoCompareNegZero::new(),
     no_cond_assign::NoCondAssign::new(),
     no_const_assign::NoConstAssign::new(),
     no_constant_condition::NoConstantCondition::new(),
+    no_constant_condition_in_loop::NoConstantConditionInLoop::new(),
     no_control_regex::NoControlRegex::new(),
+    no_dead_property_store::NoDeadPropertyStore::new(),
     no_debugger::NoDebugger::new(),
     no_delete_var::NoDeleteVar::new(),
     no_dupe_args::NoDupeArgs::new(),
@@ -132,6 +214,7 @@ pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
     no_dupe_else_if::NoDupeElseIf::new(),
     no_dupe_keys::NoDupeKeys::new(),
     no_duplicate_case::NoDuplicateCase::new(),
+    no_duplicate_string::NoDuplicateString::new(),
     no_empty::NoEmpty::new(),
     no_empty_character_class::NoEmptyCharacterClass::new(),
     no_empty_interface::NoEmptyInterface::new(),
@@ -145,22 +228,32 @@ pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
     no_fallthrough::NoFallthrough::new(),
     no_func_assign::NoFuncAssign::new(),
     no_global_assign::NoGlobalAssign::new(),
+    no_gratuitous_expr::NoGratuitousExpr::new(),
+    no_identical_functions::NoIdenticalFunctions::new(),
     no_import_assign::NoImportAssign::new(),
     no_inferrable_types::NoInferrableTypes::new(),
     no_inner_declarations::NoInnerDeclarations::new(),
     no_invalid_regexp::NoInvalidRegexp::new(),
     no_irregular_whitespace::NoIrregularWhitespace::new(),
     no_misused_new::NoMisusedNew::new(),
+    no_mixed_requires::NoMixedRequires::new(),
     no_mixed_spaces_and_tabs::NoMixedSpacesAndTabs::new(),
+    no_mutable_exports::NoMutableExports::new(),
     no_namespace::NoNamespace::new(),
+    no_negated_in_lhs::NoNegatedInLhs::new(),
+    no_new_require::NoNewRequire::new(),
     no_new_symbol::NoNewSymbol::new(),
+    no_new_wrappers::NoNewWrappers::new(),
     no_non_null_asserted_optional_chain::NoNonNullAssertedOptionalChain::new(),
     no_non_null_assertion::NoNonNullAssertion::new(),
     no_obj_calls::NoObjCalls::new(),
     no_octal::NoOctal::new(),
+    no_path_concat::NoPathConcat::new(),
     no_prototype_builtins::NoPrototypeBuiltins::new(),
     no_redeclare::NoRedeclare::new(),
     no_regex_spaces::NoRegexSpaces::new(),
+    no_restricted_exports::NoRestrictedExports::new(),
+    no_return_await::NoReturnAwait::new(),
     no_self_assign::NoSelfAssign::new(),
     no_setter_return::NoSetterReturn::new(),
     no_shadow_restricted_names::NoShadowRestrictedNames::new(),
@@ -169,19 +262,36 @@ pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
     no_this_before_super::NoThisBeforeSuper::new(),
     no_throw_literal::NoThrowLiteral::new(),
     no_undef::NoUndef::new(),
+    no_undef_init::NoUndefInit::new(),
     no_unreachable::NoUnreachable::new(),
+    no_unreachable_loop::NoUnreachableLoop::new(),
     no_unsafe_finally::NoUnsafeFinally::new(),
     no_unsafe_negation::NoUnsafeNegation::new(),
     no_unused_labels::NoUnusedLabels::new(),
     no_unused_vars::NoUnusedVars::new(),
+    no_useless_spread::NoUselessSpread::new(),
+    no_useless_undefined::NoUselessUndefined::new(),
     no_var::NoVar::new(),
+    no_void::NoVoid::new(),
     no_with::NoWith::new(),
+    no_zero_fractions::NoZeroFractions::new(),
+    object_shorthand::ObjectShorthand::new(),
+    prefer_arrow_callback::PreferArrowCallback::new(),
     prefer_as_const::PreferAsConst::new(),
+    prefer_at::PreferAt::new(),
+    prefer_await_to_then::PreferAwaitToThen::new(),
     prefer_const::PreferConst::new(),
+    prefer_date_now::PreferDateNow::new(),
+    prefer_destructuring::PreferDestructuring::new(),
+    prefer_exponentiation_operator::PreferExponentiationOperator::new(),
     prefer_namespace_keyword::PreferNamespaceKeyword::new(),
+    prefer_number_properties::PreferNumberProperties::new(),
+    prefer_numeric_separators::PreferNumericSeparators::new(),
+    prefer_promise_reject_errors::PreferPromiseRejectErrors::new(),
     require_await::RequireAwait::new(),
     require_yield::RequireYield::new(),
     single_var_declarator::SingleVarDeclarator::new(),
+    sort_type_constituents::SortTypeConstituents::new(),
     triple_slash_reference::TripleSlashReference::new(),
     use_isnan::UseIsNaN::new(),
     valid_typeof::ValidTypeof::new(),
@@ -195,6 +305,93 @@ pub fn get_recommended_rules() -> Vec<Box<dyn LintRule>> {
     .collect()
 }
 
+/// Selection of rules, e.g. from the lint section of a config file.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct LintRulesConfig {
+  /// Tags of the rules to start from. All rules if empty.
+  pub tags: Vec<String>,
+  /// Codes of rules to add.
+  pub include: Vec<String>,
+  /// Codes of rules to remove.
+  pub exclude: Vec<String>,
+}
+
+/// Resolves `config` to the rules it selects, sorted by code.
+///
+/// Rules with any of the tags are selected, then excluded rules are removed
+/// and included rules are added. A rule that is both included and excluded is
+/// selected. Fails if `include` or `exclude` contain an unknown code.
+pub fn resolve_rule_set(
+  config: LintRulesConfig,
+) -> anyhow::Result<Vec<Box<dyn LintRule>>> {
+  let all_rules = get_all_rules();
+  for code in config.include.iter().chain(&config.exclude) {
+    if !all_rules.iter().any(|rule| rule.code() == code) {
+      anyhow::bail!("Unknown rule for code \"{}\"", code);
+    }
+  }
+
+  let rules = all_rules
+    .into_iter()
+    .filter(|rule| {
+      let code = rule.code();
+      let tagged = config.tags.is_empty()
+        || rule
+          .tags()
+          .iter()
+          .any(|tag| config.tags.iter().any(|t| t == tag));
+      let included = config.include.iter().any(|c| c == code);
+      let excluded = config.exclude.iter().any(|c| c == code);
+      included || (tagged && !excluded)
+    })
+    .collect();
+  Ok(rules)
+}
+
+/// Information about a rule for tools, such as editors that show rule
+/// documentation.
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct RuleDescription {
+  pub code: &'static str,
+  pub docs: &'static str,
+  pub tags: &'static [&'static str],
+}
+
+impl From<&dyn LintRule> for RuleDescription {
+  fn from(rule: &dyn LintRule) -> Self {
+    RuleDescription {
+      code: rule.code(),
+      docs: rule.docs(),
+      tags: rule.tags(),
+    }
+  }
+}
+
+/// Describes every available rule, sorted by code.
+pub fn describe_all() -> Vec<RuleDescription> {
+  get_all_rules()
+    .iter()
+    .map(|rule| RuleDescription::from(rule.as_ref()))
+    .collect()
+}
+
+/// Builds a JSON schema document for the options of every configurable rule,
+/// keyed by rule code.
+pub fn options_schema() -> Value {
+  let properties: Map<String, Value> = get_all_rules()
+    .iter()
+    .filter_map(|rule| Some((rule.code().to_string(), rule.options_schema()?)))
+    .collect();
+  json!({
+    "$schema": "http://json-schema.org/draft-07/schema#",
+    "title": "deno_lint rule options",
+    "type": "object",
+    "properties": properties,
+    "additionalProperties": false
+  })
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -218,4 +415,97 @@ mod tests {
       assert_eq!(sorted.code(), unsorted.code());
     }
   }
+
+  #[test]
+  fn describe_all_rules() {
+    let descriptions = describe_all();
+    assert_eq!(descriptions.len(), get_all_rules().len());
+
+    let valid_typeof = descriptions
+      .iter()
+      .find(|d| d.code == "valid-typeof")
+      .unwrap();
+    assert!(!valid_typeof.docs.is_empty());
+    assert!(valid_typeof.tags.contains(&"recommended"));
+
+    let json = serde_json::to_value(valid_typeof).unwrap();
+    assert_eq!(json["code"], "valid-typeof");
+    assert_eq!(json["tags"][0], "recommended");
+  }
+
+  #[test]
+  fn options_of_rules() {
+    for rule in get_all_rules() {
+      assert_eq!(
+        rule.options().is_some(),
+        rule.options_schema().is_some(),
+        "{}",
+        rule.code()
+      );
+    }
+    let max_lines = max_lines::MaxLines::new();
+    assert_eq!(
+      max_lines.options().unwrap(),
+      json!({ "max": 300, "skipBlankLines": false, "skipComments": false })
+    );
+    // Unknown options are rejected, as in the schema
+    assert!(serde_json::from_value::<max_lines::MaxLinesOptions>(
+      json!({ "maximum": 1 })
+    )
+    .is_err());
+  }
+
+  #[test]
+  fn options_schema_of_rules() {
+    let max_lines = max_lines::MaxLines::new();
+    let schema = max_lines.options_schema().unwrap();
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["properties"]["max"]["type"], "integer");
+    assert_eq!(schema["properties"]["max"]["default"], 300);
+
+    assert!(no_debugger::NoDebugger::new().options_schema().is_none());
+
+    let document = options_schema();
+    let properties = document["properties"].as_object().unwrap();
+    assert_eq!(properties["max-lines"], schema);
+    assert!(!properties.contains_key("no-debugger"));
+    for (code, schema) in properties {
+      assert_eq!(schema["type"], "object", "{} has an invalid schema", code);
+      assert!(schema["properties"].is_object(), "{}", code);
+      assert_eq!(schema["additionalProperties"], false, "{}", code);
+    }
+  }
+
+  #[test]
+  fn resolve_rule_set_tags_and_exclude() {
+    let rules = resolve_rule_set(LintRulesConfig {
+      tags: vec!["recommended".to_string()],
+      include: vec!["no-var".to_string()],
+      exclude: vec!["no-debugger".to_string()],
+    })
+    .unwrap();
+    let codes: Vec<&str> = rules.iter().map(|r| r.code()).collect();
+
+    let mut expected: Vec<&str> = get_recommended_rules()
+      .iter()
+      .map(|r| r.code())
+      .filter(|code| *code != "no-debugger")
+      .chain(std::iter::once("no-var"))
+      .collect();
+    expected.sort_unstable();
+    assert_eq!(codes, expected);
+
+    let rules = resolve_rule_set(LintRulesConfig::default()).unwrap();
+    assert_eq!(rules.len(), get_all_rules().len());
+  }
+
+  #[test]
+  fn resolve_rule_set_unknown_code() {
+    let result = resolve_rule_set(LintRulesConfig {
+      include: vec!["no-such-rule".to_string()],
+      ..Default::default()
+    });
+    let err = result.err().expect("Unknown code should be an error");
+    assert_eq!(err.to_string(), "Unknown rule for code \"no-such-rule\"");
+  }
 }
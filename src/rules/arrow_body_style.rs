@@ -0,0 +1,362 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::LintFix;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_common::Spanned;
+use swc_ecmascript::ast::{ArrowExpr, BlockStmtOrExpr, Expr, ReturnStmt, Stmt};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct ArrowBodyStyle {
+  options: ArrowBodyStyleOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct ArrowBodyStyleOptions {
+  /// When arrow functions must have braces around their body.
+  pub style: BodyStyle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BodyStyle {
+  AsNeeded,
+  Always,
+  Never,
+}
+
+impl Default for BodyStyle {
+  fn default() -> Self {
+    BodyStyle::AsNeeded
+  }
+}
+
+const CODE: &str = "arrow-body-style";
+
+#[derive(Display)]
+enum ArrowBodyStyleMessage {
+  #[display(fmt = "Unexpected block statement surrounding arrow body")]
+  UnexpectedBlock,
+  #[display(fmt = "Expected block statement surrounding arrow body")]
+  ExpectedBlock,
+}
+
+#[derive(Display)]
+enum ArrowBodyStyleFix {
+  #[display(fmt = "Remove the braces and `return`")]
+  ToConcise,
+  #[display(fmt = "Add braces and `return`")]
+  ToBlock,
+}
+
+impl ArrowBodyStyle {
+  pub fn with_options(options: ArrowBodyStyleOptions) -> Box<Self> {
+    Box::new(ArrowBodyStyle { options })
+  }
+}
+
+impl LintRule for ArrowBodyStyle {
+  fn new() -> Box<Self> {
+    ArrowBodyStyle::with_options(ArrowBodyStyleOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "style": {
+          "type": "string",
+          "enum": ["as-needed", "always", "never"],
+          "description": "When arrow functions must have braces around their body.",
+          "default": "as-needed"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = ArrowBodyStyleVisitor::new(context, self.options.style);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a consistent style of arrow function bodies
+
+An arrow function body is either a block statement or a single expression that
+is returned. The `style` option decides which is required:
+
+- `as-needed` (the default) reports blocks consisting of a single `return`
+  statement, which can be written as the returned expression.
+- `always` requires a block statement.
+- `never` requires an expression.
+
+### Invalid:
+```typescript
+// With `style: "as-needed"`
+const double = (x) => {
+  return x * 2;
+};
+// With `style: "always"`
+const triple = (x) => x * 3;
+```
+
+### Valid:
+```typescript
+// With `style: "as-needed"`
+const double = (x) => x * 2;
+const point = (x, y) => ({ x, y });
+const log = (x) => {
+  console.log(x);
+  return x;
+};
+// With `style: "always"`
+const triple = (x) => {
+  return x * 3;
+};
+```
+"#
+  }
+}
+
+struct ArrowBodyStyleVisitor<'c> {
+  context: &'c mut Context,
+  style: BodyStyle,
+}
+
+impl<'c> ArrowBodyStyleVisitor<'c> {
+  fn new(context: &'c mut Context, style: BodyStyle) -> Self {
+    Self { context, style }
+  }
+
+  fn report(&mut self, arrow_expr: &ArrowExpr, fix: Option<LintFix>) {
+    let message = match arrow_expr.body {
+      BlockStmtOrExpr::BlockStmt(_) => ArrowBodyStyleMessage::UnexpectedBlock,
+      BlockStmtOrExpr::Expr(_) => ArrowBodyStyleMessage::ExpectedBlock,
+    };
+    let span = arrow_expr.body.span();
+    match fix {
+      Some(fix) => self
+        .context
+        .add_diagnostic_with_fix(span, CODE, message, fix),
+      None => self.context.add_diagnostic(span, CODE, message),
+    }
+  }
+
+  /// Builds a fix replacing a block body with the expression `return_stmt`
+  /// returns.
+  fn to_concise_fix(
+    &self,
+    arrow_expr: &ArrowExpr,
+    return_stmt: &ReturnStmt,
+  ) -> Option<LintFix> {
+    let arg = return_stmt.arg.as_ref()?;
+    let source_map = &self.context.source_map;
+    let body_text = source_map.span_to_snippet(arrow_expr.body.span()).ok()?;
+    let arg_text = source_map.span_to_snippet(arg.span()).ok()?;
+    // Removing the braces would also remove any comments next to the
+    // `return` statement
+    let rest = body_text.replacen(&arg_text, "", 1);
+    if rest.contains("//") || rest.contains("/*") {
+      return None;
+    }
+
+    // An expression starting with an object literal, like `{ a }.a`, would
+    // be parsed as a block, and a sequence would end the arrow function at
+    // its first comma
+    let new_text =
+      if arg_text.starts_with('{') || matches!(&**arg, Expr::Seq(_)) {
+        format!("({})", arg_text)
+      } else {
+        arg_text
+      };
+    Some(LintFix {
+      description: ArrowBodyStyleFix::ToConcise.to_string(),
+      changes: vec![self
+        .context
+        .create_fix_change(arrow_expr.body.span(), new_text)],
+      priority: 0,
+    })
+  }
+
+  fn to_block_fix(
+    &self,
+    arrow_expr: &ArrowExpr,
+    expr: &Expr,
+  ) -> Option<LintFix> {
+    let expr_text =
+      self.context.source_map.span_to_snippet(expr.span()).ok()?;
+    Some(LintFix {
+      description: ArrowBodyStyleFix::ToBlock.to_string(),
+      changes: vec![self.context.create_fix_change(
+        arrow_expr.body.span(),
+        format!("{{ return {}; }}", expr_text),
+      )],
+      priority: 0,
+    })
+  }
+}
+
+impl<'c> Visit for ArrowBodyStyleVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    match &arrow_expr.body {
+      BlockStmtOrExpr::BlockStmt(block_stmt) => {
+        let return_stmt = match block_stmt.stmts.as_slice() {
+          [Stmt::Return(return_stmt)] if return_stmt.arg.is_some() => {
+            Some(return_stmt)
+          }
+          _ => None,
+        };
+        match (self.style, return_stmt) {
+          (BodyStyle::AsNeeded, Some(return_stmt))
+          | (BodyStyle::Never, Some(return_stmt)) => {
+            let fix = self.to_concise_fix(arrow_expr, return_stmt);
+            self.report(arrow_expr, fix);
+          }
+          (BodyStyle::Never, None) => self.report(arrow_expr, None),
+          _ => {}
+        }
+      }
+      BlockStmtOrExpr::Expr(expr) => {
+        if self.style == BodyStyle::Always {
+          let fix = self.to_block_fix(arrow_expr, expr);
+          self.report(arrow_expr, fix);
+        }
+      }
+    }
+    arrow_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn with_style(style: BodyStyle) -> Box<ArrowBodyStyle> {
+    ArrowBodyStyle::with_options(ArrowBodyStyleOptions { style })
+  }
+
+  #[test]
+  fn arrow_body_style_valid() {
+    assert_lint_ok! {
+      ArrowBodyStyle,
+      "const f = (x) => x;",
+      "const f = (x) => ({ x });",
+      "const f = (x) => { console.log(x); return x; };",
+      "const f = () => {};",
+      "const f = () => { return; };",
+      "const f = (x) => { if (x) { return x; } };",
+    };
+
+    let sources = [
+      "const f = (x) => { return x; };",
+      "const f = () => {};",
+      "const f = () => { foo(); };",
+    ];
+    for src in &sources {
+      assert_lint_ok_with_rule(with_style(BodyStyle::Always), src);
+    }
+
+    let sources = ["const f = (x) => x;", "const f = () => ({});"];
+    for src in &sources {
+      assert_lint_ok_with_rule(with_style(BodyStyle::Never), src);
+    }
+  }
+
+  #[test]
+  fn arrow_body_style_invalid() {
+    assert_lint_err! {
+      ArrowBodyStyle,
+      "const f = (x) => { return x; };": [
+        {
+          col: 17,
+          message: ArrowBodyStyleMessage::UnexpectedBlock,
+        }
+      ],
+      "const f = () => {\n  return () => { return 1; };\n};": [
+        {
+          col: 16,
+          message: ArrowBodyStyleMessage::UnexpectedBlock,
+        },
+        {
+          line: 2,
+          col: 15,
+          message: ArrowBodyStyleMessage::UnexpectedBlock,
+        }
+      ],
+    };
+
+    assert_lint_err_on_line_with_rule(
+      with_style(BodyStyle::Always),
+      "const f = (x) => x;",
+      1,
+      17,
+    );
+    let sources = [
+      ("const f = () => { foo(); };", 16),
+      ("const f = (x) => { return x; };", 17),
+    ];
+    for (src, col) in &sources {
+      assert_lint_err_on_line_with_rule(
+        with_style(BodyStyle::Never),
+        src,
+        1,
+        *col,
+      );
+    }
+  }
+
+  #[test]
+  fn arrow_body_style_fix() {
+    assert_lint_fix::<ArrowBodyStyle>(
+      "const f = (x) => { return x; };",
+      "const f = (x) => x;",
+    );
+    assert_lint_fix::<ArrowBodyStyle>(
+      "const f = (x) => {\n  return { x };\n};",
+      "const f = (x) => ({ x });",
+    );
+    assert_lint_fix::<ArrowBodyStyle>(
+      "const f = (x) => { return {a}.a; };",
+      "const f = (x) => ({a}.a);",
+    );
+    assert_lint_fix::<ArrowBodyStyle>(
+      "const f = () => { return {}.toString() + a; };",
+      "const f = () => ({}.toString() + a);",
+    );
+    assert_lint_fix::<ArrowBodyStyle>(
+      "const f = () => { return a, b; };",
+      "const f = () => (a, b);",
+    );
+    assert_lint_fix::<ArrowBodyStyle>(
+      "const f = () => {\n  // keep me\n  return 1;\n};",
+      "const f = () => {\n  // keep me\n  return 1;\n};",
+    );
+    assert_lint_fix_with_rule(
+      with_style(BodyStyle::Always),
+      "const f = (x) => ({ x });",
+      "const f = (x) => { return ({ x }); };",
+    );
+  }
+}
@@ -2,8 +2,11 @@
 use super::Context;
 use super::LintRule;
 
+use derive_more::Display;
 use once_cell::sync::Lazy;
 use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use swc_common::comments::Comment;
 use swc_common::comments::CommentKind;
 use swc_common::Span;
@@ -18,22 +21,111 @@ use swc_common::Span;
 /// - ts-expect-error: allowed with comment
 /// - ts-ignore: allowed with comment
 /// - ts-nocheck: allowed with comment
-pub struct BanTsComment;
+/// - ts-check: allowed
+pub struct BanTsComment {
+  options: BanTsCommentOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct BanTsCommentOptions {
+  pub ts_expect_error: DirectiveMode,
+  pub ts_ignore: DirectiveMode,
+  pub ts_nocheck: DirectiveMode,
+  pub ts_check: DirectiveMode,
+  /// Minimum number of characters of the description of directives set to
+  /// `allow-with-description`.
+  pub minimum_description_length: usize,
+}
+
+impl Default for BanTsCommentOptions {
+  fn default() -> Self {
+    BanTsCommentOptions {
+      ts_expect_error: DirectiveMode::AllowWithDescription,
+      ts_ignore: DirectiveMode::AllowWithDescription,
+      ts_nocheck: DirectiveMode::AllowWithDescription,
+      ts_check: DirectiveMode::Allow,
+      minimum_description_length: 1,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DirectiveMode {
+  Allow,
+  AllowWithDescription,
+  Ban,
+}
+
+const CODE: &str = "ban-ts-comment";
+
+#[derive(Display)]
+enum BanTsCommentMessage {
+  #[display(fmt = "ts directives are not allowed without comment")]
+  MissingDescription,
+  #[display(fmt = "`@ts-{}` directive is not allowed", _0)]
+  Banned(String),
+}
+
+#[derive(Display)]
+enum BanTsCommentHint {
+  #[display(
+    fmt = "Add an in-line comment explaining the reason for using this directive"
+  )]
+  AddDescription,
+  #[display(fmt = "Remove the directive and fix the code it applies to")]
+  RemoveDirective,
+}
 
 impl BanTsComment {
-  fn report(&self, context: &mut Context, span: Span) {
-    context.add_diagnostic_with_hint(
-      span,
-      "ban-ts-comment",
-      "ts directives are not allowed without comment",
-      "Add an in-line comment explaining the reason for using this directive",
-    );
+  pub fn with_options(options: BanTsCommentOptions) -> Box<Self> {
+    Box::new(BanTsComment { options })
+  }
+
+  fn mode(&self, directive: &str) -> DirectiveMode {
+    match directive {
+      "expect-error" => self.options.ts_expect_error,
+      "ignore" => self.options.ts_ignore,
+      "nocheck" => self.options.ts_nocheck,
+      _ => self.options.ts_check,
+    }
+  }
+
+  /// Returns the diagnostic to report for `comment`, if any.
+  fn check_comment(
+    &self,
+    comment: &Comment,
+  ) -> Option<(Span, BanTsCommentMessage, BanTsCommentHint)> {
+    let (directive, description) = parse_directive(comment)?;
+    match self.mode(directive) {
+      DirectiveMode::Allow => None,
+      DirectiveMode::AllowWithDescription => {
+        if description.is_empty()
+          || description.chars().count()
+            < self.options.minimum_description_length
+        {
+          Some((
+            comment.span,
+            BanTsCommentMessage::MissingDescription,
+            BanTsCommentHint::AddDescription,
+          ))
+        } else {
+          None
+        }
+      }
+      DirectiveMode::Ban => Some((
+        comment.span,
+        BanTsCommentMessage::Banned(directive.to_string()),
+        BanTsCommentHint::RemoveDirective,
+      )),
+    }
   }
 }
 
 impl LintRule for BanTsComment {
   fn new() -> Box<Self> {
-    Box::new(BanTsComment)
+    BanTsComment::with_options(BanTsCommentOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -41,7 +133,50 @@ impl LintRule for BanTsComment {
   }
 
   fn code(&self) -> &'static str {
-    "ban-ts-comment"
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "tsExpectError": {
+          "type": "string",
+          "enum": ["allow", "allow-with-description", "ban"],
+          "description": "How `@ts-expect-error` directives are treated.",
+          "default": "allow-with-description"
+        },
+        "tsIgnore": {
+          "type": "string",
+          "enum": ["allow", "allow-with-description", "ban"],
+          "description": "How `@ts-ignore` directives are treated.",
+          "default": "allow-with-description"
+        },
+        "tsNocheck": {
+          "type": "string",
+          "enum": ["allow", "allow-with-description", "ban"],
+          "description": "How `@ts-nocheck` directives are treated.",
+          "default": "allow-with-description"
+        },
+        "tsCheck": {
+          "type": "string",
+          "enum": ["allow", "allow-with-description", "ban"],
+          "description": "How `@ts-check` directives are treated.",
+          "default": "allow"
+        },
+        "minimumDescriptionLength": {
+          "type": "integer",
+          "minimum": 0,
+          "description": "Minimum number of characters of the description of directives set to `allow-with-description`.",
+          "default": 1
+        }
+      },
+      "additionalProperties": false
+    }))
   }
 
   fn lint_program(
@@ -49,27 +184,25 @@ impl LintRule for BanTsComment {
     context: &mut Context,
     _program: &swc_ecmascript::ast::Program,
   ) {
-    let mut violated_comment_spans = Vec::new();
+    let mut violations = Vec::new();
 
-    violated_comment_spans.extend(
-      context.leading_comments.values().flatten().filter_map(|c| {
-        if check_comment(c) {
-          Some(c.span)
-        } else {
-          None
-        }
-      }),
+    violations.extend(
+      context
+        .leading_comments
+        .values()
+        .flatten()
+        .filter_map(|c| self.check_comment(c)),
     );
-    violated_comment_spans.extend(
+    violations.extend(
       context
         .trailing_comments
         .values()
         .flatten()
-        .filter_map(|c| if check_comment(c) { Some(c.span) } else { None }),
+        .filter_map(|c| self.check_comment(c)),
     );
 
-    for span in violated_comment_spans {
-      self.report(context, span);
+    for (span, message, hint) in violations {
+      context.add_diagnostic_with_hint(span, CODE, message, hint);
     }
   }
 
@@ -105,26 +238,41 @@ let a: number = "I am a string";
 // @ts-nocheck: Temporary workaround (see ticket #422)
 let a: number = "I am a string";
 ```
+
+### Options
+
+Each of `tsExpectError`, `tsIgnore`, `tsNocheck` and `tsCheck` can be set to
+`"allow"`, `"allow-with-description"` or `"ban"`. `@ts-check` is allowed by
+default, the others are allowed with a description.
+`minimumDescriptionLength` sets how long the description must be, and defaults
+to 1.
 "#
   }
 }
 
-/// Returns `true` if the comment should be reported.
-fn check_comment(comment: &Comment) -> bool {
+/// Returns the directive of the comment, e.g. `ignore` for `@ts-ignore`, and
+/// the description following it.
+fn parse_directive(comment: &Comment) -> Option<(&str, &str)> {
   if comment.kind != CommentKind::Line {
-    return false;
+    return None;
   }
 
   static BTC_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r#"^/*\s*@ts-(expect-error|ignore|nocheck)$"#).unwrap()
+    Regex::new(r#"^/*\s*@ts-(expect-error|ignore|nocheck|check)\b(.*)$"#)
+      .unwrap()
   });
 
-  BTC_REGEX.is_match(&comment.text)
+  let captures = BTC_REGEX.captures(&comment.text)?;
+  let directive = captures.get(1)?.as_str();
+  let description = captures.get(2)?.as_str().trim();
+  let description = description.trim_start_matches(':').trim_start();
+  Some((directive, description))
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::test_util::*;
 
   #[test]
   fn ban_ts_comment_valid() {
@@ -200,4 +348,65 @@ console.log('hello');
           ]
     };
   }
+
+  #[test]
+  fn ban_ts_comment_options() {
+    let options = BanTsCommentOptions {
+      ts_ignore: DirectiveMode::Ban,
+      ts_nocheck: DirectiveMode::Allow,
+      minimum_description_length: 10,
+      ..Default::default()
+    };
+    let sources = [
+      "// @ts-nocheck",
+      "// @ts-check",
+      "// @ts-expect-error: the types are wrong",
+    ];
+    for src in &sources {
+      assert_lint_ok_with_rule(
+        BanTsComment::with_options(options.clone()),
+        src,
+      );
+    }
+
+    let sources = [
+      "// @ts-ignore",
+      "// @ts-ignore: the types are wrong",
+      "// @ts-expect-error",
+      "// @ts-expect-error: wrong",
+    ];
+    for src in &sources {
+      assert_lint_err_on_line_with_rule(
+        BanTsComment::with_options(options.clone()),
+        src,
+        1,
+        0,
+      );
+    }
+
+    let options = BanTsCommentOptions {
+      ts_check: DirectiveMode::Ban,
+      ..Default::default()
+    };
+    assert_lint_err_on_line_with_rule(
+      BanTsComment::with_options(options),
+      "// @ts-check",
+      1,
+      0,
+    );
+  }
+
+  #[test]
+  fn ban_ts_comment_empty_description() {
+    assert_lint_ok! {
+      BanTsComment,
+      "// @ts-ignore: reason",
+      "// @ts-ignore reason",
+    };
+
+    let sources = ["// @ts-ignore:", "// @ts-ignore ", "// @ts-expect-error :"];
+    for src in &sources {
+      assert_lint_err_on_line::<BanTsComment>(src, 1, 0);
+    }
+  }
 }
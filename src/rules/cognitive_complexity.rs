@@ -0,0 +1,491 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  ArrowExpr, BinExpr, BinaryOp, BreakStmt, CatchClause, CondExpr, Constructor,
+  ContinueStmt, DoWhileStmt, Expr, ForInStmt, ForOfStmt, ForStmt, Function,
+  IfStmt, Stmt, SwitchStmt, WhileStmt,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct CognitiveComplexity {
+  options: CognitiveComplexityOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct CognitiveComplexityOptions {
+  /// Highest complexity allowed for a single function.
+  pub threshold: usize,
+}
+
+impl Default for CognitiveComplexityOptions {
+  fn default() -> Self {
+    Self { threshold: 15 }
+  }
+}
+
+const CODE: &str = "cognitive-complexity";
+
+#[derive(Display)]
+enum CognitiveComplexityMessage {
+  #[display(
+    fmt = "Function has a cognitive complexity of {} (max allowed is {})",
+    _0,
+    _1
+  )]
+  TooComplex(usize, usize),
+}
+
+#[derive(Display)]
+enum CognitiveComplexityHint {
+  #[display(
+    fmt = "Reduce nesting, or move parts of the function into smaller functions"
+  )]
+  Simplify,
+}
+
+impl CognitiveComplexity {
+  pub fn with_options(options: CognitiveComplexityOptions) -> Box<Self> {
+    Box::new(CognitiveComplexity { options })
+  }
+}
+
+impl LintRule for CognitiveComplexity {
+  fn new() -> Box<Self> {
+    CognitiveComplexity::with_options(CognitiveComplexityOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "threshold": {
+          "type": "integer",
+          "minimum": 0,
+          "description": "Highest complexity allowed for a single function.",
+          "default": 15
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor =
+      CognitiveComplexityVisitor::new(context, self.options.threshold);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows functions that are too hard to understand
+
+Cognitive complexity measures how hard the control flow of a function is to
+follow, as described by SonarSource. Each of the following adds one to the
+complexity of the enclosing function:
+
+- `if`, `else if` and `else`
+- conditional expressions
+- `switch`
+- `for`, `for...in`, `for...of`, `while` and `do...while` loops
+- `catch` clauses
+- `break` and `continue` with a label
+- each sequence of like logical operators, so `a && b && c` adds one but
+  `a && b || c` adds two
+
+Structures other than `else` and `else if` additionally add one for each level
+they are nested in. Nested functions are measured on their own.
+
+Functions are reported when their complexity exceeds the `threshold` option
+(15 by default). The complexities of all functions are summed up in the
+`cognitive-complexity` metric of the file.
+
+### Invalid:
+```typescript
+// With a threshold of 5
+function process(items, options) {
+  if (options) {                      // +1
+    for (const item of items) {       // +2 (nesting = 1)
+      if (item.enabled && item.ready) { // +3 (nesting = 2), +1 for `&&`
+        handle(item);
+      }
+    }
+  }
+}
+```
+
+### Valid:
+```typescript
+// With a threshold of 5
+function process(items, options) {
+  if (!options) {                     // +1
+    return;
+  }
+  for (const item of items) {         // +1
+    if (isActive(item)) {             // +2 (nesting = 1)
+      handle(item);
+    }
+  }
+}
+```
+"#
+  }
+}
+
+struct Frame {
+  complexity: usize,
+  nesting: usize,
+}
+
+struct CognitiveComplexityVisitor<'c> {
+  context: &'c mut Context,
+  threshold: usize,
+  /// One frame per enclosing function, innermost last.
+  frames: Vec<Frame>,
+}
+
+impl<'c> CognitiveComplexityVisitor<'c> {
+  fn new(context: &'c mut Context, threshold: usize) -> Self {
+    Self {
+      context,
+      threshold,
+      frames: Vec::new(),
+    }
+  }
+
+  /// Adds a structural increment, plus the nesting level if `nested`.
+  fn increment(&mut self, nested: bool) {
+    if let Some(frame) = self.frames.last_mut() {
+      frame.complexity += 1;
+      if nested {
+        frame.complexity += frame.nesting;
+      }
+    }
+  }
+
+  fn with_nesting<F>(&mut self, f: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    if let Some(frame) = self.frames.last_mut() {
+      frame.nesting += 1;
+    }
+    f(self);
+    if let Some(frame) = self.frames.last_mut() {
+      frame.nesting -= 1;
+    }
+  }
+
+  fn check_function<F>(&mut self, span: Span, f: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    self.frames.push(Frame {
+      complexity: 0,
+      nesting: 0,
+    });
+    f(self);
+    let frame = self.frames.pop().unwrap();
+    self.context.add_metric(CODE, frame.complexity as f64);
+    if frame.complexity > self.threshold {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        CognitiveComplexityMessage::TooComplex(
+          frame.complexity,
+          self.threshold,
+        ),
+        CognitiveComplexityHint::Simplify,
+      );
+    }
+  }
+
+  /// `else if` is counted like `else`, without a nesting increment.
+  fn check_if_stmt(&mut self, if_stmt: &IfStmt, is_else_if: bool) {
+    self.increment(!is_else_if);
+    if_stmt.test.visit_with(if_stmt, self);
+    self.with_nesting(|v| if_stmt.cons.visit_with(if_stmt, v));
+
+    match if_stmt.alt.as_deref() {
+      Some(Stmt::If(else_if)) => self.check_if_stmt(else_if, true),
+      Some(alt) => {
+        self.increment(false);
+        self.with_nesting(|v| alt.visit_with(if_stmt, v));
+      }
+      None => {}
+    }
+  }
+}
+
+/// Collects the operators and operands of a chain of logical expressions, in
+/// source order.
+fn flatten_logical<'a>(
+  expr: &'a Expr,
+  ops: &mut Vec<BinaryOp>,
+  operands: &mut Vec<&'a Expr>,
+) {
+  match expr {
+    Expr::Bin(bin_expr) if is_logical(bin_expr.op) => {
+      flatten_logical(&bin_expr.left, ops, operands);
+      ops.push(bin_expr.op);
+      flatten_logical(&bin_expr.right, ops, operands);
+    }
+    _ => operands.push(expr),
+  }
+}
+
+fn is_logical(op: BinaryOp) -> bool {
+  matches!(
+    op,
+    BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing
+  )
+}
+
+impl<'c> Visit for CognitiveComplexityVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
+    self.check_function(function.span, |v| function.visit_children_with(v));
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    self.check_function(arrow_expr.span, |v| arrow_expr.visit_children_with(v));
+  }
+
+  fn visit_constructor(
+    &mut self,
+    constructor: &Constructor,
+    _parent: &dyn Node,
+  ) {
+    self
+      .check_function(constructor.span, |v| constructor.visit_children_with(v));
+  }
+
+  fn visit_if_stmt(&mut self, if_stmt: &IfStmt, _parent: &dyn Node) {
+    self.check_if_stmt(if_stmt, false);
+  }
+
+  fn visit_cond_expr(&mut self, cond_expr: &CondExpr, _parent: &dyn Node) {
+    self.increment(true);
+    cond_expr.test.visit_with(cond_expr, self);
+    self.with_nesting(|v| {
+      cond_expr.cons.visit_with(cond_expr, v);
+      cond_expr.alt.visit_with(cond_expr, v);
+    });
+  }
+
+  fn visit_switch_stmt(
+    &mut self,
+    switch_stmt: &SwitchStmt,
+    _parent: &dyn Node,
+  ) {
+    self.increment(true);
+    switch_stmt.discriminant.visit_with(switch_stmt, self);
+    self.with_nesting(|v| {
+      for case in &switch_stmt.cases {
+        case.visit_with(switch_stmt, v);
+      }
+    });
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _parent: &dyn Node) {
+    self.increment(true);
+    self.with_nesting(|v| for_stmt.visit_children_with(v));
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _parent: &dyn Node) {
+    self.increment(true);
+    self.with_nesting(|v| for_in_stmt.visit_children_with(v));
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _parent: &dyn Node) {
+    self.increment(true);
+    self.with_nesting(|v| for_of_stmt.visit_children_with(v));
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _parent: &dyn Node) {
+    self.increment(true);
+    self.with_nesting(|v| while_stmt.visit_children_with(v));
+  }
+
+  fn visit_do_while_stmt(
+    &mut self,
+    do_while_stmt: &DoWhileStmt,
+    _parent: &dyn Node,
+  ) {
+    self.increment(true);
+    self.with_nesting(|v| do_while_stmt.visit_children_with(v));
+  }
+
+  fn visit_catch_clause(
+    &mut self,
+    catch_clause: &CatchClause,
+    _parent: &dyn Node,
+  ) {
+    self.increment(true);
+    self.with_nesting(|v| catch_clause.visit_children_with(v));
+  }
+
+  fn visit_break_stmt(&mut self, break_stmt: &BreakStmt, _parent: &dyn Node) {
+    if break_stmt.label.is_some() {
+      self.increment(false);
+    }
+  }
+
+  fn visit_continue_stmt(
+    &mut self,
+    continue_stmt: &ContinueStmt,
+    _parent: &dyn Node,
+  ) {
+    if continue_stmt.label.is_some() {
+      self.increment(false);
+    }
+  }
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    if !is_logical(bin_expr.op) {
+      bin_expr.visit_children_with(self);
+      return;
+    }
+
+    let mut ops = Vec::new();
+    let mut operands = Vec::new();
+    flatten_logical(&bin_expr.left, &mut ops, &mut operands);
+    ops.push(bin_expr.op);
+    flatten_logical(&bin_expr.right, &mut ops, &mut operands);
+
+    // One increment per sequence of like operators
+    for (i, op) in ops.iter().enumerate() {
+      if i == 0 || ops[i - 1] != *op {
+        self.increment(false);
+      }
+    }
+    for operand in operands {
+      operand.visit_with(bin_expr, self);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn cognitive_complexity_valid() {
+    assert_lint_ok! {
+      CognitiveComplexity,
+      r#"
+function f(a, b, c) {
+  if (a) {
+    foo();
+  }
+  if (b) {
+    bar();
+  } else {
+    baz();
+  }
+  for (const x of c) {
+    handle(x);
+  }
+  while (a && b) {
+    a = next();
+  }
+  return a ? b : c;
+}
+      "#,
+      r#"
+function f(a, b) {
+  if (a) {
+    return () => {
+      if (b) {
+        for (const x of b) {
+          while (x) {
+            x.next();
+          }
+        }
+      }
+    };
+  }
+}
+      "#,
+    };
+  }
+
+  #[test]
+  fn cognitive_complexity_invalid() {
+    assert_lint_err! {
+      CognitiveComplexity,
+      r#"
+function f(a, b, c) {
+  if (a) {
+    for (const x of b) {
+      while (c) {
+        if (x && c) {
+          switch (x) {
+            case 1:
+              break;
+          }
+        }
+      }
+    }
+  }
+}
+      "#: [
+        {
+          line: 2,
+          col: 0,
+          message: CognitiveComplexityMessage::TooComplex(16, 15),
+          hint: CognitiveComplexityHint::Simplify,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn cognitive_complexity_threshold() {
+    let rule = || {
+      CognitiveComplexity::with_options(CognitiveComplexityOptions {
+        threshold: 2,
+      })
+    };
+    // `if` + `else if` + `else`
+    assert_lint_err_on_line_with_rule(
+      rule(),
+      "function f(a, b) { if (a) {} else if (b) {} else {} }",
+      1,
+      0,
+    );
+    // Two sequences of logical operators and a ternary
+    assert_lint_err_on_line_with_rule(
+      rule(),
+      "const f = () => a && b && c || d ? 1 : 2;",
+      1,
+      10,
+    );
+    assert_lint_ok_with_rule(rule(), "function f() { return a && b && c; }");
+    assert_lint_ok_with_rule(
+      rule(),
+      "function f(a) { outer: for (;;) { break outer; } }",
+    );
+  }
+}
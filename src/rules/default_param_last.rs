@@ -2,7 +2,9 @@
 use super::Context;
 use super::LintRule;
 use swc_common::Span;
-use swc_ecmascript::ast::{ArrowExpr, Function, Pat};
+use swc_ecmascript::ast::{
+  ArrowExpr, Constructor, Function, ParamOrTsParamProp, Pat, TsParamPropParam,
+};
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::VisitAll;
@@ -36,10 +38,14 @@ of the function call without mapping the function inputs to different parameters
 which is confusing and error prone.  Specifying them last allows them to be left
 out without changing the semantics of the other parameters.
 
+Optional parameters (`a?: T`) are treated the same as parameters with default
+values.
+
 ### Invalid:
 ```typescript
 function f(a = 2, b) {}
 function f(a = 5, b, c = 5) {}
+function f(a?: number, b: number) {}
 ```
     
 ### Valid:
@@ -51,11 +57,47 @@ function f(a, b = 5) {}
 function f(a, b = 5, c = 5) {}
 function f(a, b = 5, ...c) {}
 function f(a = 2, b = 3) {}
+function f(a: number, b?: number) {}
 ```
 "#
   }
 }
 
+enum ParamKind {
+  /// A parameter with a default value, or an optional one
+  Optional(Span),
+  Rest,
+  Required,
+}
+
+impl From<&Pat> for ParamKind {
+  fn from(pat: &Pat) -> Self {
+    match pat {
+      Pat::Assign(assign_pat) => ParamKind::Optional(assign_pat.span),
+      Pat::Ident(ident) if ident.optional => ParamKind::Optional(ident.span),
+      Pat::Rest(_) => ParamKind::Rest,
+      _ => ParamKind::Required,
+    }
+  }
+}
+
+impl From<&ParamOrTsParamProp> for ParamKind {
+  fn from(param: &ParamOrTsParamProp) -> Self {
+    match param {
+      ParamOrTsParamProp::Param(param) => ParamKind::from(&param.pat),
+      ParamOrTsParamProp::TsParamProp(prop) => match &prop.param {
+        TsParamPropParam::Assign(assign_pat) => {
+          ParamKind::Optional(assign_pat.span)
+        }
+        TsParamPropParam::Ident(ident) if ident.optional => {
+          ParamKind::Optional(ident.span)
+        }
+        TsParamPropParam::Ident(_) => ParamKind::Required,
+      },
+    }
+  }
+}
+
 struct DefaultParamLastVisitor<'c> {
   context: &'c mut Context,
 }
@@ -74,20 +116,20 @@ impl<'c> DefaultParamLastVisitor<'c> {
     );
   }
 
-  fn check_params<'a, 'b, I>(&'a mut self, params: I)
+  fn check_params<I>(&mut self, params: I)
   where
-    I: Iterator<Item = &'b Pat>,
+    I: Iterator<Item = ParamKind>,
   {
     let mut has_seen_normal_param = false;
     for param in params {
       match param {
-        Pat::Assign(pat) => {
+        ParamKind::Optional(span) => {
           if has_seen_normal_param {
-            self.report(pat.span);
+            self.report(span);
           }
         }
-        Pat::Rest(_) => {}
-        _ => {
+        ParamKind::Rest => {}
+        ParamKind::Required => {
           has_seen_normal_param = true;
         }
       }
@@ -99,11 +141,25 @@ impl<'c> VisitAll for DefaultParamLastVisitor<'c> {
   noop_visit_type!();
 
   fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
-    self.check_params(function.params.iter().rev().map(|p| &p.pat));
+    self.check_params(
+      function
+        .params
+        .iter()
+        .rev()
+        .map(|p| ParamKind::from(&p.pat)),
+    );
   }
 
   fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
-    self.check_params(arrow_expr.params.iter().rev());
+    self.check_params(arrow_expr.params.iter().rev().map(ParamKind::from));
+  }
+
+  fn visit_constructor(
+    &mut self,
+    constructor: &Constructor,
+    _parent: &dyn Node,
+  ) {
+    self.check_params(constructor.params.iter().rev().map(ParamKind::from));
   }
 }
 
@@ -134,6 +190,12 @@ mod tests {
       "const f = function f() {}",
       "const f = function f(a) {}",
       "const f = function f(a = 5) {}",
+      "function f(a: number, b?: number) {}",
+      "function f(a?: number, b?: number) {}",
+      "const f = (a, b?) => {}",
+      "class Foo { constructor(a, b = 1) {} }",
+      "class Foo { constructor(private a: number, private b = 1) {} }",
+      "class Foo { constructor(a: number, public b?: number) {} }",
       r#"
 class Foo {
   bar(a, b = 2) {}
@@ -171,6 +233,23 @@ class Foo {
       "const f = ([a, b] = [1, 2], c) => {}",
       11,
     );
+    assert_lint_err::<DefaultParamLast>(
+      "function f(a?: number, b: number) {}",
+      11,
+    );
+    assert_lint_err::<DefaultParamLast>("const f = (a?, b) => {}", 11);
+    assert_lint_err::<DefaultParamLast>(
+      "class Foo { constructor(a = 1, b) {} }",
+      24,
+    );
+    assert_lint_err::<DefaultParamLast>(
+      "class Foo { constructor(private a = 1, private b: number) {} }",
+      32,
+    );
+    assert_lint_err::<DefaultParamLast>(
+      "class Foo { constructor(public a?: number, b: number) {} }",
+      31,
+    );
     assert_lint_err_on_line::<DefaultParamLast>(
       r#"
 class Foo {
@@ -158,35 +158,48 @@ impl<'c> VisitAll for ForDirectionVisitor<'c> {
 
     if let Some(test) = &for_stmt.test {
       if let Expr::Bin(bin_expr) = &**test {
-        let counter_name = match &*bin_expr.left {
-          Expr::Ident(ident) => ident.sym.as_ref(),
-          _ => return,
-        };
-
+        // Direction that moves a counter on the left of the comparison away
+        // from the end
         let wrong_direction = match &bin_expr.op {
           BinaryOp::Lt | BinaryOp::LtEq => -1,
           BinaryOp::Gt | BinaryOp::GtEq => 1,
           _ => return,
         };
 
+        // The counter may be on either side, e.g. `i < 10` or `10 > i`
+        let candidates = [
+          (&*bin_expr.left, wrong_direction),
+          (&*bin_expr.right, -wrong_direction),
+        ];
         let update = for_stmt.update.as_ref().unwrap();
-        let update_direction = match &**update {
-          Expr::Update(update_expr) => {
-            self.check_update_direction(update_expr, counter_name)
-          }
-          Expr::Assign(assign_expr) => {
-            self.check_assign_direction(assign_expr, counter_name)
+        for (operand, wrong_direction) in candidates.iter() {
+          let counter_name = match operand {
+            Expr::Ident(ident) => ident.sym.as_ref(),
+            _ => continue,
+          };
+
+          let update_direction = match &**update {
+            Expr::Update(update_expr) => {
+              self.check_update_direction(update_expr, counter_name)
+            }
+            Expr::Assign(assign_expr) => {
+              self.check_assign_direction(assign_expr, counter_name)
+            }
+            _ => return,
+          };
+          if update_direction == 0 {
+            continue;
           }
-          _ => return,
-        };
 
-        if update_direction == wrong_direction {
-          self.context.add_diagnostic_with_hint(
-            for_stmt.span,
-            "for-direction",
-            MESSAGE,
-            HINT,
-          );
+          if update_direction == *wrong_direction {
+            self.context.add_diagnostic_with_hint(
+              for_stmt.span,
+              "for-direction",
+              MESSAGE,
+              HINT,
+            );
+          }
+          break;
         }
       }
     }
@@ -232,6 +245,10 @@ mod tests {
       "for(let i = 0; i === 0; i++) {}",
       "for(let i = 0; i == 0; i++) {}",
       "for(let i = 0; i < 2; ++i) { for (let j = 0; j < 2; j++) {} }",
+      "for(let i = 0; 2 > i; i++) {}",
+      "for(let i = 2; 0 <= i; i--) {}",
+      "for(let i = 0; n > i; i += 1) {}",
+      "for(let i = 0; i < n; n--) {}",
     };
   }
 
@@ -356,6 +373,29 @@ mod tests {
         }
       ],
 
+      // counter on the right
+      "for(let i = 0; 2 > i; i--) {}": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "for(let i = 2; 0 <= i; i++) {}": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "for(let i = 0; n > i; i -= 1) {}": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+
       // nested
       r#"
 for (let i = 0; i < 2; i++) {
@@ -0,0 +1,276 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_ecmascript::ast::{Expr, FnDecl, VarDeclarator};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct FuncStyle {
+  options: FuncStyleOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct FuncStyleOptions {
+  /// The style functions must be defined with.
+  pub style: FunctionStyle,
+  /// Allow `const foo = () => {}` when `style` is `declaration`.
+  pub allow_arrow_functions: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FunctionStyle {
+  Expression,
+  Declaration,
+}
+
+impl Default for FunctionStyle {
+  fn default() -> Self {
+    FunctionStyle::Expression
+  }
+}
+
+const CODE: &str = "func-style";
+
+#[derive(Display)]
+enum FuncStyleMessage {
+  #[display(fmt = "Expected a function expression")]
+  ExpectedExpression,
+  #[display(fmt = "Expected a function declaration")]
+  ExpectedDeclaration,
+}
+
+#[derive(Display)]
+enum FuncStyleHint {
+  #[display(fmt = "Assign a function expression to a variable instead")]
+  UseExpression,
+  #[display(fmt = "Use a function declaration instead")]
+  UseDeclaration,
+}
+
+impl FuncStyle {
+  pub fn with_options(options: FuncStyleOptions) -> Box<Self> {
+    Box::new(FuncStyle { options })
+  }
+}
+
+impl LintRule for FuncStyle {
+  fn new() -> Box<Self> {
+    FuncStyle::with_options(FuncStyleOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "style": {
+          "type": "string",
+          "enum": ["expression", "declaration"],
+          "description": "The style functions must be defined with.",
+          "default": "expression"
+        },
+        "allowArrowFunctions": {
+          "type": "boolean",
+          "description": "Allow `const foo = () => {}` when `style` is `declaration`.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = FuncStyleVisitor::new(context, &self.options);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a consistent style of defining named functions
+
+A named function can be defined with a function declaration, or by assigning a
+function expression to a variable. This rule enforces one of the two.
+
+The `style` option is either `expression` (the default) or `declaration`. With
+`declaration`, arrow functions assigned to variables are reported as well,
+unless `allowArrowFunctions` is set. Default exports and overload signatures
+are never reported.
+
+### Invalid:
+```typescript
+// With `style: "expression"`
+function foo() {}
+// With `style: "declaration"`
+const bar = function () {};
+const baz = () => {};
+```
+
+### Valid:
+```typescript
+// With `style: "expression"`
+const foo = function () {};
+const bar = () => {};
+// With `style: "declaration"`
+function baz() {}
+// With `style: "declaration"` and `allowArrowFunctions: true`
+const qux = () => {};
+```
+"#
+  }
+}
+
+struct FuncStyleVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o FuncStyleOptions,
+}
+
+impl<'c, 'o> FuncStyleVisitor<'c, 'o> {
+  fn new(context: &'c mut Context, options: &'o FuncStyleOptions) -> Self {
+    Self { context, options }
+  }
+}
+
+impl<'c, 'o> Visit for FuncStyleVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_fn_decl(&mut self, fn_decl: &FnDecl, _parent: &dyn Node) {
+    // Ambient declarations and overload signatures have no expression form
+    if self.options.style == FunctionStyle::Expression
+      && fn_decl.function.body.is_some()
+    {
+      self.context.add_diagnostic_with_hint(
+        fn_decl.function.span,
+        CODE,
+        FuncStyleMessage::ExpectedExpression,
+        FuncStyleHint::UseExpression,
+      );
+    }
+    fn_decl.visit_children_with(self);
+  }
+
+  fn visit_var_declarator(
+    &mut self,
+    var_declarator: &VarDeclarator,
+    _parent: &dyn Node,
+  ) {
+    if self.options.style == FunctionStyle::Declaration {
+      let is_function = match var_declarator.init.as_deref() {
+        Some(Expr::Fn(_)) => true,
+        Some(Expr::Arrow(_)) => !self.options.allow_arrow_functions,
+        _ => false,
+      };
+      if is_function {
+        self.context.add_diagnostic_with_hint(
+          var_declarator.span,
+          CODE,
+          FuncStyleMessage::ExpectedDeclaration,
+          FuncStyleHint::UseDeclaration,
+        );
+      }
+    }
+    var_declarator.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn declaration(allow_arrow_functions: bool) -> Box<FuncStyle> {
+    FuncStyle::with_options(FuncStyleOptions {
+      style: FunctionStyle::Declaration,
+      allow_arrow_functions,
+    })
+  }
+
+  #[test]
+  fn func_style_valid() {
+    assert_lint_ok! {
+      FuncStyle,
+      "const foo = function () {};",
+      "const foo = () => {};",
+      "export default function foo() {}",
+      "const obj = { foo() {} };",
+      "function foo(): void;",
+      "declare function foo(): void;",
+    };
+
+    let sources = [
+      "function foo() {}",
+      "const a = 1;",
+      "let foo;",
+      "foo(function () {});",
+      "const foo = bar(() => {});",
+    ];
+    for src in &sources {
+      assert_lint_ok_with_rule(declaration(false), src);
+    }
+
+    let sources = ["const foo = () => {};", "function foo() {}"];
+    for src in &sources {
+      assert_lint_ok_with_rule(declaration(true), src);
+    }
+  }
+
+  #[test]
+  fn func_style_invalid() {
+    assert_lint_err! {
+      FuncStyle,
+      "function foo() {}": [
+        {
+          col: 0,
+          message: FuncStyleMessage::ExpectedExpression,
+          hint: FuncStyleHint::UseExpression,
+        }
+      ],
+      "const foo = () => {\n  function bar() {}\n};": [
+        {
+          line: 2,
+          col: 2,
+          message: FuncStyleMessage::ExpectedExpression,
+          hint: FuncStyleHint::UseExpression,
+        }
+      ],
+      "export function foo() {}": [
+        {
+          col: 7,
+          message: FuncStyleMessage::ExpectedExpression,
+          hint: FuncStyleHint::UseExpression,
+        }
+      ],
+    };
+
+    let sources = [
+      ("const foo = function () {};", 6),
+      ("var foo = () => {};", 4),
+      ("let a = 1, foo = function bar() {};", 11),
+    ];
+    for (src, col) in &sources {
+      assert_lint_err_on_line_with_rule(declaration(false), src, 1, *col);
+    }
+    assert_lint_err_on_line_with_rule(
+      declaration(true),
+      "const foo = function () {};",
+      1,
+      6,
+    );
+  }
+}
@@ -3,6 +3,8 @@ use super::Context;
 use super::LintRule;
 use crate::swc_util::StringRepr;
 use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use std::collections::BTreeMap;
 use std::mem;
 use swc_common::{Span, Spanned};
@@ -16,7 +18,16 @@ use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
 use swc_ecmascript::visit::VisitWith;
 
-pub struct GetterReturn;
+pub struct GetterReturn {
+  options: GetterReturnOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct GetterReturnOptions {
+  /// Allows a bare `return;` without a value in getters.
+  pub allow_implicit: bool,
+}
 
 const CODE: &str = "getter-return";
 
@@ -34,9 +45,15 @@ enum GetterReturnHint {
   Return,
 }
 
+impl GetterReturn {
+  pub fn with_options(options: GetterReturnOptions) -> Box<Self> {
+    Box::new(GetterReturn { options })
+  }
+}
+
 impl LintRule for GetterReturn {
   fn new() -> Box<Self> {
-    Box::new(GetterReturn)
+    GetterReturn::with_options(GetterReturnOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -47,12 +64,31 @@ impl LintRule for GetterReturn {
     CODE
   }
 
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "allowImplicit": {
+          "type": "boolean",
+          "description": "Allows a bare `return;` without a value in getters.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
   fn lint_program(
     &self,
     context: &mut Context,
     program: &swc_ecmascript::ast::Program,
   ) {
-    let mut visitor = GetterReturnVisitor::new(context);
+    let mut visitor =
+      GetterReturnVisitor::new(context, self.options.allow_implicit);
     visitor.visit_program(program, program);
     visitor.report();
   }
@@ -63,6 +99,9 @@ impl LintRule for GetterReturn {
 Getter functions return the value of a property.  If the function returns no
 value then this contract is broken.
 
+When `allowImplicit` option is enabled, a bare `return;` is allowed in getters,
+but every path must still reach a `return` statement.
+
 ### Invalid:
 ```typescript
 let foo = { 
@@ -99,15 +138,18 @@ struct GetterReturnVisitor<'c> {
   getter_name: Option<String>,
   // `true` if a getter contains as least one return statement.
   has_return: bool,
+  /// If `true`, `return;` without a value is allowed.
+  allow_implicit: bool,
 }
 
 impl<'c> GetterReturnVisitor<'c> {
-  fn new(context: &'c mut Context) -> Self {
+  fn new(context: &'c mut Context, allow_implicit: bool) -> Self {
     Self {
       context,
       errors: BTreeMap::new(),
       getter_name: None,
       has_return: false,
+      allow_implicit,
     }
   }
 
@@ -153,7 +195,7 @@ impl<'c> GetterReturnVisitor<'c> {
 
     if self
       .context
-      .control_flow
+      .control_flow()
       .meta(getter_body_span.lo)
       .unwrap()
       .continues_execution()
@@ -330,7 +372,7 @@ impl<'c> Visit for GetterReturnVisitor<'c> {
   fn visit_return_stmt(&mut self, return_stmt: &ReturnStmt, _: &dyn Node) {
     if self.getter_name.is_some() {
       self.has_return = true;
-      if return_stmt.arg.is_none() {
+      if return_stmt.arg.is_none() && !self.allow_implicit {
         self.report_expected(return_stmt.span);
       }
     }
@@ -340,6 +382,7 @@ impl<'c> Visit for GetterReturnVisitor<'c> {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::test_util::*;
 
   // Some tests are derived from
   // https://github.com/eslint/eslint/blob/v7.9.0/tests/lib/rules/getter-return.js
@@ -677,4 +720,31 @@ Object.defineProperty(foo, 'bar', {
       ]
     };
   }
+
+  #[test]
+  fn getter_return_allow_implicit() {
+    let options = GetterReturnOptions {
+      allow_implicit: true,
+    };
+    assert_lint_ok_with_rule(
+      GetterReturn::with_options(options.clone()),
+      "const foo = { get bar() { return; } };",
+    );
+    assert_lint_ok_with_rule(
+      GetterReturn::with_options(options.clone()),
+      "class Foo { get bar() { if (baz) { return; } else { return 1; } } }",
+    );
+    assert_lint_err_on_line_with_rule(
+      GetterReturn::with_options(options.clone()),
+      "class Foo { get bar() {} }",
+      1,
+      12,
+    );
+    assert_lint_err_on_line_with_rule(
+      GetterReturn::with_options(options),
+      "const foo = { get bar() { if (baz) { return; } } };",
+      1,
+      14,
+    );
+  }
 }
@@ -0,0 +1,332 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_ecmascript::ast::{
+  ArrowExpr, BlockStmtOrExpr, Function, Ident, MemberExpr, Pat,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct HandleCallbackErr {
+  options: HandleCallbackErrOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct HandleCallbackErrOptions {
+  /// Name of the error parameter. A name starting with `^` is a regular
+  /// expression the name must match.
+  pub pattern: String,
+}
+
+impl Default for HandleCallbackErrOptions {
+  fn default() -> Self {
+    HandleCallbackErrOptions {
+      pattern: "^(err|error)$".to_string(),
+    }
+  }
+}
+
+const CODE: &str = "handle-callback-err";
+
+#[derive(Display)]
+enum HandleCallbackErrMessage {
+  #[display(fmt = "Expected error `{}` to be handled", _0)]
+  Unhandled(String),
+}
+
+#[derive(Display)]
+enum HandleCallbackErrHint {
+  #[display(fmt = "Check the error, or pass it on to the caller")]
+  HandleError,
+}
+
+/// Decides whether a parameter name is an error parameter.
+enum ErrorName {
+  Exact(String),
+  Pattern(Regex),
+}
+
+impl ErrorName {
+  fn new(pattern: &str) -> Self {
+    if pattern.starts_with('^') {
+      if let Ok(regex) = Regex::new(pattern) {
+        return ErrorName::Pattern(regex);
+      }
+    }
+    ErrorName::Exact(pattern.to_string())
+  }
+
+  fn matches(&self, name: &str) -> bool {
+    match self {
+      ErrorName::Exact(exact) => exact == name,
+      ErrorName::Pattern(regex) => regex.is_match(name),
+    }
+  }
+}
+
+impl HandleCallbackErr {
+  pub fn with_options(options: HandleCallbackErrOptions) -> Box<Self> {
+    Box::new(HandleCallbackErr { options })
+  }
+}
+
+impl LintRule for HandleCallbackErr {
+  fn new() -> Box<Self> {
+    HandleCallbackErr::with_options(HandleCallbackErrOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "pattern": {
+          "type": "string",
+          "description": "Name of the error parameter. A name starting with `^` is a regular expression the name must match.",
+          "default": "^(err|error)$"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let error_name = ErrorName::new(&self.options.pattern);
+    let mut visitor = HandleCallbackErrVisitor::new(context, &error_name);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires the error parameter of Node.js style callbacks to be used
+
+Callbacks following the Node.js convention get an error as their first
+parameter. Ignoring it hides failures, and the other parameters are often
+meaningless when it's set. This rule reports functions whose first parameter is
+named like an error and is never used.
+
+The `pattern` option sets the name of the error parameter. A name starting
+with `^` is treated as a regular expression. It defaults to `^(err|error)$`.
+
+### Invalid:
+```typescript
+fs.readFile("foo.txt", (err, data) => {
+  console.log(data);
+});
+```
+
+### Valid:
+```typescript
+fs.readFile("foo.txt", (err, data) => {
+  if (err) {
+    throw err;
+  }
+  console.log(data);
+});
+```
+"#
+  }
+}
+
+struct HandleCallbackErrVisitor<'c, 'e> {
+  context: &'c mut Context,
+  error_name: &'e ErrorName,
+}
+
+impl<'c, 'e> HandleCallbackErrVisitor<'c, 'e> {
+  fn new(context: &'c mut Context, error_name: &'e ErrorName) -> Self {
+    Self {
+      context,
+      error_name,
+    }
+  }
+
+  /// Reports `first_param` if it's an error parameter that `body` never
+  /// references.
+  fn check<N: VisitWith<ReferenceFinder>>(
+    &mut self,
+    first_param: Option<&Pat>,
+    body: &N,
+  ) {
+    let ident = match first_param {
+      Some(Pat::Ident(ident)) => ident,
+      _ => return,
+    };
+    if !self.error_name.matches(&ident.sym) {
+      return;
+    }
+
+    let mut finder = ReferenceFinder {
+      id: ident.to_id(),
+      found: false,
+    };
+    body.visit_with(ident, &mut finder);
+    if !finder.found {
+      self.context.add_diagnostic_with_hint(
+        ident.span,
+        CODE,
+        HandleCallbackErrMessage::Unhandled(ident.sym.to_string()),
+        HandleCallbackErrHint::HandleError,
+      );
+    }
+  }
+}
+
+impl<'c, 'e> Visit for HandleCallbackErrVisitor<'c, 'e> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
+    if let Some(body) = &function.body {
+      self.check(function.params.first().map(|param| &param.pat), body);
+    }
+    function.visit_children_with(self);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    let first_param = arrow_expr.params.first();
+    match &arrow_expr.body {
+      BlockStmtOrExpr::BlockStmt(block_stmt) => {
+        self.check(first_param, block_stmt)
+      }
+      BlockStmtOrExpr::Expr(expr) => self.check(first_param, &**expr),
+    }
+    arrow_expr.visit_children_with(self);
+  }
+}
+
+/// Looks for references to the binding `id`.
+struct ReferenceFinder {
+  id: Id,
+  found: bool,
+}
+
+impl Visit for ReferenceFinder {
+  noop_visit_type!();
+
+  fn visit_ident(&mut self, ident: &Ident, _parent: &dyn Node) {
+    if ident.to_id() == self.id {
+      self.found = true;
+    }
+  }
+
+  fn visit_member_expr(
+    &mut self,
+    member_expr: &MemberExpr,
+    _parent: &dyn Node,
+  ) {
+    member_expr.obj.visit_with(member_expr, self);
+    // `foo.err` doesn't reference `err`
+    if member_expr.computed {
+      member_expr.prop.visit_with(member_expr, self);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn handle_callback_err_valid() {
+    assert_lint_ok! {
+      HandleCallbackErr,
+      "function f(err) { if (err) throw err; }",
+      "function f(error) { console.error(error); }",
+      "readFile(path, (err, data) => { if (err) return cb(err); cb(null, data); });",
+      "readFile(path, (err) => log(err));",
+      "readFile(path, function (err) { return () => err; });",
+      "readFile(path, (err) => { const e = { err }; });",
+      "function f(data, err) {}",
+      "function f(e) {}",
+      "function f({ err }) {}",
+      "function f() {}",
+      "class A { m(err) { throw err; } }",
+    };
+
+    let options = HandleCallbackErrOptions {
+      pattern: "^.+Error$".to_string(),
+    };
+    assert_lint_ok_with_rule(
+      HandleCallbackErr::with_options(options),
+      "function f(err) {}",
+    );
+  }
+
+  #[test]
+  fn handle_callback_err_invalid() {
+    assert_lint_err! {
+      HandleCallbackErr,
+      "function f(err) {}": [
+        {
+          col: 11,
+          message: HandleCallbackErrMessage::Unhandled("err".to_string()),
+          hint: HandleCallbackErrHint::HandleError,
+        }
+      ],
+      "readFile(path, (error, data) => { use(data); });": [
+        {
+          col: 16,
+          message: HandleCallbackErrMessage::Unhandled("error".to_string()),
+          hint: HandleCallbackErrHint::HandleError,
+        }
+      ],
+      "readFile(path, err => data);": [
+        {
+          col: 15,
+          message: HandleCallbackErrMessage::Unhandled("err".to_string()),
+          hint: HandleCallbackErrHint::HandleError,
+        }
+      ],
+      "readFile(path, function (err) { log(result.err); });": [
+        {
+          col: 25,
+          message: HandleCallbackErrMessage::Unhandled("err".to_string()),
+          hint: HandleCallbackErrHint::HandleError,
+        }
+      ],
+      "function f(err) { return (err) => err; }": [
+        {
+          col: 11,
+          message: HandleCallbackErrMessage::Unhandled("err".to_string()),
+          hint: HandleCallbackErrHint::HandleError,
+        }
+      ],
+    };
+
+    let sources = [
+      ("^.+Error$", "function f(fileError) {}", 11),
+      ("e", "function f(e) {}", 11),
+    ];
+    for (pattern, src, col) in &sources {
+      let options = HandleCallbackErrOptions {
+        pattern: pattern.to_string(),
+      };
+      assert_lint_err_on_line_with_rule(
+        HandleCallbackErr::with_options(options),
+        src,
+        1,
+        *col,
+      );
+    }
+  }
+}
@@ -0,0 +1,262 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::LintFix;
+use derive_more::Display;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{
+  Expr, JSXElement, JSXElementChild, JSXElementName, JSXFragment, JSXObject,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct JsxNoUselessFragment;
+
+const CODE: &str = "jsx-no-useless-fragment";
+
+#[derive(Display)]
+enum JsxNoUselessFragmentMessage {
+  #[display(fmt = "Fragments should contain more than one child")]
+  Useless,
+}
+
+#[derive(Display)]
+enum JsxNoUselessFragmentHint {
+  #[display(fmt = "Remove the fragment and keep its child, if any")]
+  Unwrap,
+}
+
+#[derive(Display)]
+enum JsxNoUselessFragmentFix {
+  #[display(fmt = "Remove the fragment")]
+  Unwrap,
+}
+
+impl LintRule for JsxNoUselessFragment {
+  fn new() -> Box<Self> {
+    Box::new(JsxNoUselessFragment)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = JsxNoUselessFragmentVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows fragments with less than two children
+
+A fragment groups several elements without adding a node to the DOM. Wrapping a
+single element, or nothing, in a fragment has no effect. This rule reports
+`<></>` fragments, and `<Fragment>` or `<React.Fragment>` elements without
+attributes, that contain at most one element.
+
+Fragments wrapping a single text or expression child are allowed, since they
+turn it into an element. A `<Fragment>` with a `key` is needed when rendering a
+list, so it's allowed as well.
+
+### Invalid:
+```typescript
+<><Foo /></>;
+<div><></></div>;
+<Fragment><Foo /></Fragment>;
+```
+
+### Valid:
+```typescript
+<><Foo /><Bar /></>;
+<>{children}</>;
+items.map((item) => <Fragment key={item.id}><Foo /></Fragment>);
+```
+"#
+  }
+}
+
+struct JsxNoUselessFragmentVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> JsxNoUselessFragmentVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  /// Reports the fragment at `span` if `children` hold at most one element.
+  /// An empty fragment can only be removed if it's a child of another
+  /// element, since an expression can't be empty.
+  fn check(
+    &mut self,
+    span: Span,
+    children: &[JSXElementChild],
+    is_jsx_child: bool,
+  ) {
+    let mut children = children.iter().filter(|child| !is_padding(child));
+    let replacement = match (children.next(), children.next()) {
+      (None, _) if is_jsx_child => Some(String::new()),
+      (None, _) => None,
+      (Some(child @ JSXElementChild::JSXElement(_)), None)
+      | (Some(child @ JSXElementChild::JSXFragment(_)), None) => {
+        match self.context.source_map.span_to_snippet(child.span()) {
+          Ok(snippet) => Some(snippet),
+          Err(_) => return,
+        }
+      }
+      _ => return,
+    };
+
+    match replacement {
+      Some(replacement) => {
+        let fix = LintFix {
+          description: JsxNoUselessFragmentFix::Unwrap.to_string(),
+          changes: vec![self.context.create_fix_change(span, replacement)],
+          priority: 0,
+        };
+        self.context.add_diagnostic_with_hint_and_fix(
+          span,
+          CODE,
+          JsxNoUselessFragmentMessage::Useless,
+          JsxNoUselessFragmentHint::Unwrap,
+          fix,
+        );
+      }
+      None => self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        JsxNoUselessFragmentMessage::Useless,
+        JsxNoUselessFragmentHint::Unwrap,
+      ),
+    }
+  }
+
+  fn check_fragment(&mut self, fragment: &JSXFragment, is_jsx_child: bool) {
+    self.check(fragment.span, &fragment.children, is_jsx_child);
+  }
+
+  fn check_element(&mut self, element: &JSXElement, is_jsx_child: bool) {
+    // Attributes like `key` make the fragment necessary
+    if is_fragment_name(&element.opening.name)
+      && element.opening.attrs.is_empty()
+    {
+      self.check(element.span, &element.children, is_jsx_child);
+    }
+  }
+}
+
+/// Checks if `child` is whitespace that JSX drops, like the indentation
+/// between elements on separate lines.
+fn is_padding(child: &JSXElementChild) -> bool {
+  match child {
+    JSXElementChild::JSXText(text) => {
+      text.value.contains('\n') && text.value.trim().is_empty()
+    }
+    _ => false,
+  }
+}
+
+/// Checks if `name` is `Fragment` or `React.Fragment`.
+fn is_fragment_name(name: &JSXElementName) -> bool {
+  match name {
+    JSXElementName::Ident(ident) => ident.sym == *"Fragment",
+    JSXElementName::JSXMemberExpr(member_expr) => {
+      member_expr.prop.sym == *"Fragment"
+        && matches!(
+          &member_expr.obj,
+          JSXObject::Ident(obj) if obj.sym == *"React"
+        )
+    }
+    JSXElementName::JSXNamespacedName(_) => false,
+  }
+}
+
+impl<'c> Visit for JsxNoUselessFragmentVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_expr(&mut self, expr: &Expr, _parent: &dyn Node) {
+    match expr {
+      Expr::JSXFragment(fragment) => self.check_fragment(fragment, false),
+      Expr::JSXElement(element) => self.check_element(element, false),
+      _ => {}
+    }
+    expr.visit_children_with(self);
+  }
+
+  fn visit_jsx_element_child(
+    &mut self,
+    child: &JSXElementChild,
+    _parent: &dyn Node,
+  ) {
+    match child {
+      JSXElementChild::JSXFragment(fragment) => {
+        self.check_fragment(fragment, true)
+      }
+      JSXElementChild::JSXElement(element) => self.check_element(element, true),
+      _ => {}
+    }
+    child.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn jsx_no_useless_fragment_valid() {
+    let sources = [
+      "<><Foo /><Bar /></>;",
+      "<>\n  <Foo />\n  <Bar />\n</>;",
+      "<>foo</>;",
+      "<>{children}</>;",
+      "<><Foo />bar</>;",
+      "<Fragment key={id}><Foo /></Fragment>;",
+      "<React.Fragment key={id}><Foo /></React.Fragment>;",
+      "<Other.Fragment><Foo /></Other.Fragment>;",
+      "<div><Foo /></div>;",
+    ];
+    for src in &sources {
+      assert_lint_ok_tsx::<JsxNoUselessFragment>(src);
+    }
+  }
+
+  #[test]
+  fn jsx_no_useless_fragment_invalid() {
+    let sources = [
+      ("<><Foo /></>;", 1, 0),
+      ("<></>;", 1, 0),
+      ("const a = <Fragment><Foo /></Fragment>;", 1, 10),
+      ("<React.Fragment />;", 1, 0),
+      ("<div>\n  <>\n    <Foo />\n  </>\n</div>;", 2, 2),
+      ("<><><Foo /></><Bar /></>;", 1, 2),
+    ];
+    for (src, line, col) in &sources {
+      assert_lint_err_on_line_tsx::<JsxNoUselessFragment>(src, *line, *col);
+    }
+  }
+
+  #[test]
+  fn jsx_no_useless_fragment_fix() {
+    assert_lint_fix_tsx::<JsxNoUselessFragment>("<><Foo /></>;", "<Foo />;");
+    assert_lint_fix_tsx::<JsxNoUselessFragment>(
+      "<div><></>text</div>;",
+      "<div>text</div>;",
+    );
+    assert_lint_fix_tsx::<JsxNoUselessFragment>(
+      "const a = (\n  <>\n    <Foo a={1} />\n  </>\n);",
+      "const a = (\n  <Foo a={1} />\n);",
+    );
+    assert_lint_fix_tsx::<JsxNoUselessFragment>(
+      "<React.Fragment><><Foo /><Bar /></></React.Fragment>;",
+      "<><Foo /><Bar /></>;",
+    );
+  }
+}
@@ -0,0 +1,218 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_ecmascript::ast::Class;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct MaxClassesPerFile {
+  options: MaxClassesPerFileOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct MaxClassesPerFileOptions {
+  /// Highest number of classes allowed in a file.
+  pub max: usize,
+}
+
+impl Default for MaxClassesPerFileOptions {
+  fn default() -> Self {
+    Self { max: 1 }
+  }
+}
+
+const CODE: &str = "max-classes-per-file";
+
+#[derive(Display)]
+enum MaxClassesPerFileMessage {
+  #[display(fmt = "File has too many classes (max allowed is {})", _0)]
+  TooManyClasses(usize),
+}
+
+#[derive(Display)]
+enum MaxClassesPerFileHint {
+  #[display(fmt = "Move this class to its own module")]
+  MoveToModule,
+}
+
+impl MaxClassesPerFile {
+  pub fn with_options(options: MaxClassesPerFileOptions) -> Box<Self> {
+    Box::new(MaxClassesPerFile { options })
+  }
+}
+
+impl LintRule for MaxClassesPerFile {
+  fn new() -> Box<Self> {
+    MaxClassesPerFile::with_options(MaxClassesPerFileOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "max": {
+          "type": "integer",
+          "minimum": 0,
+          "description": "Highest number of classes allowed in a file.",
+          "default": 1
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = MaxClassesPerFileVisitor::new(context, self.options.max);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a maximum number of classes in a file
+
+Keeping each class in its own module makes classes easy to find and keeps
+modules focused. This rule reports every class declared in a file after the
+first `max` ones (1 by default). Class expressions are counted as well.
+
+### Invalid:
+```typescript
+class Foo {}
+class Bar {}
+```
+
+### Valid:
+```typescript
+// foo.ts
+export class Foo {}
+```
+```typescript
+// bar.ts
+export class Bar {}
+```
+"#
+  }
+}
+
+struct MaxClassesPerFileVisitor<'c> {
+  context: &'c mut Context,
+  max: usize,
+  count: usize,
+}
+
+impl<'c> MaxClassesPerFileVisitor<'c> {
+  fn new(context: &'c mut Context, max: usize) -> Self {
+    Self {
+      context,
+      max,
+      count: 0,
+    }
+  }
+}
+
+impl<'c> Visit for MaxClassesPerFileVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_class(&mut self, class: &Class, _parent: &dyn Node) {
+    self.count += 1;
+    if self.count > self.max {
+      self.context.add_diagnostic_with_hint(
+        class.span,
+        CODE,
+        MaxClassesPerFileMessage::TooManyClasses(self.max),
+        MaxClassesPerFileHint::MoveToModule,
+      );
+    }
+    class.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn max_classes_per_file_valid() {
+    assert_lint_ok! {
+      MaxClassesPerFile,
+      "class Foo {}",
+      "export class Foo {}",
+      "export default class {}",
+      "const Foo = class {};",
+      "function Foo() {} function Bar() {}",
+    };
+
+    let rule =
+      MaxClassesPerFile::with_options(MaxClassesPerFileOptions { max: 2 });
+    assert_lint_ok_with_rule(rule, "class Foo {}\nclass Bar {}");
+  }
+
+  #[test]
+  fn max_classes_per_file_invalid() {
+    assert_lint_err! {
+      MaxClassesPerFile,
+      "class Foo {}\nclass Bar {}": [
+        {
+          line: 2,
+          col: 0,
+          message: MaxClassesPerFileMessage::TooManyClasses(1),
+          hint: MaxClassesPerFileHint::MoveToModule,
+        }
+      ],
+      "class Foo {} const Bar = class {};": [
+        {
+          col: 25,
+          message: MaxClassesPerFileMessage::TooManyClasses(1),
+          hint: MaxClassesPerFileHint::MoveToModule,
+        }
+      ],
+      "class Foo { bar() { return class {}; } }": [
+        {
+          col: 27,
+          message: MaxClassesPerFileMessage::TooManyClasses(1),
+          hint: MaxClassesPerFileHint::MoveToModule,
+        }
+      ],
+      "class Foo {}\nclass Bar {}\nclass Baz {}": [
+        {
+          line: 2,
+          col: 0,
+          message: MaxClassesPerFileMessage::TooManyClasses(1),
+          hint: MaxClassesPerFileHint::MoveToModule,
+        },
+        {
+          line: 3,
+          col: 0,
+          message: MaxClassesPerFileMessage::TooManyClasses(1),
+          hint: MaxClassesPerFileHint::MoveToModule,
+        }
+      ],
+    };
+
+    let rule =
+      MaxClassesPerFile::with_options(MaxClassesPerFileOptions { max: 2 });
+    assert_lint_err_on_line_with_rule(
+      rule,
+      "class Foo {}\nclass Bar {}\nexport class Baz {}",
+      3,
+      7,
+    );
+  }
+}
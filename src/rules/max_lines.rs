@@ -0,0 +1,224 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::{comment_spans, counted_lines};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_common::{BytePos, Span, Spanned, SyntaxContext};
+
+pub struct MaxLines {
+  options: MaxLinesOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct MaxLinesOptions {
+  /// Highest number of lines allowed in a file.
+  pub max: usize,
+  /// Don't count lines that are empty or contain only whitespace.
+  pub skip_blank_lines: bool,
+  /// Don't count lines that contain only comments.
+  pub skip_comments: bool,
+}
+
+impl Default for MaxLinesOptions {
+  fn default() -> Self {
+    Self {
+      max: 300,
+      skip_blank_lines: false,
+      skip_comments: false,
+    }
+  }
+}
+
+const CODE: &str = "max-lines";
+
+#[derive(Display)]
+enum MaxLinesMessage {
+  #[display(fmt = "File has {} lines (max allowed is {})", _0, _1)]
+  TooManyLines(usize, usize),
+}
+
+#[derive(Display)]
+enum MaxLinesHint {
+  #[display(fmt = "Split the file into smaller modules")]
+  Split,
+}
+
+impl MaxLines {
+  pub fn with_options(options: MaxLinesOptions) -> Box<Self> {
+    Box::new(MaxLines { options })
+  }
+}
+
+impl LintRule for MaxLines {
+  fn new() -> Box<Self> {
+    MaxLines::with_options(MaxLinesOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "max": {
+          "type": "integer",
+          "minimum": 0,
+          "description": "Highest number of lines allowed in a file.",
+          "default": 300
+        },
+        "skipBlankLines": {
+          "type": "boolean",
+          "description": "Don't count lines that are empty or contain only whitespace.",
+          "default": false
+        },
+        "skipComments": {
+          "type": "boolean",
+          "description": "Don't count lines that contain only comments.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    if program.span().is_dummy() {
+      return;
+    }
+    let file = context.source_map.lookup_char_pos(program.span().lo()).file;
+    let comment_spans = if self.options.skip_comments {
+      comment_spans(context)
+    } else {
+      vec![]
+    };
+    let lines = counted_lines(
+      &file.src,
+      file.start_pos,
+      &comment_spans,
+      self.options.skip_blank_lines,
+      self.options.skip_comments,
+    );
+    context.add_metric("lines", lines.len() as f64);
+    if lines.len() <= self.options.max {
+      return;
+    }
+
+    // Report the first line over the limit
+    let offset = lines[self.options.max];
+    let line = &file.src[offset..];
+    let line_len = line.find('\n').unwrap_or_else(|| line.len());
+    let lo = file.start_pos + BytePos(offset as u32);
+    let span =
+      Span::new(lo, lo + BytePos(line_len as u32), SyntaxContext::empty());
+    context.add_diagnostic_with_hint(
+      span,
+      CODE,
+      MaxLinesMessage::TooManyLines(lines.len(), self.options.max),
+      MaxLinesHint::Split,
+    );
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a maximum number of lines in a file
+
+Large files are hard to navigate and usually contain several unrelated parts,
+which are easier to maintain as separate modules. This rule reports files with
+more lines than the `max` option (300 by default), at the first line over the
+limit.
+
+Blank lines and lines containing only comments are counted, unless the
+`skipBlankLines` or `skipComments` options are set. The number of counted lines
+is recorded in the `lines` metric of the file.
+
+### Invalid:
+```typescript
+// With a maximum of 2 lines
+const a = 1;
+const b = 2;
+```
+
+### Valid:
+```typescript
+// With a maximum of 2 lines
+const a = 1, b = 2;
+```
+"#
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn max_lines(
+    max: usize,
+    skip_blank_lines: bool,
+    skip_comments: bool,
+  ) -> Box<MaxLines> {
+    MaxLines::with_options(MaxLinesOptions {
+      max,
+      skip_blank_lines,
+      skip_comments,
+    })
+  }
+
+  #[test]
+  fn max_lines_valid() {
+    assert_lint_ok! {
+      MaxLines,
+      "const a = 1;\nconst b = 2;\n",
+    };
+
+    assert_lint_ok_with_rule(max_lines(3, false, false), "a();\nb();\nc();");
+    assert_lint_ok_with_rule(
+      max_lines(3, true, false),
+      "a();\n\nb();\n  \nc();\n",
+    );
+    assert_lint_ok_with_rule(
+      max_lines(3, false, true),
+      "// a\na();\n/* b\n b */\nb(); // b\nc();",
+    );
+  }
+
+  #[test]
+  fn max_lines_invalid() {
+    assert_lint_err_on_line_with_rule(
+      max_lines(3, false, false),
+      "a();\nb();\nc();\nd();\ne();",
+      4,
+      0,
+    );
+    assert_lint_err_on_line_with_rule(
+      max_lines(3, false, false),
+      "a();\n\nb();\n  \nc();",
+      4,
+      0,
+    );
+    assert_lint_err_on_line_with_rule(
+      max_lines(3, true, false),
+      "a();\n\nb();\n  \nc();\n\nd();",
+      7,
+      0,
+    );
+    assert_lint_err_on_line_with_rule(
+      max_lines(2, false, true),
+      "// a\na();\n/* b\n b */\nb(); // b\nc();",
+      6,
+      0,
+    );
+  }
+}
@@ -0,0 +1,281 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::{comment_spans, counted_lines};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_common::Span;
+use swc_ecmascript::ast::{ArrowExpr, Constructor, Function};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct MaxLinesPerFunction {
+  options: MaxLinesPerFunctionOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct MaxLinesPerFunctionOptions {
+  /// Highest number of lines allowed in a function.
+  pub max: usize,
+  /// Don't count lines that are empty or contain only whitespace.
+  pub skip_blank_lines: bool,
+  /// Don't count lines that contain only comments.
+  pub skip_comments: bool,
+}
+
+impl Default for MaxLinesPerFunctionOptions {
+  fn default() -> Self {
+    Self {
+      max: 50,
+      skip_blank_lines: false,
+      skip_comments: false,
+    }
+  }
+}
+
+const CODE: &str = "max-lines-per-function";
+
+#[derive(Display)]
+enum MaxLinesPerFunctionMessage {
+  #[display(fmt = "Function has {} lines (max allowed is {})", _0, _1)]
+  TooManyLines(usize, usize),
+}
+
+#[derive(Display)]
+enum MaxLinesPerFunctionHint {
+  #[display(fmt = "Split the function into smaller ones")]
+  Split,
+}
+
+impl MaxLinesPerFunction {
+  pub fn with_options(options: MaxLinesPerFunctionOptions) -> Box<Self> {
+    Box::new(MaxLinesPerFunction { options })
+  }
+}
+
+impl LintRule for MaxLinesPerFunction {
+  fn new() -> Box<Self> {
+    MaxLinesPerFunction::with_options(MaxLinesPerFunctionOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "max": {
+          "type": "integer",
+          "minimum": 0,
+          "description": "Highest number of lines allowed in a function.",
+          "default": 50
+        },
+        "skipBlankLines": {
+          "type": "boolean",
+          "description": "Don't count lines that are empty or contain only whitespace.",
+          "default": false
+        },
+        "skipComments": {
+          "type": "boolean",
+          "description": "Don't count lines that contain only comments.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let comment_spans = if self.options.skip_comments {
+      comment_spans(context)
+    } else {
+      vec![]
+    };
+
+    let mut visitor =
+      MaxLinesPerFunctionVisitor::new(context, &self.options, comment_spans);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a maximum number of lines in a function
+
+Long functions tend to do too many things and are hard to follow. This rule
+reports functions, methods and arrow functions that span more lines than the
+`max` option (50 by default).
+
+Blank lines and lines containing only comments are counted, unless the
+`skipBlankLines` or `skipComments` options are set.
+
+### Invalid:
+```typescript
+// With a maximum of 3 lines
+function greet(name) {
+  const greeting = `Hello, ${name}`;
+  console.log(greeting);
+}
+```
+
+### Valid:
+```typescript
+// With a maximum of 3 lines
+function greet(name) {
+  console.log(`Hello, ${name}`);
+}
+```
+"#
+  }
+}
+
+struct MaxLinesPerFunctionVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o MaxLinesPerFunctionOptions,
+  /// Spans of all comments in the file, sorted by position. Empty unless
+  /// `skip_comments` is set.
+  comment_spans: Vec<Span>,
+}
+
+impl<'c, 'o> MaxLinesPerFunctionVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o MaxLinesPerFunctionOptions,
+    comment_spans: Vec<Span>,
+  ) -> Self {
+    Self {
+      context,
+      options,
+      comment_spans,
+    }
+  }
+
+  fn count_lines(&self, span: Span) -> usize {
+    match self.context.source_map.span_to_snippet(span) {
+      Ok(text) => counted_lines(
+        &text,
+        span.lo(),
+        &self.comment_spans,
+        self.options.skip_blank_lines,
+        self.options.skip_comments,
+      )
+      .len(),
+      Err(_) => 0,
+    }
+  }
+
+  fn check_function(&mut self, span: Span) {
+    let lines = self.count_lines(span);
+    if lines > self.options.max {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        MaxLinesPerFunctionMessage::TooManyLines(lines, self.options.max),
+        MaxLinesPerFunctionHint::Split,
+      );
+    }
+  }
+}
+
+impl<'c, 'o> Visit for MaxLinesPerFunctionVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
+    if function.body.is_some() {
+      self.check_function(function.span);
+    }
+    function.visit_children_with(self);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    self.check_function(arrow_expr.span);
+    arrow_expr.visit_children_with(self);
+  }
+
+  fn visit_constructor(
+    &mut self,
+    constructor: &Constructor,
+    _parent: &dyn Node,
+  ) {
+    if constructor.body.is_some() {
+      self.check_function(constructor.span);
+    }
+    constructor.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn max_lines(
+    max: usize,
+    skip_blank_lines: bool,
+    skip_comments: bool,
+  ) -> Box<MaxLinesPerFunction> {
+    MaxLinesPerFunction::with_options(MaxLinesPerFunctionOptions {
+      max,
+      skip_blank_lines,
+      skip_comments,
+    })
+  }
+
+  #[test]
+  fn max_lines_per_function_valid() {
+    assert_lint_ok! {
+      MaxLinesPerFunction,
+      "function f() { return 1; }",
+      "const f = () => {\n  foo();\n  bar();\n};",
+      "class A {\n  constructor() {\n    foo();\n  }\n}",
+    };
+
+    let src = "function f() {\n  foo();\n}";
+    assert_lint_ok_with_rule(max_lines(3, false, false), src);
+  }
+
+  #[test]
+  fn max_lines_per_function_invalid() {
+    let src = "function f() {\n  foo();\n  bar();\n}";
+    assert_lint_err_on_line_with_rule(max_lines(3, false, false), src, 1, 0);
+
+    let src = "class A {\n  m() {\n    foo();\n    bar();\n  }\n}";
+    assert_lint_err_on_line_with_rule(max_lines(3, false, false), src, 2, 2);
+
+    let src = "foo(() => {\n  bar();\n\n  baz();\n});";
+    assert_lint_err_on_line_with_rule(max_lines(3, false, false), src, 1, 4);
+  }
+
+  #[test]
+  fn max_lines_per_function_skip_blank_lines() {
+    let src = "function f() {\n  foo();\n\n   \n  bar();\n}";
+    assert_lint_err_on_line_with_rule(max_lines(4, false, false), src, 1, 0);
+    assert_lint_ok_with_rule(max_lines(4, true, false), src);
+  }
+
+  #[test]
+  fn max_lines_per_function_skip_comments() {
+    let src = r#"function f() {
+  // comment
+  /* multi
+     line */
+  foo(); // trailing
+  /* a */ bar();
+}"#;
+    assert_lint_err_on_line_with_rule(max_lines(4, false, false), src, 1, 0);
+    assert_lint_ok_with_rule(max_lines(4, false, true), src);
+    assert_lint_err_on_line_with_rule(max_lines(3, false, true), src, 1, 0);
+  }
+}
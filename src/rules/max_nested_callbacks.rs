@@ -0,0 +1,239 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_common::Span;
+use swc_ecmascript::ast::{CallExpr, Expr, ExprOrSpread, NewExpr};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct MaxNestedCallbacks {
+  options: MaxNestedCallbacksOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct MaxNestedCallbacksOptions {
+  /// Deepest nesting of callbacks allowed.
+  pub max: usize,
+}
+
+impl Default for MaxNestedCallbacksOptions {
+  fn default() -> Self {
+    Self { max: 3 }
+  }
+}
+
+const CODE: &str = "max-nested-callbacks";
+
+#[derive(Display)]
+enum MaxNestedCallbacksMessage {
+  #[display(
+    fmt = "Callbacks are nested {} levels deep (max allowed is {})",
+    _0,
+    _1
+  )]
+  TooDeep(usize, usize),
+}
+
+#[derive(Display)]
+enum MaxNestedCallbacksHint {
+  #[display(fmt = "Use named functions, promises or async/await instead")]
+  Flatten,
+}
+
+impl MaxNestedCallbacks {
+  pub fn with_options(options: MaxNestedCallbacksOptions) -> Box<Self> {
+    Box::new(MaxNestedCallbacks { options })
+  }
+}
+
+impl LintRule for MaxNestedCallbacks {
+  fn new() -> Box<Self> {
+    MaxNestedCallbacks::with_options(MaxNestedCallbacksOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "max": {
+          "type": "integer",
+          "minimum": 0,
+          "description": "Deepest nesting of callbacks allowed.",
+          "default": 3
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = MaxNestedCallbacksVisitor::new(context, self.options.max);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a maximum depth of nested callbacks
+
+Callbacks nested in callbacks drift further to the right with every level and
+are hard to follow. This rule reports function expressions and arrow functions
+passed as arguments when they are nested deeper than the `max` option (3 by
+default).
+
+### Invalid:
+```typescript
+readConfig((config) => {
+  connect(config, (db) => {
+    db.query("SELECT 1", (rows) => {
+      rows.forEach((row) => { // 4th level
+        console.log(row);
+      });
+    });
+  });
+});
+```
+
+### Valid:
+```typescript
+const config = await readConfig();
+const db = await connect(config);
+const rows = await db.query("SELECT 1");
+rows.forEach((row) => {
+  console.log(row);
+});
+```
+"#
+  }
+}
+
+struct MaxNestedCallbacksVisitor<'c> {
+  context: &'c mut Context,
+  max: usize,
+  depth: usize,
+}
+
+impl<'c> MaxNestedCallbacksVisitor<'c> {
+  fn new(context: &'c mut Context, max: usize) -> Self {
+    Self {
+      context,
+      max,
+      depth: 0,
+    }
+  }
+
+  fn check_args(&mut self, args: &[ExprOrSpread], parent: &dyn Node) {
+    for arg in args {
+      match callback_span(&arg.expr) {
+        Some(span) => {
+          self.depth += 1;
+          if self.depth > self.max {
+            self.context.add_diagnostic_with_hint(
+              span,
+              CODE,
+              MaxNestedCallbacksMessage::TooDeep(self.depth, self.max),
+              MaxNestedCallbacksHint::Flatten,
+            );
+          }
+          arg.visit_with(parent, self);
+          self.depth -= 1;
+        }
+        None => arg.visit_with(parent, self),
+      }
+    }
+  }
+}
+
+/// Returns the span of `expr` if it's a function expression or an arrow
+/// function.
+fn callback_span(expr: &Expr) -> Option<Span> {
+  match expr {
+    Expr::Fn(fn_expr) => Some(fn_expr.function.span),
+    Expr::Arrow(arrow_expr) => Some(arrow_expr.span),
+    Expr::Paren(paren_expr) => callback_span(&paren_expr.expr),
+    _ => None,
+  }
+}
+
+impl<'c> Visit for MaxNestedCallbacksVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    call_expr.callee.visit_with(call_expr, self);
+    self.check_args(&call_expr.args, call_expr);
+  }
+
+  fn visit_new_expr(&mut self, new_expr: &NewExpr, _parent: &dyn Node) {
+    new_expr.callee.visit_with(new_expr, self);
+    if let Some(args) = &new_expr.args {
+      self.check_args(args, new_expr);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn max_nested_callbacks_valid() {
+    assert_lint_ok! {
+      MaxNestedCallbacks,
+      "foo(() => { bar(() => { baz(() => {}); }); });",
+      "foo(function () { bar(function () { baz(function () {}); }); });",
+      "foo(() => { bar(() => {}); baz(() => {}); qux(() => {}); });",
+      "foo(() => { function f() { bar(() => { baz(() => {}); }); } });",
+      "foo(handleFoo); function handleFoo() { bar(handleBar); }",
+    };
+  }
+
+  #[test]
+  fn max_nested_callbacks_invalid() {
+    assert_lint_err! {
+      MaxNestedCallbacks,
+      "foo(() => { bar(() => { baz(() => { qux(() => {}); }); }); });": [
+        {
+          col: 40,
+          message: MaxNestedCallbacksMessage::TooDeep(4, 3),
+          hint: MaxNestedCallbacksHint::Flatten,
+        }
+      ],
+      "a(function () { b(function () { c(function () { new D(function () {}); }); }); });": [
+        {
+          col: 54,
+          message: MaxNestedCallbacksMessage::TooDeep(4, 3),
+          hint: MaxNestedCallbacksHint::Flatten,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn max_nested_callbacks_max() {
+    let rule =
+      MaxNestedCallbacks::with_options(MaxNestedCallbacksOptions { max: 1 });
+    assert_lint_err_on_line_with_rule(
+      rule,
+      "foo(() => {\n  bar((x) => x);\n});",
+      2,
+      6,
+    );
+  }
+}
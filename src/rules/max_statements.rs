@@ -0,0 +1,257 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_common::Span;
+use swc_ecmascript::ast::{ArrowExpr, BlockStmt, Constructor, Function};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct MaxStatements {
+  options: MaxStatementsOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct MaxStatementsOptions {
+  /// Highest number of statements allowed in a function.
+  pub max: usize,
+}
+
+impl Default for MaxStatementsOptions {
+  fn default() -> Self {
+    Self { max: 10 }
+  }
+}
+
+const CODE: &str = "max-statements";
+
+#[derive(Display)]
+enum MaxStatementsMessage {
+  #[display(fmt = "Function has {} statements (max allowed is {})", _0, _1)]
+  TooManyStatements(usize, usize),
+}
+
+#[derive(Display)]
+enum MaxStatementsHint {
+  #[display(fmt = "Split the function into smaller ones")]
+  Split,
+}
+
+impl MaxStatements {
+  pub fn with_options(options: MaxStatementsOptions) -> Box<Self> {
+    Box::new(MaxStatements { options })
+  }
+}
+
+impl LintRule for MaxStatements {
+  fn new() -> Box<Self> {
+    MaxStatements::with_options(MaxStatementsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "max": {
+          "type": "integer",
+          "minimum": 0,
+          "description": "Highest number of statements allowed in a function.",
+          "default": 10
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = MaxStatementsVisitor::new(context, self.options.max);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces a maximum number of statements in a function
+
+A function made of many statements usually does too many things at once. This
+rule reports functions, methods and arrow functions containing more statements
+than the `max` option (10 by default).
+
+Statements in nested blocks, such as the body of an `if` or a loop, count
+towards the enclosing function. Statements of nested functions are counted for
+those functions only.
+
+### Invalid:
+```typescript
+// With a maximum of 2 statements
+function init() {
+  const config = load();
+  validate(config);
+  start(config);
+}
+```
+
+### Valid:
+```typescript
+// With a maximum of 2 statements
+function init() {
+  const config = load();
+  start(validate(config));
+}
+```
+"#
+  }
+}
+
+struct MaxStatementsVisitor<'c> {
+  context: &'c mut Context,
+  max: usize,
+  /// Statement counts of the enclosing functions, innermost last.
+  counts: Vec<usize>,
+}
+
+impl<'c> MaxStatementsVisitor<'c> {
+  fn new(context: &'c mut Context, max: usize) -> Self {
+    Self {
+      context,
+      max,
+      counts: vec![],
+    }
+  }
+
+  fn check_function<F>(&mut self, span: Span, visit_body: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    self.counts.push(0);
+    visit_body(self);
+    let count = self.counts.pop().unwrap();
+    if count > self.max {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        MaxStatementsMessage::TooManyStatements(count, self.max),
+        MaxStatementsHint::Split,
+      );
+    }
+  }
+}
+
+impl<'c> Visit for MaxStatementsVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_block_stmt(&mut self, block_stmt: &BlockStmt, _parent: &dyn Node) {
+    if let Some(count) = self.counts.last_mut() {
+      *count += block_stmt.stmts.len();
+    }
+    block_stmt.visit_children_with(self);
+  }
+
+  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
+    self.check_function(function.span, |v| function.visit_children_with(v));
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    self.check_function(arrow_expr.span, |v| arrow_expr.visit_children_with(v));
+  }
+
+  fn visit_constructor(
+    &mut self,
+    constructor: &Constructor,
+    _parent: &dyn Node,
+  ) {
+    self
+      .check_function(constructor.span, |v| constructor.visit_children_with(v));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn max_statements(max: usize) -> Box<MaxStatements> {
+    MaxStatements::with_options(MaxStatementsOptions { max })
+  }
+
+  #[test]
+  fn max_statements_valid() {
+    assert_lint_ok! {
+      MaxStatements,
+      "function f() { a(); b(); c(); d(); e(); f(); g(); h(); i(); j(); }",
+      "a(); b(); c(); d(); e(); f(); g(); h(); i(); j(); k();",
+      "if (a) { a(); b(); c(); d(); e(); f(); g(); h(); i(); j(); k(); }",
+    };
+
+    assert_lint_ok_with_rule(max_statements(2), "function f() { a(); b(); }");
+    assert_lint_ok_with_rule(max_statements(2), "const f = () => a;");
+    assert_lint_ok_with_rule(
+      max_statements(2),
+      "function f() { a(); return () => { b(); c(); }; }",
+    );
+    assert_lint_ok_with_rule(
+      max_statements(2),
+      "function f() { a(); function g() { b(); c(); } }",
+    );
+  }
+
+  #[test]
+  fn max_statements_invalid() {
+    assert_lint_err! {
+      MaxStatements,
+      "function f() { a(); b(); c(); d(); e(); f(); g(); h(); i(); j(); k(); }": [
+        {
+          col: 0,
+          message: MaxStatementsMessage::TooManyStatements(11, 10),
+          hint: MaxStatementsHint::Split,
+        }
+      ],
+    };
+
+    assert_lint_err_on_line_with_rule(
+      max_statements(2),
+      "function f() { a(); b(); c(); }",
+      1,
+      0,
+    );
+    assert_lint_err_on_line_with_rule(
+      max_statements(2),
+      "function f() {\n  a();\n  if (a) {\n    b();\n  }\n}",
+      1,
+      0,
+    );
+    assert_lint_err_on_line_with_rule(
+      max_statements(2),
+      "foo(() => {\n  a();\n  b();\n  c();\n});",
+      1,
+      4,
+    );
+    assert_lint_err_on_line_with_rule(
+      max_statements(2),
+      "class A {\n  constructor() {\n    a();\n    b();\n    c();\n  }\n}",
+      2,
+      2,
+    );
+    assert_lint_err_on_line_with_rule(
+      max_statements(2),
+      "function f() {\n  a();\n  return function g() { b(); c(); d(); };\n}",
+      3,
+      9,
+    );
+  }
+}
@@ -0,0 +1,190 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::visit_stmt_lists;
+use derive_more::Display;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{ReturnStmt, Stmt};
+
+pub struct NoAdjacentIdenticalStatements;
+
+const CODE: &str = "no-adjacent-identical-statements";
+
+#[derive(Display)]
+enum NoAdjacentIdenticalStatementsMessage {
+  #[display(fmt = "This statement is identical to the previous one")]
+  Identical,
+}
+
+#[derive(Display)]
+enum NoAdjacentIdenticalStatementsHint {
+  #[display(fmt = "Remove the duplicate, or change it if it was copied")]
+  RemoveOrChange,
+}
+
+impl LintRule for NoAdjacentIdenticalStatements {
+  fn new() -> Box<Self> {
+    Box::new(NoAdjacentIdenticalStatements)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoAdjacentIdenticalStatementsVisitor::new(context);
+    visit_stmt_lists(program, |stmts| {
+      visitor.check_stmts(stmts.iter().copied())
+    });
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows two identical statements in a row
+
+A statement repeated right after itself is usually a copy-paste mistake, where
+the copy was meant to be edited. Statements are compared by their source text,
+ignoring differences in whitespace. Trivial statements like `break;` or
+`return;` aren't reported.
+
+### Invalid:
+```typescript
+foo();
+foo();
+
+x.a = 1;
+x.a = 1;
+```
+
+### Valid:
+```typescript
+foo();
+bar();
+
+x.a = 1;
+x.b = 1;
+```
+"#
+  }
+}
+
+struct NoAdjacentIdenticalStatementsVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoAdjacentIdenticalStatementsVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  /// Reports statements identical to the one before them. `None` stands for
+  /// an item that isn't a statement, like an import.
+  fn check_stmts<'s, I>(&mut self, stmts: I)
+  where
+    I: Iterator<Item = Option<&'s Stmt>>,
+  {
+    let mut previous: Option<String> = None;
+    for stmt in stmts {
+      let current = match stmt {
+        Some(stmt) if !is_trivial(stmt) => self.normalized_text(stmt.span()),
+        _ => None,
+      };
+      if let (Some(previous), Some(current), Some(stmt)) =
+        (&previous, &current, stmt)
+      {
+        if previous == current {
+          self.context.add_diagnostic_with_hint(
+            stmt.span(),
+            CODE,
+            NoAdjacentIdenticalStatementsMessage::Identical,
+            NoAdjacentIdenticalStatementsHint::RemoveOrChange,
+          );
+        }
+      }
+      previous = current;
+    }
+  }
+
+  /// Returns the source text at `span` with all whitespace collapsed to
+  /// single spaces.
+  fn normalized_text(&self, span: Span) -> Option<String> {
+    let snippet = self.context.source_map.span_to_snippet(span).ok()?;
+    Some(snippet.split_whitespace().collect::<Vec<_>>().join(" "))
+  }
+}
+
+/// Checks if `stmt` is too simple for a repetition to be a mistake.
+fn is_trivial(stmt: &Stmt) -> bool {
+  matches!(
+    stmt,
+    Stmt::Empty(_)
+      | Stmt::Break(_)
+      | Stmt::Continue(_)
+      | Stmt::Debugger(_)
+      | Stmt::Return(ReturnStmt { arg: None, .. })
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_adjacent_identical_statements_valid() {
+    assert_lint_ok! {
+      NoAdjacentIdenticalStatements,
+      "foo(); bar();",
+      "foo(); bar(); foo();",
+      "x.a = 1; x.b = 1;",
+      "while (a) { if (b) { break; } break; }",
+      "switch (a) { case 1: break; case 2: break; }",
+      "function f() { if (a) return; return; }",
+      "foo(); import 'a'; foo();",
+      "for (;;) { continue; continue; }",
+    };
+  }
+
+  #[test]
+  fn no_adjacent_identical_statements_invalid() {
+    assert_lint_err! {
+      NoAdjacentIdenticalStatements,
+      "foo(); foo();": [
+        {
+          col: 7,
+          message: NoAdjacentIdenticalStatementsMessage::Identical,
+          hint: NoAdjacentIdenticalStatementsHint::RemoveOrChange,
+        }
+      ],
+      "function f() {\n  x.a = 1;\n  x.a  =  1;\n}": [
+        {
+          line: 3,
+          col: 2,
+          message: NoAdjacentIdenticalStatementsMessage::Identical,
+          hint: NoAdjacentIdenticalStatementsHint::RemoveOrChange,
+        }
+      ],
+      "switch (a) { case 1: foo(); foo(); break; }": [
+        {
+          col: 28,
+          message: NoAdjacentIdenticalStatementsMessage::Identical,
+          hint: NoAdjacentIdenticalStatementsHint::RemoveOrChange,
+        }
+      ],
+      "foo(); foo(); foo();": [
+        {
+          col: 7,
+          message: NoAdjacentIdenticalStatementsMessage::Identical,
+          hint: NoAdjacentIdenticalStatementsHint::RemoveOrChange,
+        },
+        {
+          col: 14,
+          message: NoAdjacentIdenticalStatementsMessage::Identical,
+          hint: NoAdjacentIdenticalStatementsHint::RemoveOrChange,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,396 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{
+  DefaultDecl, ExportDefaultDecl, ExportDefaultExpr, Expr,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+pub struct NoAnonymousDefaultExport {
+  options: NoAnonymousDefaultExportOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoAnonymousDefaultExportOptions {
+  /// Allow `export default () => {}`.
+  pub allow_arrow_function: bool,
+  /// Allow `export default function () {}`.
+  pub allow_anonymous_function: bool,
+  /// Allow `export default class {}`.
+  pub allow_anonymous_class: bool,
+  /// Allow `export default {}`.
+  pub allow_object: bool,
+  /// Allow `export default []`.
+  pub allow_array: bool,
+}
+
+const CODE: &str = "no-anonymous-default-export";
+
+#[derive(Clone, Copy, Display)]
+enum AnonymousKind {
+  #[display(fmt = "arrow function")]
+  ArrowFunction,
+  #[display(fmt = "function")]
+  Function,
+  #[display(fmt = "class")]
+  Class,
+  #[display(fmt = "object")]
+  Object,
+  #[display(fmt = "array")]
+  Array,
+}
+
+#[derive(Display)]
+enum NoAnonymousDefaultExportMessage {
+  #[display(fmt = "Default export of an anonymous {}", _0)]
+  Anonymous(AnonymousKind),
+}
+
+#[derive(Display)]
+enum NoAnonymousDefaultExportHint {
+  #[display(fmt = "Give it a name")]
+  AddName,
+  #[display(fmt = "Assign it to a variable before exporting it")]
+  AssignToVariable,
+}
+
+impl NoAnonymousDefaultExport {
+  pub fn with_options(options: NoAnonymousDefaultExportOptions) -> Box<Self> {
+    Box::new(NoAnonymousDefaultExport { options })
+  }
+}
+
+impl LintRule for NoAnonymousDefaultExport {
+  fn new() -> Box<Self> {
+    NoAnonymousDefaultExport::with_options(
+      NoAnonymousDefaultExportOptions::default(),
+    )
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "allowArrowFunction": {
+          "type": "boolean",
+          "description": "Allow `export default () => {}`.",
+          "default": false
+        },
+        "allowAnonymousFunction": {
+          "type": "boolean",
+          "description": "Allow `export default function () {}`.",
+          "default": false
+        },
+        "allowAnonymousClass": {
+          "type": "boolean",
+          "description": "Allow `export default class {}`.",
+          "default": false
+        },
+        "allowObject": {
+          "type": "boolean",
+          "description": "Allow `export default {}`.",
+          "default": false
+        },
+        "allowArray": {
+          "type": "boolean",
+          "description": "Allow `export default []`.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor =
+      NoAnonymousDefaultExportVisitor::new(context, &self.options);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows anonymous values as default exports
+
+A named default export can be found by searching for its name, and editors can
+use the name to suggest imports. This rule reports default exports of anonymous
+functions, arrow functions, classes, object literals and array literals.
+
+Each kind can be allowed with the `allowAnonymousFunction`,
+`allowArrowFunction`, `allowAnonymousClass`, `allowObject` and `allowArray`
+options.
+
+### Invalid:
+```typescript
+export default () => {};
+export default function () {}
+export default class {}
+export default { foo: 1 };
+export default [1, 2];
+```
+
+### Valid:
+```typescript
+export default function foo() {}
+export default class Foo {}
+const config = { foo: 1 };
+export default config;
+```
+"#
+  }
+}
+
+struct NoAnonymousDefaultExportVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o NoAnonymousDefaultExportOptions,
+}
+
+impl<'c, 'o> NoAnonymousDefaultExportVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o NoAnonymousDefaultExportOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn is_allowed(&self, kind: AnonymousKind) -> bool {
+    match kind {
+      AnonymousKind::ArrowFunction => self.options.allow_arrow_function,
+      AnonymousKind::Function => self.options.allow_anonymous_function,
+      AnonymousKind::Class => self.options.allow_anonymous_class,
+      AnonymousKind::Object => self.options.allow_object,
+      AnonymousKind::Array => self.options.allow_array,
+    }
+  }
+
+  fn check(&mut self, span: Span, kind: AnonymousKind) {
+    if self.is_allowed(kind) {
+      return;
+    }
+    let hint = match kind {
+      AnonymousKind::Function | AnonymousKind::Class => {
+        NoAnonymousDefaultExportHint::AddName
+      }
+      _ => NoAnonymousDefaultExportHint::AssignToVariable,
+    };
+    self.context.add_diagnostic_with_hint(
+      span,
+      CODE,
+      NoAnonymousDefaultExportMessage::Anonymous(kind),
+      hint,
+    );
+  }
+}
+
+/// Returns the kind of `expr` if it's an anonymous value this rule reports.
+fn anonymous_kind(expr: &Expr) -> Option<AnonymousKind> {
+  match expr {
+    Expr::Arrow(_) => Some(AnonymousKind::ArrowFunction),
+    Expr::Fn(fn_expr) if fn_expr.ident.is_none() => {
+      Some(AnonymousKind::Function)
+    }
+    Expr::Class(class_expr) if class_expr.ident.is_none() => {
+      Some(AnonymousKind::Class)
+    }
+    Expr::Object(_) => Some(AnonymousKind::Object),
+    Expr::Array(_) => Some(AnonymousKind::Array),
+    Expr::Paren(paren_expr) => anonymous_kind(&paren_expr.expr),
+    _ => None,
+  }
+}
+
+impl<'c, 'o> Visit for NoAnonymousDefaultExportVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_export_default_decl(
+    &mut self,
+    export_default_decl: &ExportDefaultDecl,
+    _parent: &dyn Node,
+  ) {
+    let kind = match &export_default_decl.decl {
+      DefaultDecl::Fn(fn_expr) if fn_expr.ident.is_none() => {
+        AnonymousKind::Function
+      }
+      DefaultDecl::Class(class_expr) if class_expr.ident.is_none() => {
+        AnonymousKind::Class
+      }
+      _ => return,
+    };
+    // Reported at the exported value, like default expressions. The span of
+    // the declaration starts at `function` or `class`
+    self.check(export_default_decl.span, kind);
+  }
+
+  fn visit_export_default_expr(
+    &mut self,
+    export_default_expr: &ExportDefaultExpr,
+    _parent: &dyn Node,
+  ) {
+    if let Some(kind) = anonymous_kind(&export_default_expr.expr) {
+      self.check(export_default_expr.expr.span(), kind);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn no_anonymous_default_export_valid() {
+    assert_lint_ok! {
+      NoAnonymousDefaultExport,
+      "export default function foo() {}",
+      "export default class Foo {}",
+      "const foo = () => {}; export default foo;",
+      "export default foo();",
+      "export default 42;",
+      "export default (function foo() {});",
+      "export const foo = () => {};",
+      "export default interface Foo {}",
+    };
+
+    let sources = [
+      (
+        "export default () => {};",
+        NoAnonymousDefaultExportOptions {
+          allow_arrow_function: true,
+          ..Default::default()
+        },
+      ),
+      (
+        "export default function () {}",
+        NoAnonymousDefaultExportOptions {
+          allow_anonymous_function: true,
+          ..Default::default()
+        },
+      ),
+      (
+        "export default class {}",
+        NoAnonymousDefaultExportOptions {
+          allow_anonymous_class: true,
+          ..Default::default()
+        },
+      ),
+      (
+        "export default { foo: 1 };",
+        NoAnonymousDefaultExportOptions {
+          allow_object: true,
+          ..Default::default()
+        },
+      ),
+      (
+        "export default [1, 2];",
+        NoAnonymousDefaultExportOptions {
+          allow_array: true,
+          ..Default::default()
+        },
+      ),
+    ];
+    for (src, options) in sources.iter() {
+      assert_lint_ok_with_rule(
+        NoAnonymousDefaultExport::with_options(options.clone()),
+        src,
+      );
+    }
+  }
+
+  #[test]
+  fn no_anonymous_default_export_invalid() {
+    assert_lint_err! {
+      NoAnonymousDefaultExport,
+      "export default () => {};": [
+        {
+          col: 15,
+          message: NoAnonymousDefaultExportMessage::Anonymous(
+            AnonymousKind::ArrowFunction
+          ),
+          hint: NoAnonymousDefaultExportHint::AssignToVariable,
+        }
+      ],
+      "export default async () => {};": [
+        {
+          col: 15,
+          message: NoAnonymousDefaultExportMessage::Anonymous(
+            AnonymousKind::ArrowFunction
+          ),
+          hint: NoAnonymousDefaultExportHint::AssignToVariable,
+        }
+      ],
+      "export default function () {}": [
+        {
+          col: 15,
+          message: NoAnonymousDefaultExportMessage::Anonymous(
+            AnonymousKind::Function
+          ),
+          hint: NoAnonymousDefaultExportHint::AddName,
+        }
+      ],
+      "export default (function () {});": [
+        {
+          col: 15,
+          message: NoAnonymousDefaultExportMessage::Anonymous(
+            AnonymousKind::Function
+          ),
+          hint: NoAnonymousDefaultExportHint::AddName,
+        }
+      ],
+      "export default class {}": [
+        {
+          col: 15,
+          message: NoAnonymousDefaultExportMessage::Anonymous(
+            AnonymousKind::Class
+          ),
+          hint: NoAnonymousDefaultExportHint::AddName,
+        }
+      ],
+      "export default { foo: 1 };": [
+        {
+          col: 15,
+          message: NoAnonymousDefaultExportMessage::Anonymous(
+            AnonymousKind::Object
+          ),
+          hint: NoAnonymousDefaultExportHint::AssignToVariable,
+        }
+      ],
+      "export default [1, 2];": [
+        {
+          col: 15,
+          message: NoAnonymousDefaultExportMessage::Anonymous(
+            AnonymousKind::Array
+          ),
+          hint: NoAnonymousDefaultExportHint::AssignToVariable,
+        }
+      ],
+    };
+
+    let options = NoAnonymousDefaultExportOptions {
+      allow_arrow_function: true,
+      ..Default::default()
+    };
+    assert_lint_err_on_line_with_rule(
+      NoAnonymousDefaultExport::with_options(options),
+      "export default function () {}",
+      1,
+      15,
+    );
+  }
+}
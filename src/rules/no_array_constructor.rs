@@ -2,7 +2,10 @@
 use super::Context;
 use super::LintRule;
 use swc_common::Span;
-use swc_ecmascript::ast::{CallExpr, Expr, ExprOrSpread, ExprOrSuper, NewExpr};
+use swc_ecmascript::ast::{
+  CallExpr, Expr, ExprOrSpread, ExprOrSuper, Ident, Lit, NewExpr,
+};
+use swc_ecmascript::utils::ident::IdentLike;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::VisitAll;
@@ -12,7 +15,7 @@ pub struct NoArrayConstructor;
 
 const CODE: &str = "no-array-constructor";
 const MESSAGE: &str = "Array Constructor is not allowed";
-const HINT: &str = "Use array literal notation (e.g. []) or single argument specifying array size only (e.g. new Array(5)";
+const HINT: &str = "Use array literal notation (e.g. []) or single argument specifying array size only (e.g. new Array(5))";
 
 impl LintRule for NoArrayConstructor {
   fn new() -> Box<Self> {
@@ -49,6 +52,8 @@ the `Array` global may be redefined.
 
 The one exception to this rule is when creating a new array of fixed size, e.g.
 `new Array(6)`.  This is the conventional way to create arrays of fixed length.
+A single argument that can't be a number, like `new Array("a")`, is still
+reported since it creates an array containing that argument.
 
 ### Invalid:
 ```typescript
@@ -56,6 +61,8 @@ The one exception to this rule is when creating a new array of fixed size, e.g.
 const a = new Array(100, 1, 2, 3);
 
 const b = new Array(); // use [] instead
+
+const c = new Array("a"); // use ["a"] instead
 ```
     
 ### Valid:
@@ -77,8 +84,17 @@ impl<'c> NoArrayConstructorVisitor<'c> {
     Self { context }
   }
 
-  fn check_args(&mut self, args: Vec<ExprOrSpread>, span: Span) {
-    if args.len() != 1 {
+  /// Checks if `ident` is the global `Array`.
+  fn is_global_array(&self, ident: &Ident) -> bool {
+    ident.sym == *"Array" && self.context.scope().var(&ident.to_id()).is_none()
+  }
+
+  fn check_args(&mut self, args: &[ExprOrSpread], span: Span) {
+    let is_size = match args {
+      [arg] => arg.spread.is_none() && !is_non_numeric_literal(&arg.expr),
+      _ => false,
+    };
+    if !is_size {
       self
         .context
         .add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
@@ -86,23 +102,31 @@ impl<'c> NoArrayConstructorVisitor<'c> {
   }
 }
 
+/// Checks if `expr` is a literal that can't be an array size, so that
+/// `new Array(expr)` creates an array containing it.
+fn is_non_numeric_literal(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::Num(_)) => false,
+    Expr::Lit(_) | Expr::Tpl(_) | Expr::Array(_) | Expr::Object(_) => true,
+    Expr::Paren(paren_expr) => is_non_numeric_literal(&paren_expr.expr),
+    _ => false,
+  }
+}
+
 impl<'c> VisitAll for NoArrayConstructorVisitor<'c> {
   noop_visit_type!();
 
   fn visit_new_expr(&mut self, new_expr: &NewExpr, _parent: &dyn Node) {
     if let Expr::Ident(ident) = &*new_expr.callee {
-      let name = ident.sym.as_ref();
-      if name != "Array" {
+      if !self.is_global_array(ident) {
         return;
       }
       if new_expr.type_args.is_some() {
         return;
       }
       match &new_expr.args {
-        Some(args) => {
-          self.check_args(args.to_vec(), new_expr.span);
-        }
-        None => self.check_args(vec![], new_expr.span),
+        Some(args) => self.check_args(args, new_expr.span),
+        None => self.check_args(&[], new_expr.span),
       };
     }
   }
@@ -110,15 +134,14 @@ impl<'c> VisitAll for NoArrayConstructorVisitor<'c> {
   fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
     if let ExprOrSuper::Expr(expr) = &call_expr.callee {
       if let Expr::Ident(ident) = expr.as_ref() {
-        let name = ident.sym.as_ref();
-        if name != "Array" {
+        if !self.is_global_array(ident) {
           return;
         }
         if call_expr.type_args.is_some() {
           return;
         }
 
-        self.check_args((&*call_expr.args).to_vec(), call_expr.span);
+        self.check_args(&call_expr.args, call_expr.span);
       }
     }
   }
@@ -144,6 +167,11 @@ mod tests {
       "new Array<Foo>()",
       "Array<Foo>(1, 2, 3);",
       "Array<Foo>();",
+      "new Array(n + 1)",
+      "new Array((5))",
+      "new Array(-1)",
+      "const Array = Foo; new Array(1, 2);",
+      "function f(Array) { return Array(); }",
     };
   }
 
@@ -155,6 +183,13 @@ mod tests {
       "new Array()": [{ col: 0, message: MESSAGE, hint: HINT }],
       "new Array(x, y)": [{ col: 0, message: MESSAGE, hint: HINT }],
       "new Array(0, 1, 2)": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "new Array(1, 2)": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "Array(1, 2)": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "new Array('a')": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "new Array(`a`)": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "Array(true)": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "new Array({})": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "new Array(...items)": [{ col: 0, message: MESSAGE, hint: HINT }],
       // nested
       r#"
 const a = new class {
@@ -0,0 +1,185 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use swc_ecmascript::ast::{CallExpr, Expr, ExprOrSuper, Lit};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoAsyncForeach;
+
+const CODE: &str = "no-async-foreach";
+
+#[derive(Display)]
+enum NoAsyncForeachMessage {
+  #[display(fmt = "`forEach` does not wait for async callbacks")]
+  AsyncCallback,
+}
+
+#[derive(Display)]
+enum NoAsyncForeachHint {
+  #[display(
+    fmt = "Use a `for...of` loop with `await`, or `Promise.all` with `map`"
+  )]
+  UseForOf,
+}
+
+impl LintRule for NoAsyncForeach {
+  fn new() -> Box<Self> {
+    Box::new(NoAsyncForeach)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoAsyncForeachVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows async callbacks passed to `forEach`
+
+`forEach` ignores the promises returned by its callback. The iterations run
+concurrently instead of one after another, the code following `forEach` runs
+before they finish, and rejected promises are never handled. Use a `for...of`
+loop to run the iterations in order, or `Promise.all` with `map` to run them
+concurrently and wait for all of them.
+
+### Invalid:
+```typescript
+files.forEach(async (file) => {
+  await Deno.remove(file);
+});
+```
+
+### Valid:
+```typescript
+for (const file of files) {
+  await Deno.remove(file);
+}
+await Promise.all(files.map((file) => Deno.remove(file)));
+files.forEach((file) => console.log(file));
+```
+"#
+  }
+}
+
+struct NoAsyncForeachVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoAsyncForeachVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c> Visit for NoAsyncForeachVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    if is_for_each_call(call_expr)
+      && call_expr
+        .args
+        .first()
+        .map_or(false, |arg| is_async_function(&arg.expr))
+    {
+      self.context.add_diagnostic_with_hint(
+        call_expr.span,
+        CODE,
+        NoAsyncForeachMessage::AsyncCallback,
+        NoAsyncForeachHint::UseForOf,
+      );
+    }
+    call_expr.visit_children_with(self);
+  }
+}
+
+/// Checks if `call_expr` calls a method named `forEach`.
+fn is_for_each_call(call_expr: &CallExpr) -> bool {
+  let callee = match &call_expr.callee {
+    ExprOrSuper::Expr(callee) => callee,
+    ExprOrSuper::Super(_) => return false,
+  };
+  let member_expr = match &**callee {
+    Expr::Member(member_expr) => member_expr,
+    _ => return false,
+  };
+  match &*member_expr.prop {
+    Expr::Ident(ident) if !member_expr.computed => ident.sym == *"forEach",
+    Expr::Lit(Lit::Str(s)) if member_expr.computed => s.value == *"forEach",
+    _ => false,
+  }
+}
+
+fn is_async_function(expr: &Expr) -> bool {
+  match expr {
+    Expr::Arrow(arrow_expr) => arrow_expr.is_async,
+    Expr::Fn(fn_expr) => fn_expr.function.is_async,
+    Expr::Paren(paren_expr) => is_async_function(&paren_expr.expr),
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_async_foreach_valid() {
+    assert_lint_ok! {
+      NoAsyncForeach,
+      "arr.forEach((x) => {});",
+      "arr.forEach(function (x) {});",
+      "arr.forEach(callback);",
+      "arr.map(async (x) => {});",
+      "async function f() { for (const x of arr) { await g(x); } }",
+      "arr.forEach((x) => { const f = async () => {}; });",
+      "forEach(async (x) => {});",
+    };
+  }
+
+  #[test]
+  fn no_async_foreach_invalid() {
+    assert_lint_err! {
+      NoAsyncForeach,
+      "arr.forEach(async (x) => {});": [
+        {
+          col: 0,
+          message: NoAsyncForeachMessage::AsyncCallback,
+          hint: NoAsyncForeachHint::UseForOf,
+        }
+      ],
+      "arr.forEach(async function (x) {});": [
+        {
+          col: 0,
+          message: NoAsyncForeachMessage::AsyncCallback,
+          hint: NoAsyncForeachHint::UseForOf,
+        }
+      ],
+      "arr['forEach'](async x => {});": [
+        {
+          col: 0,
+          message: NoAsyncForeachMessage::AsyncCallback,
+          hint: NoAsyncForeachHint::UseForOf,
+        }
+      ],
+      "function f() {\n  map.forEach((async (v, k) => {}));\n}": [
+        {
+          line: 2,
+          col: 2,
+          message: NoAsyncForeachMessage::AsyncCallback,
+          hint: NoAsyncForeachHint::UseForOf,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,209 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{AwaitExpr, Expr, ExprOrSuper, MemberExpr};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoAwaitExpressionMember;
+
+const CODE: &str = "no-await-expression-member";
+
+#[derive(Display)]
+enum NoAwaitExpressionMemberMessage {
+  #[display(fmt = "`await` applies to the whole member expression")]
+  Ambiguous,
+}
+
+#[derive(Display)]
+enum NoAwaitExpressionMemberHint {
+  #[display(
+    fmt = "To await the object, write `(await {})` instead; otherwise wrap the member expression in parentheses",
+    _0
+  )]
+  AwaitObject(String),
+}
+
+impl LintRule for NoAwaitExpressionMember {
+  fn new() -> Box<Self> {
+    Box::new(NoAwaitExpressionMember)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoAwaitExpressionMemberVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows awaiting a member expression whose object is a call
+
+`await` binds looser than member access and calls, so
+`await fetch(url).json()` awaits the result of `.json()` on the promise
+returned by `fetch`, not the response. This rule reports `await` applied to a
+member expression, or a call of one, whose object is itself a call. Chains
+ending in `.then()`, `.catch()` or `.finally()` are not reported, since
+awaiting the whole chain is intended there.
+
+### Invalid:
+```typescript
+const data = await fetch(url).json();
+const name = await getUser().name;
+```
+
+### Valid:
+```typescript
+const data = await (await fetch(url)).json();
+const name = (await getUser()).name;
+const text = await file.text();
+const body = await fetch(url).then((res) => res.json());
+const result = await (getUser().name);
+```
+"#
+  }
+}
+
+struct NoAwaitExpressionMemberVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoAwaitExpressionMemberVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+/// Returns the member expression that `expr` reads or calls, e.g. `foo.bar`
+/// in both `foo.bar` and `foo.bar()`.
+fn get_member_expr(expr: &Expr) -> Option<&MemberExpr> {
+  match expr {
+    Expr::Member(member_expr) => Some(member_expr),
+    Expr::Call(call_expr) => match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => match &**callee {
+        Expr::Member(member_expr) => Some(member_expr),
+        _ => None,
+      },
+      ExprOrSuper::Super(_) => None,
+    },
+    _ => None,
+  }
+}
+
+/// Checks if `expr` is a call, or a member expression on one, e.g.
+/// `foo()` or `foo().bar`.
+fn contains_call(expr: &Expr) -> bool {
+  match expr {
+    Expr::Call(_) | Expr::New(_) => true,
+    Expr::Member(member_expr) => match &member_expr.obj {
+      ExprOrSuper::Expr(obj) => contains_call(obj),
+      ExprOrSuper::Super(_) => false,
+    },
+    _ => false,
+  }
+}
+
+fn is_promise_method(member_expr: &MemberExpr) -> bool {
+  match &*member_expr.prop {
+    Expr::Ident(ident) if !member_expr.computed => {
+      matches!(ident.sym.as_ref(), "then" | "catch" | "finally")
+    }
+    _ => false,
+  }
+}
+
+impl<'c> Visit for NoAwaitExpressionMemberVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_await_expr(&mut self, await_expr: &AwaitExpr, _parent: &dyn Node) {
+    if let Some(member_expr) = get_member_expr(&await_expr.arg) {
+      if let ExprOrSuper::Expr(obj) = &member_expr.obj {
+        if contains_call(obj) && !is_promise_method(member_expr) {
+          if let Ok(obj_text) =
+            self.context.source_map.span_to_snippet(obj.span())
+          {
+            self.context.add_diagnostic_with_hint(
+              await_expr.span,
+              CODE,
+              NoAwaitExpressionMemberMessage::Ambiguous,
+              NoAwaitExpressionMemberHint::AwaitObject(obj_text),
+            );
+          }
+        }
+      }
+    }
+    await_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_await_expression_member_valid() {
+    assert_lint_ok! {
+      NoAwaitExpressionMember,
+      "async function f() { await x.y(); }",
+      "async function f() { await foo.bar; }",
+      "async function f() { await foo(); }",
+      "async function f() { (await fetch(url)).json(); }",
+      "async function f() { await (await fetch(url)).json(); }",
+      "async function f() { await (foo().bar); }",
+      "async function f() { await fetch(url).then((res) => res.json()); }",
+      "async function f() { await foo().catch(handle); }",
+      "async function f() { await foo().finally(cleanup); }",
+    };
+  }
+
+  #[test]
+  fn no_await_expression_member_invalid() {
+    assert_lint_err! {
+      NoAwaitExpressionMember,
+      "async function f() { await fetch(url).json(); }": [
+        {
+          col: 21,
+          message: NoAwaitExpressionMemberMessage::Ambiguous,
+          hint: NoAwaitExpressionMemberHint::AwaitObject(
+            "fetch(url)".to_string()
+          ),
+        }
+      ],
+      "async function f() { return await getUser().name; }": [
+        {
+          col: 28,
+          message: NoAwaitExpressionMemberMessage::Ambiguous,
+          hint: NoAwaitExpressionMemberHint::AwaitObject(
+            "getUser()".to_string()
+          ),
+        }
+      ],
+      "async function f() { await x.y().z(); }": [
+        {
+          col: 21,
+          message: NoAwaitExpressionMemberMessage::Ambiguous,
+          hint: NoAwaitExpressionMemberHint::AwaitObject("x.y()".to_string()),
+        }
+      ],
+      "async function f() { await foo().bar.baz; }": [
+        {
+          col: 21,
+          message: NoAwaitExpressionMemberMessage::Ambiguous,
+          hint: NoAwaitExpressionMemberHint::AwaitObject(
+            "foo().bar".to_string()
+          ),
+        }
+      ],
+    };
+  }
+}
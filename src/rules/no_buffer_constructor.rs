@@ -0,0 +1,170 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use swc_common::Span;
+use swc_ecmascript::ast::{CallExpr, Expr, ExprOrSuper, Ident, NewExpr};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoBufferConstructor;
+
+const CODE: &str = "no-buffer-constructor";
+
+#[derive(Display)]
+enum NoBufferConstructorMessage {
+  #[display(fmt = "`Buffer()` and `new Buffer()` are deprecated")]
+  Deprecated,
+}
+
+#[derive(Display)]
+enum NoBufferConstructorHint {
+  #[display(
+    fmt = "Use `Buffer.from()`, `Buffer.alloc()` or `Buffer.allocUnsafe()` instead"
+  )]
+  UseFactoryMethods,
+}
+
+impl LintRule for NoBufferConstructor {
+  fn new() -> Box<Self> {
+    Box::new(NoBufferConstructor)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoBufferConstructorVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows calling the deprecated Node.js `Buffer` constructor
+
+The behavior of `Buffer()` depends on the type of its argument: a number
+allocates a buffer of that size, possibly with uninitialized memory, while other
+values are copied. Passing user input to it can expose memory or allocate
+unexpectedly large buffers. The factory methods state which behavior is wanted.
+
+### Invalid:
+```typescript
+new Buffer(5);
+new Buffer([1, 2, 3]);
+Buffer("abc");
+```
+
+### Valid:
+```typescript
+Buffer.alloc(5);
+Buffer.from([1, 2, 3]);
+Buffer.from("abc");
+```
+"#
+  }
+}
+
+struct NoBufferConstructorVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoBufferConstructorVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  fn check(&mut self, span: Span, callee: &Expr) {
+    if let Expr::Ident(ident) = callee {
+      if self.is_global_buffer(ident) {
+        self.context.add_diagnostic_with_hint(
+          span,
+          CODE,
+          NoBufferConstructorMessage::Deprecated,
+          NoBufferConstructorHint::UseFactoryMethods,
+        );
+      }
+    }
+  }
+
+  /// `Buffer` may be shadowed by a local binding
+  fn is_global_buffer(&self, ident: &Ident) -> bool {
+    ident.sym == *"Buffer" && self.context.scope().var(&ident.to_id()).is_none()
+  }
+}
+
+impl<'c> Visit for NoBufferConstructorVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_new_expr(&mut self, new_expr: &NewExpr, _parent: &dyn Node) {
+    self.check(new_expr.span, &new_expr.callee);
+    new_expr.visit_children_with(self);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+      self.check(call_expr.span, callee);
+    }
+    call_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_buffer_constructor_valid() {
+    assert_lint_ok! {
+      NoBufferConstructor,
+      "Buffer.from([]);",
+      "Buffer.alloc(10);",
+      "Buffer.allocUnsafe(10);",
+      "new Buffer.Foo();",
+      "new Uint8Array(10);",
+      "const Buffer = class {}; new Buffer(5);",
+      "function f(Buffer) { return Buffer(5); }",
+    };
+  }
+
+  #[test]
+  fn no_buffer_constructor_invalid() {
+    assert_lint_err! {
+      NoBufferConstructor,
+      "new Buffer(5);": [
+        {
+          col: 0,
+          message: NoBufferConstructorMessage::Deprecated,
+          hint: NoBufferConstructorHint::UseFactoryMethods,
+        }
+      ],
+      "Buffer([1, 2, 3]);": [
+        {
+          col: 0,
+          message: NoBufferConstructorMessage::Deprecated,
+          hint: NoBufferConstructorHint::UseFactoryMethods,
+        }
+      ],
+      "const b = new Buffer;": [
+        {
+          col: 10,
+          message: NoBufferConstructorMessage::Deprecated,
+          hint: NoBufferConstructorHint::UseFactoryMethods,
+        }
+      ],
+      "f(Buffer('abc'));": [
+        {
+          col: 2,
+          message: NoBufferConstructorMessage::Deprecated,
+          hint: NoBufferConstructorHint::UseFactoryMethods,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,258 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::LintFix;
+use derive_more::Display;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{BinaryOp, Expr, IfStmt, Stmt};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoCollapsibleIf;
+
+const CODE: &str = "no-collapsible-if";
+
+#[derive(Display)]
+enum NoCollapsibleIfMessage {
+  #[display(fmt = "`if` statement can be merged with the enclosing one")]
+  Collapsible,
+}
+
+#[derive(Display)]
+enum NoCollapsibleIfHint {
+  #[display(fmt = "Combine the conditions with `&&`")]
+  Merge,
+}
+
+#[derive(Display)]
+enum NoCollapsibleIfFix {
+  #[display(fmt = "Merge the conditions with `&&`")]
+  Merge,
+}
+
+impl LintRule for NoCollapsibleIf {
+  fn new() -> Box<Self> {
+    Box::new(NoCollapsibleIf)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoCollapsibleIfVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows nested `if` statements that can be merged
+
+An `if` statement whose only content is another `if` statement, where neither
+has an `else` branch, is equivalent to a single `if` with both conditions
+combined by `&&`. Merging them reduces nesting.
+
+### Invalid:
+```typescript
+if (a) {
+  if (b) {
+    foo();
+  }
+}
+```
+
+### Valid:
+```typescript
+if (a && b) {
+  foo();
+}
+```
+```typescript
+if (a) {
+  if (b) {
+    foo();
+  } else {
+    bar();
+  }
+}
+```
+"#
+  }
+}
+
+struct NoCollapsibleIfVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoCollapsibleIfVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  /// Builds `if (outer && inner) <inner body>`. Returns `None` if the outer
+  /// block contains anything besides the inner `if`, such as comments, which
+  /// would be lost.
+  fn merged_text(&self, outer: &IfStmt, inner: &IfStmt) -> Option<String> {
+    let source_map = &self.context.source_map;
+    let outer_cons = source_map.span_to_snippet(outer.cons.span()).ok()?;
+    let inner_if = source_map.span_to_snippet(inner.span).ok()?;
+    let rest = outer_cons.replacen(&inner_if, "", 1);
+    if rest
+      .chars()
+      .any(|c| !c.is_whitespace() && c != '{' && c != '}')
+    {
+      return None;
+    }
+
+    let outer_test = source_map.span_to_snippet(outer.test.span()).ok()?;
+    let inner_test = source_map.span_to_snippet(inner.test.span()).ok()?;
+    let inner_cons = source_map.span_to_snippet(inner.cons.span()).ok()?;
+    Some(format!(
+      "if ({} && {}) {}",
+      wrap_if_needed(&outer.test, outer_test),
+      wrap_if_needed(&inner.test, inner_test),
+      inner_cons
+    ))
+  }
+}
+
+/// Returns the `if` statement that is the only content of `stmt`, if any.
+fn only_if_stmt(stmt: &Stmt) -> Option<&IfStmt> {
+  match stmt {
+    Stmt::If(if_stmt) => Some(if_stmt),
+    Stmt::Block(block) => match block.stmts.as_slice() {
+      [Stmt::If(if_stmt)] => Some(if_stmt),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+/// Wraps the text of `expr` in parentheses if it binds less tightly than `&&`.
+fn wrap_if_needed(expr: &Expr, text: String) -> String {
+  let needs_parens = match expr {
+    Expr::Bin(bin_expr) => matches!(
+      bin_expr.op,
+      BinaryOp::LogicalOr | BinaryOp::NullishCoalescing
+    ),
+    Expr::Cond(_)
+    | Expr::Assign(_)
+    | Expr::Seq(_)
+    | Expr::Yield(_)
+    | Expr::Arrow(_) => true,
+    _ => false,
+  };
+  if needs_parens {
+    format!("({})", text)
+  } else {
+    text
+  }
+}
+
+impl<'c> Visit for NoCollapsibleIfVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_if_stmt(&mut self, if_stmt: &IfStmt, _parent: &dyn Node) {
+    if if_stmt.alt.is_none() {
+      if let Some(inner) = only_if_stmt(&if_stmt.cons) {
+        if inner.alt.is_none() {
+          match self.merged_text(if_stmt, inner) {
+            Some(new_text) => {
+              let fix = LintFix {
+                description: NoCollapsibleIfFix::Merge.to_string(),
+                changes: vec![self
+                  .context
+                  .create_fix_change(if_stmt.span, new_text)],
+                priority: 0,
+              };
+              self.context.add_diagnostic_with_fix(
+                inner.span,
+                CODE,
+                NoCollapsibleIfMessage::Collapsible,
+                fix,
+              );
+            }
+            None => self.context.add_diagnostic_with_hint(
+              inner.span,
+              CODE,
+              NoCollapsibleIfMessage::Collapsible,
+              NoCollapsibleIfHint::Merge,
+            ),
+          }
+        }
+      }
+    }
+    if_stmt.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn no_collapsible_if_valid() {
+    assert_lint_ok! {
+      NoCollapsibleIf,
+      "if (a && b) { foo(); }",
+      "if (a) { if (b) { foo(); } else { bar(); } }",
+      "if (a) { if (b) { foo(); } } else { bar(); }",
+      "if (a) { if (b) { foo(); } bar(); }",
+      "if (a) { bar(); if (b) { foo(); } }",
+      "if (a) { { if (b) { foo(); } } }",
+    };
+  }
+
+  #[test]
+  fn no_collapsible_if_invalid() {
+    assert_lint_err! {
+      NoCollapsibleIf,
+      "if (a) { if (b) { foo(); } }": [
+        {
+          col: 9,
+          message: NoCollapsibleIfMessage::Collapsible,
+        }
+      ],
+      "if (a) if (b) foo();": [
+        {
+          col: 7,
+          message: NoCollapsibleIfMessage::Collapsible,
+        }
+      ],
+      "if (a) {\n  // comment\n  if (b) { foo(); }\n}": [
+        {
+          line: 3,
+          col: 2,
+          message: NoCollapsibleIfMessage::Collapsible,
+          hint: NoCollapsibleIfHint::Merge,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_collapsible_if_fix() {
+    assert_lint_fix::<NoCollapsibleIf>(
+      "if (a) { if (b) { foo(); } }",
+      "if (a && b) { foo(); }",
+    );
+    assert_lint_fix::<NoCollapsibleIf>(
+      "if (a) if (b) foo();",
+      "if (a && b) foo();",
+    );
+    assert_lint_fix::<NoCollapsibleIf>(
+      "if (a || b) {\n  if (c ? d : e) {\n    foo();\n  }\n}",
+      "if ((a || b) && (c ? d : e)) {\n    foo();\n  }",
+    );
+    assert_lint_fix::<NoCollapsibleIf>(
+      "if (a) {\n  // comment\n  if (b) { foo(); }\n}",
+      "if (a) {\n  // comment\n  if (b) { foo(); }\n}",
+    );
+  }
+}
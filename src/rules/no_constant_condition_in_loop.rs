@@ -0,0 +1,400 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::control_flow::ControlFlow;
+use derive_more::Display;
+use swc_atoms::JsWord;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  ArrowExpr, BreakStmt, Class, ContinueStmt, DoWhileStmt, Expr, ForInStmt,
+  ForOfStmt, ForStmt, Function, LabeledStmt, ReturnStmt, Stmt, SwitchStmt,
+  ThrowStmt, WhileStmt, YieldExpr,
+};
+use swc_ecmascript::utils::{ExprExt, Value};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoConstantConditionInLoop;
+
+const CODE: &str = "no-constant-condition-in-loop";
+
+#[derive(Display)]
+enum NoConstantConditionInLoopMessage {
+  #[display(fmt = "This loop's condition is always true and it never exits")]
+  Infinite,
+}
+
+#[derive(Display)]
+enum NoConstantConditionInLoopHint {
+  #[display(fmt = "Add a condition that ends the loop, or `break` out of it")]
+  AddExit,
+}
+
+impl LintRule for NoConstantConditionInLoop {
+  fn new() -> Box<Self> {
+    Box::new(NoConstantConditionInLoop)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoConstantConditionInLoopVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows loops with a constant condition that never exit
+
+This is a narrower version of `no-constant-condition` for catching infinite
+loops. Loops like `while (true)` are reported only if there's no reachable
+`break`, `return` or `throw` leaving them. Loops that `yield` are allowed, since
+a generator can stop resuming them.
+
+### Invalid:
+```typescript
+while (true) {
+  poll();
+}
+for (;;) {}
+```
+
+### Valid:
+```typescript
+while (true) {
+  if (poll()) break;
+}
+function* counter() {
+  for (let i = 0; ; i++) yield i;
+}
+```
+"#
+  }
+}
+
+struct NoConstantConditionInLoopVisitor<'c> {
+  context: &'c mut Context,
+  /// Labels of the loop about to be visited, e.g. `outer` in
+  /// `outer: for (;;) {}`.
+  pending_labels: Vec<JsWord>,
+}
+
+impl<'c> NoConstantConditionInLoopVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self {
+      context,
+      pending_labels: Vec::new(),
+    }
+  }
+
+  /// Reports the loop if its condition is constant and `body` never leaves it.
+  fn check_loop(&mut self, span: Span, test: Option<&Expr>, body: &Stmt) {
+    let labels = std::mem::take(&mut self.pending_labels);
+    let is_constant = match test {
+      None => true,
+      Some(test) => matches!(test.as_bool(), (_, Value::Known(true))),
+    };
+    if !is_constant {
+      return;
+    }
+
+    let mut finder = ExitFinder::new(self.context.control_flow(), &labels);
+    body.visit_with(body, &mut finder);
+    if !finder.found {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        NoConstantConditionInLoopMessage::Infinite,
+        NoConstantConditionInLoopHint::AddExit,
+      );
+    }
+  }
+}
+
+impl<'c> Visit for NoConstantConditionInLoopVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_labeled_stmt(
+    &mut self,
+    labeled_stmt: &LabeledStmt,
+    _parent: &dyn Node,
+  ) {
+    match &*labeled_stmt.body {
+      Stmt::For(_)
+      | Stmt::ForIn(_)
+      | Stmt::ForOf(_)
+      | Stmt::While(_)
+      | Stmt::DoWhile(_)
+      | Stmt::Labeled(_) => {
+        self.pending_labels.push(labeled_stmt.label.sym.clone())
+      }
+      _ => self.pending_labels.clear(),
+    }
+    labeled_stmt.visit_children_with(self);
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _parent: &dyn Node) {
+    self.check_loop(for_stmt.span, for_stmt.test.as_deref(), &for_stmt.body);
+    for_stmt.visit_children_with(self);
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _parent: &dyn Node) {
+    self.pending_labels.clear();
+    for_in_stmt.visit_children_with(self);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _parent: &dyn Node) {
+    self.pending_labels.clear();
+    for_of_stmt.visit_children_with(self);
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _parent: &dyn Node) {
+    self.check_loop(while_stmt.span, Some(&while_stmt.test), &while_stmt.body);
+    while_stmt.visit_children_with(self);
+  }
+
+  fn visit_do_while_stmt(
+    &mut self,
+    do_while_stmt: &DoWhileStmt,
+    _parent: &dyn Node,
+  ) {
+    self.check_loop(
+      do_while_stmt.span,
+      Some(&do_while_stmt.test),
+      &do_while_stmt.body,
+    );
+    do_while_stmt.visit_children_with(self);
+  }
+}
+
+/// Looks for a reachable statement leaving the loop labeled with `labels`, or
+/// a `yield` suspending it.
+struct ExitFinder<'a> {
+  control_flow: &'a ControlFlow,
+  labels: &'a [JsWord],
+  /// Labels declared inside the loop body.
+  inner_labels: Vec<JsWord>,
+  /// Number of loops and `switch` statements an unlabeled `break` would
+  /// leave before the loop.
+  breakable_depth: usize,
+  found: bool,
+}
+
+impl<'a> ExitFinder<'a> {
+  fn new(control_flow: &'a ControlFlow, labels: &'a [JsWord]) -> Self {
+    Self {
+      control_flow,
+      labels,
+      inner_labels: Vec::new(),
+      breakable_depth: 0,
+      found: false,
+    }
+  }
+
+  fn is_reachable(&self, span: Span) -> bool {
+    self
+      .control_flow
+      .meta(span.lo)
+      .map_or(true, |meta| !meta.unreachable)
+  }
+
+  /// Checks if a `break` or `continue` with `label` jumps out of the loop.
+  fn leaves_loop(&self, label: Option<&JsWord>) -> bool {
+    match label {
+      Some(label) => {
+        self.labels.contains(label) || !self.inner_labels.contains(label)
+      }
+      None => self.breakable_depth == 0,
+    }
+  }
+
+  fn visit_breakable<N: VisitWith<Self>>(&mut self, node: &N) {
+    self.breakable_depth += 1;
+    node.visit_children_with(self);
+    self.breakable_depth -= 1;
+  }
+}
+
+impl<'a> Visit for ExitFinder<'a> {
+  noop_visit_type!();
+
+  fn visit_break_stmt(&mut self, break_stmt: &BreakStmt, _parent: &dyn Node) {
+    let label = break_stmt.label.as_ref().map(|label| &label.sym);
+    if self.leaves_loop(label) && self.is_reachable(break_stmt.span) {
+      self.found = true;
+    }
+  }
+
+  fn visit_continue_stmt(
+    &mut self,
+    continue_stmt: &ContinueStmt,
+    _parent: &dyn Node,
+  ) {
+    // Continuing an outer loop leaves this one
+    if let Some(label) = &continue_stmt.label {
+      if !self.labels.contains(&label.sym)
+        && !self.inner_labels.contains(&label.sym)
+        && self.is_reachable(continue_stmt.span)
+      {
+        self.found = true;
+      }
+    }
+  }
+
+  fn visit_return_stmt(
+    &mut self,
+    return_stmt: &ReturnStmt,
+    _parent: &dyn Node,
+  ) {
+    self.found |= self.is_reachable(return_stmt.span);
+  }
+
+  fn visit_throw_stmt(&mut self, throw_stmt: &ThrowStmt, _parent: &dyn Node) {
+    self.found |= self.is_reachable(throw_stmt.span);
+  }
+
+  fn visit_yield_expr(&mut self, _yield_expr: &YieldExpr, _parent: &dyn Node) {
+    self.found = true;
+  }
+
+  fn visit_labeled_stmt(
+    &mut self,
+    labeled_stmt: &LabeledStmt,
+    _parent: &dyn Node,
+  ) {
+    self.inner_labels.push(labeled_stmt.label.sym.clone());
+    labeled_stmt.body.visit_with(labeled_stmt, self);
+    self.inner_labels.pop();
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _parent: &dyn Node) {
+    self.visit_breakable(for_stmt);
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _parent: &dyn Node) {
+    self.visit_breakable(for_in_stmt);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _parent: &dyn Node) {
+    self.visit_breakable(for_of_stmt);
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _parent: &dyn Node) {
+    self.visit_breakable(while_stmt);
+  }
+
+  fn visit_do_while_stmt(
+    &mut self,
+    do_while_stmt: &DoWhileStmt,
+    _parent: &dyn Node,
+  ) {
+    self.visit_breakable(do_while_stmt);
+  }
+
+  fn visit_switch_stmt(
+    &mut self,
+    switch_stmt: &SwitchStmt,
+    _parent: &dyn Node,
+  ) {
+    self.visit_breakable(switch_stmt);
+  }
+
+  // Jumps in nested functions don't leave the loop
+  fn visit_function(&mut self, _function: &Function, _parent: &dyn Node) {}
+
+  fn visit_arrow_expr(&mut self, _arrow_expr: &ArrowExpr, _parent: &dyn Node) {}
+
+  fn visit_class(&mut self, _class: &Class, _parent: &dyn Node) {}
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_constant_condition_in_loop_valid() {
+    assert_lint_ok! {
+      NoConstantConditionInLoop,
+      "while (true) { if (x) break; }",
+      "while (a) {}",
+      "for (let i = 0; i < 10; i++) {}",
+      "do { foo(); } while (false);",
+      "function f() { for (;;) { if (done()) return; } }",
+      "while (1) { if (failed()) throw new Error(); }",
+      "outer: while (true) { while (true) { break outer; } }",
+      "outer: for (const x of xs) { while (true) { continue outer; } }",
+      "function* g() { let i = 0; while (true) yield i++; }",
+      "for (const x of xs) {}",
+    };
+  }
+
+  #[test]
+  fn no_constant_condition_in_loop_invalid() {
+    assert_lint_err! {
+      NoConstantConditionInLoop,
+      "while (true) {}": [
+        {
+          col: 0,
+          message: NoConstantConditionInLoopMessage::Infinite,
+          hint: NoConstantConditionInLoopHint::AddExit,
+        }
+      ],
+      "for (;;) { poll(); }": [
+        {
+          col: 0,
+          message: NoConstantConditionInLoopMessage::Infinite,
+          hint: NoConstantConditionInLoopHint::AddExit,
+        }
+      ],
+      "do { foo(); } while (1);": [
+        {
+          col: 0,
+          message: NoConstantConditionInLoopMessage::Infinite,
+          hint: NoConstantConditionInLoopHint::AddExit,
+        }
+      ],
+      "while (true) { switch (x) { case 1: break; } }": [
+        {
+          col: 0,
+          message: NoConstantConditionInLoopMessage::Infinite,
+          hint: NoConstantConditionInLoopHint::AddExit,
+        }
+      ],
+      "while (true) { for (const x of xs) { break; } }": [
+        {
+          col: 0,
+          message: NoConstantConditionInLoopMessage::Infinite,
+          hint: NoConstantConditionInLoopHint::AddExit,
+        }
+      ],
+      "while (true) { const f = () => { return; }; }": [
+        {
+          col: 0,
+          message: NoConstantConditionInLoopMessage::Infinite,
+          hint: NoConstantConditionInLoopHint::AddExit,
+        }
+      ],
+      "while (true) { foo(); continue; break; }": [
+        {
+          col: 0,
+          message: NoConstantConditionInLoopMessage::Infinite,
+          hint: NoConstantConditionInLoopHint::AddExit,
+        }
+      ],
+      "a: while (true) { b: { break b; } }": [
+        {
+          col: 3,
+          message: NoConstantConditionInLoopMessage::Infinite,
+          hint: NoConstantConditionInLoopHint::AddExit,
+        }
+      ],
+    };
+  }
+}
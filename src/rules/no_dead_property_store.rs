@@ -0,0 +1,407 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::swc_util::visit_stmt_lists;
+use derive_more::Display;
+use std::collections::HashSet;
+use swc_atoms::JsWord;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{
+  ArrowExpr, AssignExpr, AssignOp, AwaitExpr, CallExpr, Class, Expr,
+  ExprOrSuper, Function, Ident, Lit, MemberExpr, NewExpr, Pat, PatOrExpr, Stmt,
+  TaggedTpl, ThisExpr, UnaryExpr, UnaryOp, UpdateExpr, YieldExpr,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoDeadPropertyStore;
+
+const CODE: &str = "no-dead-property-store";
+
+#[derive(Display)]
+enum NoDeadPropertyStoreMessage {
+  #[display(
+    fmt = "The value assigned to `{}` is overwritten before it's read",
+    _0
+  )]
+  Overwritten(String),
+}
+
+#[derive(Display)]
+enum NoDeadPropertyStoreHint {
+  #[display(
+    fmt = "Remove this assignment, or use the value before replacing it"
+  )]
+  Remove,
+}
+
+impl LintRule for NoDeadPropertyStore {
+  fn new() -> Box<Self> {
+    Box::new(NoDeadPropertyStore)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoDeadPropertyStoreVisitor::new(context);
+    visit_stmt_lists(program, |stmts| {
+      visitor.check_stmts(stmts.iter().copied())
+    });
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows assigning a property that's assigned again before it's read
+
+When a property is assigned twice in a row, the first value is never used. This
+is often a mistake, like assigning the wrong property.
+
+The rule only looks at consecutive assignment statements. Any other statement,
+or an assigned value that calls a function or mentions the object, may read the
+property, so it ends the search. Setters with side effects can't be told apart
+from plain properties, so they may be reported.
+
+### Invalid:
+```typescript
+obj.name = "foo";
+obj.name = "bar";
+
+this.x = 1;
+this.y = 2;
+this.x = 3;
+```
+
+### Valid:
+```typescript
+obj.name = "foo";
+log(obj.name);
+obj.name = "bar";
+
+obj.count = 1;
+obj.count = obj.count + 1;
+```
+"#
+  }
+}
+
+/// Object a property path starts from.
+#[derive(Clone, PartialEq, Eq)]
+enum Root {
+  This,
+  Ident(Id),
+}
+
+/// A statement assigning a property, like `this.a.b = 1`.
+struct Store {
+  root: Root,
+  /// Property names following the root, e.g. `a` and `b`.
+  path: Vec<JsWord>,
+  /// Text of the assigned property for messages.
+  name: String,
+  span: Span,
+}
+
+struct NoDeadPropertyStoreVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoDeadPropertyStoreVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  /// Reports the stores among `stmts` that are overwritten by a later store
+  /// with only other stores, which can't read them, in between. `None`
+  /// stands for an item that isn't a statement, like an import.
+  fn check_stmts<'s, I>(&mut self, stmts: I)
+  where
+    I: Iterator<Item = Option<&'s Stmt>>,
+  {
+    let mut pending: Vec<Store> = Vec::new();
+    for stmt in stmts {
+      let (assign_expr, store) = match stmt.and_then(as_property_store) {
+        Some(found) => found,
+        None => {
+          pending.clear();
+          continue;
+        }
+      };
+
+      // The assigned value is evaluated before the store happens
+      let effects = scan_value(&assign_expr.right);
+      if effects.may_call {
+        pending.clear();
+      } else {
+        pending.retain(|pending| !effects.mentions(&pending.root));
+      }
+
+      if let Some(index) = pending
+        .iter()
+        .position(|p| p.root == store.root && p.path == store.path)
+      {
+        let overwritten = pending.remove(index);
+        self.context.add_diagnostic_with_hint(
+          overwritten.span,
+          CODE,
+          NoDeadPropertyStoreMessage::Overwritten(overwritten.name),
+          NoDeadPropertyStoreHint::Remove,
+        );
+      }
+
+      // Replacing `a.b` changes the object `a.b.c` refers to
+      pending.retain(|pending| {
+        pending.root != store.root || !pending.path.starts_with(&store.path)
+      });
+      pending.push(store);
+    }
+  }
+}
+
+/// Returns the assignment if `stmt` is a plain assignment to a property with
+/// a static path, like `obj.a.b = value;`.
+fn as_property_store(stmt: &Stmt) -> Option<(&AssignExpr, Store)> {
+  let assign_expr = match stmt {
+    Stmt::Expr(expr_stmt) => match &*expr_stmt.expr {
+      Expr::Assign(assign_expr) if assign_expr.op == AssignOp::Assign => {
+        assign_expr
+      }
+      _ => return None,
+    },
+    _ => return None,
+  };
+  let left = match &assign_expr.left {
+    PatOrExpr::Expr(expr) => &**expr,
+    PatOrExpr::Pat(pat) => match &**pat {
+      Pat::Expr(expr) => &**expr,
+      _ => return None,
+    },
+  };
+  let member_expr = match left {
+    Expr::Member(member_expr) => member_expr,
+    _ => return None,
+  };
+
+  let (root, path) = property_path(member_expr)?;
+  let root_name = match &root {
+    Root::This => "this".to_string(),
+    Root::Ident(id) => id.0.to_string(),
+  };
+  let name = path
+    .iter()
+    .fold(root_name, |name, prop| format!("{}.{}", name, prop));
+  Some((
+    assign_expr,
+    Store {
+      root,
+      path,
+      name,
+      span: stmt.span(),
+    },
+  ))
+}
+
+/// Returns the root and property names of `member_expr` if they're all
+/// static, like in `this.a["b"]`.
+fn property_path(member_expr: &MemberExpr) -> Option<(Root, Vec<JsWord>)> {
+  let prop = match (&*member_expr.prop, member_expr.computed) {
+    (Expr::Ident(ident), false) => ident.sym.clone(),
+    (Expr::Lit(Lit::Str(s)), true) => s.value.clone(),
+    _ => return None,
+  };
+  let obj = match &member_expr.obj {
+    ExprOrSuper::Expr(obj) => &**obj,
+    ExprOrSuper::Super(_) => return None,
+  };
+  let (root, mut path) = match obj {
+    Expr::This(_) => (Root::This, Vec::new()),
+    Expr::Ident(ident) => (Root::Ident(ident.to_id()), Vec::new()),
+    Expr::Member(obj) => property_path(obj)?,
+    _ => return None,
+  };
+  path.push(prop);
+  Some((root, path))
+}
+
+/// What evaluating an assigned value may do.
+#[derive(Default)]
+struct Effects {
+  /// Whether it may run code that reads any property, like a function call.
+  may_call: bool,
+  mentions_this: bool,
+  mentioned_ids: HashSet<Id>,
+}
+
+impl Effects {
+  fn mentions(&self, root: &Root) -> bool {
+    match root {
+      Root::This => self.mentions_this,
+      Root::Ident(id) => self.mentioned_ids.contains(id),
+    }
+  }
+}
+
+fn scan_value(expr: &Expr) -> Effects {
+  let mut scanner = EffectScanner {
+    effects: Effects::default(),
+  };
+  expr.visit_with(expr, &mut scanner);
+  scanner.effects
+}
+
+struct EffectScanner {
+  effects: Effects,
+}
+
+impl Visit for EffectScanner {
+  noop_visit_type!();
+
+  fn visit_ident(&mut self, ident: &Ident, _parent: &dyn Node) {
+    self.effects.mentioned_ids.insert(ident.to_id());
+  }
+
+  fn visit_this_expr(&mut self, _this_expr: &ThisExpr, _parent: &dyn Node) {
+    self.effects.mentions_this = true;
+  }
+
+  fn visit_member_expr(
+    &mut self,
+    member_expr: &MemberExpr,
+    _parent: &dyn Node,
+  ) {
+    member_expr.obj.visit_with(member_expr, self);
+    // `foo.obj` doesn't mention `obj`
+    if member_expr.computed {
+      member_expr.prop.visit_with(member_expr, self);
+    }
+  }
+
+  fn visit_call_expr(&mut self, _call_expr: &CallExpr, _parent: &dyn Node) {
+    self.effects.may_call = true;
+  }
+
+  fn visit_new_expr(&mut self, _new_expr: &NewExpr, _parent: &dyn Node) {
+    self.effects.may_call = true;
+  }
+
+  fn visit_tagged_tpl(&mut self, _tagged_tpl: &TaggedTpl, _parent: &dyn Node) {
+    self.effects.may_call = true;
+  }
+
+  fn visit_await_expr(&mut self, _await_expr: &AwaitExpr, _parent: &dyn Node) {
+    self.effects.may_call = true;
+  }
+
+  fn visit_yield_expr(&mut self, _yield_expr: &YieldExpr, _parent: &dyn Node) {
+    self.effects.may_call = true;
+  }
+
+  fn visit_assign_expr(
+    &mut self,
+    _assign_expr: &AssignExpr,
+    _parent: &dyn Node,
+  ) {
+    self.effects.may_call = true;
+  }
+
+  fn visit_update_expr(
+    &mut self,
+    _update_expr: &UpdateExpr,
+    _parent: &dyn Node,
+  ) {
+    self.effects.may_call = true;
+  }
+
+  fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr, _parent: &dyn Node) {
+    if unary_expr.op == UnaryOp::Delete {
+      self.effects.may_call = true;
+    }
+    unary_expr.visit_children_with(self);
+  }
+
+  // Computed keys and static blocks of classes run right away
+  fn visit_class(&mut self, _class: &Class, _parent: &dyn Node) {
+    self.effects.may_call = true;
+  }
+
+  // Function bodies only run when called, which counts as a call already
+  fn visit_function(&mut self, _function: &Function, _parent: &dyn Node) {}
+
+  fn visit_arrow_expr(&mut self, _arrow_expr: &ArrowExpr, _parent: &dyn Node) {}
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_dead_property_store_valid() {
+    assert_lint_ok! {
+      NoDeadPropertyStore,
+      "obj.p = 1; log(obj.p); obj.p = 2;",
+      "obj.p = 1; obj.p = obj.p + 1;",
+      "obj.p = 1; obj.p = f();",
+      "obj.p = 1; other.q = obj; obj.p = 2;",
+      "obj.p = 1; obj.q = 2;",
+      "a.p = 1; b.p = 2;",
+      "obj.a.p = 1; obj.a = other; obj.a.p = 2;",
+      "obj[key] = 1; obj[key] = 2;",
+      "obj.p = 1; if (a) { obj.p = 2; }",
+      "this.p = 1; this.p = this.q;",
+      "obj.p += 1; obj.p = 2;",
+      "obj.p = 1; x = 1; obj.p = 2;",
+      "obj.p = 1; other.p = new Foo(); obj.p = 2;",
+    };
+  }
+
+  #[test]
+  fn no_dead_property_store_invalid() {
+    assert_lint_err! {
+      NoDeadPropertyStore,
+      "obj.p = 1; obj.p = 2;": [
+        {
+          col: 0,
+          message: NoDeadPropertyStoreMessage::Overwritten("obj.p".to_string()),
+          hint: NoDeadPropertyStoreHint::Remove,
+        }
+      ],
+      "this.x = 1; this.y = 2; this.x = 3;": [
+        {
+          col: 0,
+          message: NoDeadPropertyStoreMessage::Overwritten("this.x".to_string()),
+          hint: NoDeadPropertyStoreHint::Remove,
+        }
+      ],
+      "function f(a) {\n  a.b.c = f();\n  a['b'].c = () => a.b.c;\n}": [
+        {
+          line: 2,
+          col: 2,
+          message: NoDeadPropertyStoreMessage::Overwritten("a.b.c".to_string()),
+          hint: NoDeadPropertyStoreHint::Remove,
+        }
+      ],
+      "obj.p = 1; other.q = x.y; obj.p = 2;": [
+        {
+          col: 0,
+          message: NoDeadPropertyStoreMessage::Overwritten("obj.p".to_string()),
+          hint: NoDeadPropertyStoreHint::Remove,
+        }
+      ],
+      "switch (a) { case 1: obj.p = 1; obj.p = 2; }": [
+        {
+          col: 21,
+          message: NoDeadPropertyStoreMessage::Overwritten("obj.p".to_string()),
+          hint: NoDeadPropertyStoreHint::Remove,
+        }
+      ],
+    };
+  }
+}
@@ -8,6 +8,7 @@ use swc_ecmascript::ast::UnaryOp;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
 
 pub struct NoDeleteVar;
 
@@ -89,11 +90,7 @@ impl<'c> Visit for NoDeleteVarVisitor<'c> {
   noop_visit_type!();
 
   fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr, _parent: &dyn Node) {
-    if unary_expr.op != UnaryOp::Delete {
-      return;
-    }
-
-    if let Expr::Ident(_) = *unary_expr.arg {
+    if unary_expr.op == UnaryOp::Delete && is_variable(&unary_expr.arg) {
       self.context.add_diagnostic_with_hint(
         unary_expr.span,
         CODE,
@@ -101,6 +98,15 @@ impl<'c> Visit for NoDeleteVarVisitor<'c> {
         NoDeleteVarHint::Remove,
       );
     }
+    unary_expr.visit_children_with(self);
+  }
+}
+
+fn is_variable(expr: &Expr) -> bool {
+  match expr {
+    Expr::Ident(_) => true,
+    Expr::Paren(paren_expr) => is_variable(&paren_expr.expr),
+    _ => false,
   }
 }
 
@@ -108,6 +114,18 @@ impl<'c> Visit for NoDeleteVarVisitor<'c> {
 mod tests {
   use super::*;
 
+  #[test]
+  fn no_delete_var_valid() {
+    assert_lint_ok! {
+      NoDeleteVar,
+      "const obj = { x: 1 }; delete obj.x;",
+      "const arr = [1, 2]; delete arr[0];",
+      r#"const obj = { x: 1 }; delete obj["x"];"#,
+      "const obj = { x: { y: 1 } }; delete obj.x.y;",
+      "typeof x; void x; !x;",
+    };
+  }
+
   #[test]
   fn no_delete_var_invalid() {
     assert_lint_err! {
@@ -119,6 +137,34 @@ mod tests {
           hint: NoDeleteVarHint::Remove,
         }
       ],
+      "let x = 1; delete x;": [
+        {
+          col: 11,
+          message: NoDeleteVarMessage::Unexpected,
+          hint: NoDeleteVarHint::Remove,
+        }
+      ],
+      "let x = 1; delete (x);": [
+        {
+          col: 11,
+          message: NoDeleteVarMessage::Unexpected,
+          hint: NoDeleteVarHint::Remove,
+        }
+      ],
+      "let x = 1; !(delete x);": [
+        {
+          col: 13,
+          message: NoDeleteVarMessage::Unexpected,
+          hint: NoDeleteVarHint::Remove,
+        }
+      ],
+      "let x = 1; void (() => { delete x; });": [
+        {
+          col: 25,
+          message: NoDeleteVarMessage::Unexpected,
+          hint: NoDeleteVarHint::Remove,
+        }
+      ],
     }
   }
 }
@@ -38,7 +38,7 @@ impl LintRule for NoDupeArgs {
   }
 
   fn code(&self) -> &'static str {
-    "no-dupe-args"
+    CODE
   }
 
   fn lint_program(
@@ -149,6 +149,7 @@ mod tests {
   fn no_dupe_args_valid() {
     assert_lint_ok! {
       NoDupeArgs,
+      "function f(a, b) {}",
       "function a(a, b, c) {}",
       "let a = function (a, b, c) {}",
       "const a = (a, b, c) => {}",
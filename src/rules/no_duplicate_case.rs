@@ -143,6 +143,8 @@ mod tests {
       "var a = [1,2]; switch(a.toString()){case ([1,2]).toString():break; case ([1]).toString():break; default:break;}",
       "switch(a) { case a: break; } switch(a) { case a: break; }",
       "switch(a) { case toString: break; }",
+      "switch (a) { case foo.bar: break; case foo[bar]: break; }",
+      "switch (a) { case 'a': break; case 'a ': break; case `a`: break; }",
 
       // nested
       r#"
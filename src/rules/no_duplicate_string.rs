@@ -0,0 +1,278 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use swc_common::Span;
+use swc_ecmascript::ast::{Expr, ExprStmt, Lit};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoDuplicateString {
+  options: NoDuplicateStringOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoDuplicateStringOptions {
+  /// Number of occurrences from which a string literal is reported.
+  pub threshold: usize,
+  /// Strings shorter than this are ignored.
+  pub min_length: usize,
+}
+
+impl Default for NoDuplicateStringOptions {
+  fn default() -> Self {
+    Self {
+      threshold: 3,
+      min_length: 10,
+    }
+  }
+}
+
+const CODE: &str = "no-duplicate-string";
+
+#[derive(Display)]
+enum NoDuplicateStringMessage {
+  #[display(fmt = "This string literal is repeated {} times", _0)]
+  Duplicated(usize),
+}
+
+#[derive(Display)]
+enum NoDuplicateStringHint {
+  #[display(fmt = "Extract the string into a constant and use it instead")]
+  ExtractConstant,
+}
+
+impl NoDuplicateString {
+  pub fn with_options(options: NoDuplicateStringOptions) -> Box<Self> {
+    Box::new(NoDuplicateString { options })
+  }
+}
+
+impl LintRule for NoDuplicateString {
+  fn new() -> Box<Self> {
+    NoDuplicateString::with_options(NoDuplicateStringOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "threshold": {
+          "type": "integer",
+          "minimum": 0,
+          "description": "Number of occurrences from which a string literal is reported.",
+          "default": 3
+        },
+        "minLength": {
+          "type": "integer",
+          "minimum": 0,
+          "description": "Strings shorter than this are ignored.",
+          "default": 10
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoDuplicateStringVisitor::new(self.options.min_length);
+    visitor.visit_program(program, program);
+
+    let mut duplicates: Vec<Vec<Span>> = visitor
+      .occurrences
+      .into_iter()
+      .map(|(_, spans)| spans)
+      .filter(|spans| spans.len() >= self.options.threshold)
+      .collect();
+    duplicates.sort_by_key(|spans| spans[0].lo());
+
+    for spans in duplicates {
+      for span in spans.iter().skip(1) {
+        context.add_diagnostic_with_hint(
+          *span,
+          CODE,
+          NoDuplicateStringMessage::Duplicated(spans.len()),
+          NoDuplicateStringHint::ExtractConstant,
+        );
+      }
+    }
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows repeating the same string literal many times
+
+A string literal that appears many times in a module is easy to mistype in one
+of the places, and hard to change consistently. Extracting it into a constant
+avoids both problems.
+
+Strings are reported when they appear at least `threshold` times (3 by
+default), and are at least `minLength` characters long (10 by default).
+Object keys, import and export specifiers, and directives such as
+`"use strict"` are not counted.
+
+### Invalid:
+```typescript
+log("Something went wrong");
+log("Something went wrong");
+log("Something went wrong");
+```
+
+### Valid:
+```typescript
+const ERROR_MESSAGE = "Something went wrong";
+log(ERROR_MESSAGE);
+log(ERROR_MESSAGE);
+log(ERROR_MESSAGE);
+```
+"#
+  }
+}
+
+struct NoDuplicateStringVisitor {
+  min_length: usize,
+  /// Spans of string literals, keyed by their value.
+  occurrences: HashMap<String, Vec<Span>>,
+}
+
+impl NoDuplicateStringVisitor {
+  fn new(min_length: usize) -> Self {
+    Self {
+      min_length,
+      occurrences: HashMap::new(),
+    }
+  }
+}
+
+impl Visit for NoDuplicateStringVisitor {
+  noop_visit_type!();
+
+  fn visit_expr_stmt(&mut self, expr_stmt: &ExprStmt, _parent: &dyn Node) {
+    // Directives such as `"use strict";`
+    if let Expr::Lit(Lit::Str(_)) = &*expr_stmt.expr {
+      return;
+    }
+    expr_stmt.visit_children_with(self);
+  }
+
+  fn visit_expr(&mut self, expr: &Expr, _parent: &dyn Node) {
+    // Only string literals in expression position are counted, which
+    // excludes object keys and module specifiers.
+    if let Expr::Lit(Lit::Str(s)) = expr {
+      if s.value.chars().count() >= self.min_length {
+        self
+          .occurrences
+          .entry(s.value.to_string())
+          .or_default()
+          .push(s.span);
+      }
+    }
+    expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn no_duplicate_string_valid() {
+    assert_lint_ok! {
+      NoDuplicateString,
+      r#"log("Something went wrong"); log("Something went wrong");"#,
+      r#"log("short"); log("short"); log("short");"#,
+      r#"const a = { "a long object key": 1, "a long object key": 2, "a long object key": 3 };"#,
+      r#"
+import a from "./some_module.ts";
+import b from "./some_module.ts";
+export { c } from "./some_module.ts";
+      "#,
+      r#"
+function a() { "use strict-ish mode"; }
+function b() { "use strict-ish mode"; }
+function c() { "use strict-ish mode"; }
+      "#,
+      r#"
+type A = "a long type literal";
+type B = "a long type literal";
+type C = "a long type literal";
+      "#,
+    };
+  }
+
+  #[test]
+  fn no_duplicate_string_invalid() {
+    assert_lint_err! {
+      NoDuplicateString,
+      r#"
+log("Something went wrong");
+log("Something went wrong");
+log("Something went wrong");
+      "#: [
+        {
+          line: 3,
+          col: 4,
+          message: NoDuplicateStringMessage::Duplicated(3),
+          hint: NoDuplicateStringHint::ExtractConstant,
+        },
+        {
+          line: 4,
+          col: 4,
+          message: NoDuplicateStringMessage::Duplicated(3),
+          hint: NoDuplicateStringHint::ExtractConstant,
+        }
+      ],
+      r#"
+const a = { key: "Something went wrong" };
+if (b === 'Something went wrong') {
+  throw new Error(`${"Something went wrong"}`);
+}
+      "#: [
+        {
+          line: 3,
+          col: 10,
+          message: NoDuplicateStringMessage::Duplicated(3),
+          hint: NoDuplicateStringHint::ExtractConstant,
+        },
+        {
+          line: 4,
+          col: 21,
+          message: NoDuplicateStringMessage::Duplicated(3),
+          hint: NoDuplicateStringHint::ExtractConstant,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_duplicate_string_options() {
+    let options = NoDuplicateStringOptions {
+      threshold: 2,
+      min_length: 1,
+    };
+    assert_lint_err_on_line_with_rule(
+      NoDuplicateString::with_options(options),
+      r#"log("a"); log("a");"#,
+      1,
+      14,
+    );
+  }
+}
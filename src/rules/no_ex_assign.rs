@@ -4,7 +4,9 @@ use super::LintRule;
 use crate::{scopes::BindingKind, swc_util::find_lhs_ids};
 use derive_more::Display;
 
-use swc_ecmascript::ast::{AssignExpr, Program};
+use swc_common::Span;
+use swc_ecmascript::ast::{AssignExpr, Expr, Program, UpdateExpr};
+use swc_ecmascript::utils::{ident::IdentLike, Id};
 use swc_ecmascript::visit::{noop_visit_type, Node, VisitAll, VisitAllWith};
 
 pub struct NoExAssign;
@@ -42,11 +44,11 @@ impl LintRule for NoExAssign {
   }
 
   fn docs(&self) -> &'static str {
-    r#"Disallows the reassignment of exception parameters 
+    r#"Disallows the reassignment of exception parameters
 
 There is generally no good reason to reassign an exception parameter.  Once
 reassigned the code from that point on has no reference to the error anymore.
-    
+
 ### Invalid:
 ```typescript
 try {
@@ -77,27 +79,36 @@ impl<'c> NoExAssignVisitor<'c> {
   fn new(context: &'c mut Context) -> Self {
     Self { context }
   }
+
+  fn check(&mut self, span: Span, id: &Id) {
+    let is_catch_param = self
+      .context
+      .scope()
+      .var(id)
+      .map_or(false, |var| matches!(var.kind(), BindingKind::CatchClause));
+    if is_catch_param {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        NoExAssignMessage::NotAllowed,
+        NoExAssignHint::UseDifferent,
+      );
+    }
+  }
 }
 
 impl<'c> VisitAll for NoExAssignVisitor<'c> {
   noop_visit_type!();
 
   fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, _: &dyn Node) {
-    let ids = find_lhs_ids(&assign_expr.left);
-
-    for id in ids {
-      let var = self.context.scope.var(&id);
-
-      if let Some(var) = var {
-        if let BindingKind::CatchClause = var.kind() {
-          self.context.add_diagnostic_with_hint(
-            assign_expr.span,
-            CODE,
-            NoExAssignMessage::NotAllowed,
-            NoExAssignHint::UseDifferent,
-          );
-        }
-      }
+    for id in find_lhs_ids(&assign_expr.left) {
+      self.check(assign_expr.span, &id);
+    }
+  }
+
+  fn visit_update_expr(&mut self, update_expr: &UpdateExpr, _: &dyn Node) {
+    if let Expr::Ident(ident) = &*update_expr.arg {
+      self.check(update_expr.span, &ident.to_id());
     }
   }
 }
@@ -115,6 +126,9 @@ try {} catch { e = 1; }
 try {} catch (ex) { something = 1; }
 try {} catch (ex) { return 1; }
 function foo() { try { } catch (e) { return false; } }
+try {} catch (e) { const f = e; }
+try {} catch (e) { f(e.message); }
+try {} catch (e) { let x = 0; x++; }
       "#,
     };
   }
@@ -158,6 +172,20 @@ function foo() { try { } catch (e) { return false; } }
           hint: NoExAssignHint::UseDifferent,
         },
       ],
+      r#"try {} catch (e) { e++; }"#: [
+        {
+          col: 19,
+          message: NoExAssignMessage::NotAllowed,
+          hint: NoExAssignHint::UseDifferent,
+        },
+      ],
+      r#"try {} catch (e) { --e; }"#: [
+        {
+          col: 19,
+          message: NoExAssignMessage::NotAllowed,
+          hint: NoExAssignHint::UseDifferent,
+        },
+      ],
 
       // nested
       r#"a = () => { try {} catch (e) { e = 1; } };"#: [
@@ -5,8 +5,9 @@ use derive_more::Display;
 use swc_common::Span;
 use swc_ecmascript::ast::{
   CallExpr, CondExpr, DoWhileStmt, Expr, ExprOrSpread, ExprOrSuper, ForStmt,
-  Ident, IfStmt, NewExpr, ParenExpr, UnaryExpr, UnaryOp, WhileStmt,
+  IfStmt, NewExpr, ParenExpr, UnaryExpr, UnaryOp, WhileStmt,
 };
+use swc_ecmascript::utils::ident::IdentLike;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::{VisitAll, VisitAllWith};
@@ -88,6 +89,24 @@ impl<'c> NoExtraBooleanCastVisitor<'c> {
     Self { context }
   }
 
+  fn is_boolean_call_callee(&self, callee: &ExprOrSuper) -> bool {
+    match callee {
+      ExprOrSuper::Expr(ref callee) => self.is_boolean_callee(&**callee),
+      _ => false,
+    }
+  }
+
+  /// Checks if `expr` refers to the global `Boolean`, i.e. isn't shadowed.
+  fn is_boolean_callee(&self, expr: &Expr) -> bool {
+    match expr {
+      Expr::Ident(ident) => {
+        ident.sym == *"Boolean"
+          && self.context.scope().var(&ident.to_id()).is_none()
+      }
+      _ => false,
+    }
+  }
+
   fn unexpected_call(&mut self, span: Span) {
     self.context.add_diagnostic_with_hint(
       span,
@@ -111,7 +130,7 @@ impl<'c> NoExtraBooleanCastVisitor<'c> {
       Expr::Call(CallExpr {
         ref callee, span, ..
       }) => {
-        if expr_or_super_callee_is_boolean(callee) {
+        if self.is_boolean_call_callee(callee) {
           self.unexpected_call(*span);
         }
       }
@@ -143,7 +162,7 @@ impl<'c> NoExtraBooleanCastVisitor<'c> {
   ) {
     match internal_expr {
       Expr::Call(CallExpr { ref callee, .. }) => {
-        if expr_or_super_callee_is_boolean(callee) {
+        if self.is_boolean_call_callee(callee) {
           self.unexpected_call(unary_expr_span);
         }
       }
@@ -188,7 +207,7 @@ impl<'c> VisitAll for NoExtraBooleanCastVisitor<'c> {
   }
 
   fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
-    if expr_or_super_callee_is_boolean(&call_expr.callee) {
+    if self.is_boolean_call_callee(&call_expr.callee) {
       if let Some(ExprOrSpread { expr, .. }) = call_expr.args.get(0) {
         self.check_condition(&*expr);
       }
@@ -196,7 +215,7 @@ impl<'c> VisitAll for NoExtraBooleanCastVisitor<'c> {
   }
 
   fn visit_new_expr(&mut self, new_expr: &NewExpr, _: &dyn Node) {
-    if expr_callee_is_boolean(&new_expr.callee) {
+    if self.is_boolean_callee(&new_expr.callee) {
       if let Some(ExprOrSpread { expr, .. }) =
         new_expr.args.as_ref().and_then(|a| a.get(0))
       {
@@ -210,17 +229,6 @@ impl<'c> VisitAll for NoExtraBooleanCastVisitor<'c> {
   }
 }
 
-fn expr_or_super_callee_is_boolean(expr_or_super: &ExprOrSuper) -> bool {
-  match expr_or_super {
-    ExprOrSuper::Expr(ref callee) => expr_callee_is_boolean(&**callee),
-    _ => false,
-  }
-}
-
-fn expr_callee_is_boolean(expr: &Expr) -> bool {
-  matches!(expr, Expr::Ident(Ident { ref sym, .. }) if sym == "Boolean")
-}
-
 /// Checks if `expr` has `n` continuous bang operators at the beginning, ignoring parentheses.
 fn has_n_bang(expr: &Expr, n: usize) -> bool {
   if n == 0 {
@@ -258,6 +266,8 @@ mod tests {
       "for(Boolean(foo);;) {}",
       "for(;; Boolean(foo)) {}",
       "if (new Boolean(foo)) {}",
+      "function f(Boolean) { if (Boolean(foo)) {} }",
+      "const Boolean = (x) => x; while (!Boolean(foo)) {}",
       "if (!!foo || bar) {}",
     };
   }
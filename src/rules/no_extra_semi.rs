@@ -1,6 +1,7 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
+use crate::diagnostic::LintFix;
 use derive_more::Display;
 use swc_ecmascript::ast::{
   DoWhileStmt, EmptyStmt, ForInStmt, ForOfStmt, ForStmt, IfStmt, LabeledStmt,
@@ -24,6 +25,12 @@ enum NoExtraSemiHint {
   Remove,
 }
 
+#[derive(Display)]
+enum NoExtraSemiFix {
+  #[display(fmt = "Remove the semicolon")]
+  Remove,
+}
+
 impl LintRule for NoExtraSemi {
   fn new() -> Box<Self> {
     Box::new(NoExtraSemi)
@@ -83,11 +90,19 @@ impl<'c> Visit for NoExtraSemiVisitor<'c> {
   noop_visit_type!();
 
   fn visit_empty_stmt(&mut self, empty_stmt: &EmptyStmt, _parent: &dyn Node) {
-    self.context.add_diagnostic_with_hint(
+    // Empty statements required by the syntax, like the body of `for(;;);`,
+    // aren't visited, so removing the reported ones is always safe
+    let fix = LintFix {
+      description: NoExtraSemiFix::Remove.to_string(),
+      changes: vec![self.context.create_fix_change(empty_stmt.span, "")],
+      priority: 0,
+    };
+    self.context.add_diagnostic_with_hint_and_fix(
       empty_stmt.span,
       CODE,
       NoExtraSemiMessage::Unnecessary,
       NoExtraSemiHint::Remove,
+      fix,
     );
   }
 
@@ -179,6 +194,7 @@ impl<'c> Visit for NoExtraSemiVisitor<'c> {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::test_util::*;
 
   #[test]
   fn no_extra_semi_valid() {
@@ -204,6 +220,21 @@ mod tests {
     };
   }
 
+  #[test]
+  fn no_extra_semi_fix() {
+    assert_lint_fix::<NoExtraSemi>("var x = 1;;", "var x = 1;");
+    assert_lint_fix::<NoExtraSemi>("function foo(){};", "function foo(){}");
+    assert_lint_fix::<NoExtraSemi>("for(;;);;", "for(;;);");
+    assert_lint_fix::<NoExtraSemi>(
+      "if(true){;} else {;}",
+      "if(true){} else {}",
+    );
+    assert_lint_fix::<NoExtraSemi>(
+      "class A {; a() {}; b() {}; }",
+      "class A { a() {} b() {} }",
+    );
+  }
+
   #[test]
   fn no_extra_semi_invalid() {
     assert_lint_err! {
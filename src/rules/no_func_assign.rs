@@ -103,7 +103,7 @@ impl<'c> VisitAll for NoFuncAssignVisitor<'c> {
     let ids = find_lhs_ids(&assign_expr.left);
 
     for id in ids {
-      let var = self.context.scope.var(&id);
+      let var = self.context.scope().var(&id);
       if let Some(var) = var {
         if let BindingKind::Function = var.kind() {
           self.context.add_diagnostic_with_hint(
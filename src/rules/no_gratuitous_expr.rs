@@ -0,0 +1,309 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use std::collections::HashSet;
+use std::mem;
+use swc_ecmascript::ast::{
+  ArrowExpr, AssignExpr, BinExpr, BinaryOp, Expr, ForInStmt, ForOfStmt,
+  Function, IfStmt, PatOrExpr, UnaryOp, UpdateExpr, VarDeclOrPat,
+};
+use swc_ecmascript::utils::find_ids;
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoGratuitousExpr;
+
+const CODE: &str = "no-gratuitous-expr";
+
+#[derive(Display)]
+enum NoGratuitousExprMessage {
+  #[display(
+    fmt = "`{}` is always truthy here, so this check is redundant",
+    _0
+  )]
+  AlwaysTruthy(String),
+  #[display(fmt = "`{}` is always falsy here, so this check is redundant", _0)]
+  AlwaysFalsy(String),
+}
+
+impl LintRule for NoGratuitousExpr {
+  fn new() -> Box<Self> {
+    Box::new(NoGratuitousExpr)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut collector = ReassignmentCollector::default();
+    program.visit_with(program, &mut collector);
+
+    let mut visitor =
+      NoGratuitousExprVisitor::new(context, collector.reassigned);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows logical expressions whose result is known from an enclosing `if`
+
+Inside `if (x) { ... }`, `x` is known to be truthy, so `x && y` is always `y`
+and `x || y` is always `x`. Such checks are redundant and often indicate a
+logic error.
+
+This rule is conservative: it only considers variables that are never
+reassigned, and doesn't look into nested functions, where the variable may
+have changed by the time they run.
+
+### Invalid:
+```typescript
+if (user) {
+  const name = user && user.name;
+}
+if (!error) {
+  report(error || "unknown");
+}
+```
+
+### Valid:
+```typescript
+if (user) {
+  const name = user.name;
+}
+if (user) {
+  setTimeout(() => user && user.save());
+}
+```
+"#
+  }
+}
+
+/// Collects bindings that are assigned to after being declared, whose
+/// truthiness can't be inferred from a guard.
+#[derive(Default)]
+struct ReassignmentCollector {
+  reassigned: HashSet<Id>,
+}
+
+impl Visit for ReassignmentCollector {
+  noop_visit_type!();
+
+  fn visit_assign_expr(
+    &mut self,
+    assign_expr: &AssignExpr,
+    _parent: &dyn Node,
+  ) {
+    match &assign_expr.left {
+      PatOrExpr::Pat(pat) => {
+        let ids: Vec<Id> = find_ids(pat);
+        self.reassigned.extend(ids);
+      }
+      PatOrExpr::Expr(expr) => {
+        if let Expr::Ident(ident) = &**expr {
+          self.reassigned.insert(ident.to_id());
+        }
+      }
+    }
+    assign_expr.visit_children_with(self);
+  }
+
+  fn visit_update_expr(
+    &mut self,
+    update_expr: &UpdateExpr,
+    _parent: &dyn Node,
+  ) {
+    if let Expr::Ident(ident) = &*update_expr.arg {
+      self.reassigned.insert(ident.to_id());
+    }
+    update_expr.visit_children_with(self);
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _parent: &dyn Node) {
+    if let VarDeclOrPat::Pat(pat) = &for_in_stmt.left {
+      let ids: Vec<Id> = find_ids(pat);
+      self.reassigned.extend(ids);
+    }
+    for_in_stmt.visit_children_with(self);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _parent: &dyn Node) {
+    if let VarDeclOrPat::Pat(pat) = &for_of_stmt.left {
+      let ids: Vec<Id> = find_ids(pat);
+      self.reassigned.extend(ids);
+    }
+    for_of_stmt.visit_children_with(self);
+  }
+}
+
+struct NoGratuitousExprVisitor<'c> {
+  context: &'c mut Context,
+  reassigned: HashSet<Id>,
+  /// Bindings whose truthiness is known in the current position, with `true`
+  /// meaning truthy.
+  known: Vec<(Id, bool)>,
+}
+
+impl<'c> NoGratuitousExprVisitor<'c> {
+  fn new(context: &'c mut Context, reassigned: HashSet<Id>) -> Self {
+    Self {
+      context,
+      reassigned,
+      known: Vec::new(),
+    }
+  }
+
+  /// Records what `test` being `truthy` implies about plain identifiers.
+  fn add_facts(&mut self, test: &Expr, truthy: bool) {
+    match test {
+      Expr::Ident(ident) => {
+        let id = ident.to_id();
+        // Only declared bindings that are never reassigned keep their value
+        if self.context.scope().var(&id).is_some()
+          && !self.reassigned.contains(&id)
+        {
+          self.known.push((id, truthy));
+        }
+      }
+      Expr::Paren(paren) => self.add_facts(&paren.expr, truthy),
+      Expr::Unary(unary) if unary.op == UnaryOp::Bang => {
+        self.add_facts(&unary.arg, !truthy)
+      }
+      // `a && b` being truthy means both are truthy
+      Expr::Bin(bin) if truthy && bin.op == BinaryOp::LogicalAnd => {
+        self.add_facts(&bin.left, true);
+        self.add_facts(&bin.right, true);
+      }
+      // `a || b` being falsy means both are falsy
+      Expr::Bin(bin) if !truthy && bin.op == BinaryOp::LogicalOr => {
+        self.add_facts(&bin.left, false);
+        self.add_facts(&bin.right, false);
+      }
+      _ => {}
+    }
+  }
+
+  fn is_truthy(&self, id: &Id) -> Option<bool> {
+    self
+      .known
+      .iter()
+      .rev()
+      .find(|(known_id, _)| known_id == id)
+      .map(|(_, truthy)| *truthy)
+  }
+}
+
+impl<'c> Visit for NoGratuitousExprVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_if_stmt(&mut self, if_stmt: &IfStmt, _parent: &dyn Node) {
+    if_stmt.test.visit_with(if_stmt, self);
+
+    let len = self.known.len();
+    self.add_facts(&if_stmt.test, true);
+    if_stmt.cons.visit_with(if_stmt, self);
+    self.known.truncate(len);
+
+    if let Some(alt) = &if_stmt.alt {
+      self.add_facts(&if_stmt.test, false);
+      alt.visit_with(if_stmt, self);
+      self.known.truncate(len);
+    }
+  }
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    if matches!(bin_expr.op, BinaryOp::LogicalAnd | BinaryOp::LogicalOr) {
+      if let Expr::Ident(ident) = &*bin_expr.left {
+        if let Some(truthy) = self.is_truthy(&ident.to_id()) {
+          let name = ident.sym.to_string();
+          let message = if truthy {
+            NoGratuitousExprMessage::AlwaysTruthy(name)
+          } else {
+            NoGratuitousExprMessage::AlwaysFalsy(name)
+          };
+          self.context.add_diagnostic(bin_expr.span, CODE, message);
+        }
+      }
+    }
+    bin_expr.visit_children_with(self);
+  }
+
+  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
+    let known = mem::take(&mut self.known);
+    function.visit_children_with(self);
+    self.known = known;
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    let known = mem::take(&mut self.known);
+    arrow_expr.visit_children_with(self);
+    self.known = known;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_gratuitous_expr_valid() {
+    assert_lint_ok! {
+      NoGratuitousExpr,
+      "const x = foo(); x && y;",
+      "const x = foo(); if (x) { y && x; }",
+      "const x = foo(); if (x) {} x && y;",
+      "let x = foo(); if (x) { x = bar(); x && y; }",
+      "let x = foo(); if (x) { x && y; } x = null;",
+      "const x = foo(); if (x) { setTimeout(() => x && y()); }",
+      "const x = foo(); if (x) { function f() { return x || y; } }",
+      "if (x) { x && y; }",
+      "const x = foo(); if (x || z) { x && y; }",
+      "const x = foo(); if (!(x && z)) { x && y; }",
+    };
+  }
+
+  #[test]
+  fn no_gratuitous_expr_invalid() {
+    assert_lint_err! {
+      NoGratuitousExpr,
+      "const x = foo(); if (x) { x && y; }": [
+        {
+          col: 26,
+          message: variant!(NoGratuitousExprMessage, AlwaysTruthy, "x"),
+        }
+      ],
+      "function f(x) { if (x) { return x || y; } }": [
+        {
+          col: 32,
+          message: variant!(NoGratuitousExprMessage, AlwaysTruthy, "x"),
+        }
+      ],
+      "const x = foo(); if (!x) { x || y; }": [
+        {
+          col: 27,
+          message: variant!(NoGratuitousExprMessage, AlwaysFalsy, "x"),
+        }
+      ],
+      "const x = foo(); if (x) {} else { x && y; }": [
+        {
+          col: 34,
+          message: variant!(NoGratuitousExprMessage, AlwaysFalsy, "x"),
+        }
+      ],
+      "const x = foo(), z = bar(); if (x && z) { if (w) { z && y; } }": [
+        {
+          col: 51,
+          message: variant!(NoGratuitousExprMessage, AlwaysTruthy, "z"),
+        }
+      ],
+    };
+  }
+}
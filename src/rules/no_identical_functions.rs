@@ -0,0 +1,387 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  ArrowExpr, BlockStmt, BlockStmtOrExpr, Function, Pat,
+};
+use swc_ecmascript::utils::drop_span;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoIdenticalFunctions {
+  options: NoIdenticalFunctionsOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoIdenticalFunctionsOptions {
+  /// Functions whose bodies have fewer statements than this are not checked.
+  pub min_statements: usize,
+}
+
+impl Default for NoIdenticalFunctionsOptions {
+  fn default() -> Self {
+    Self { min_statements: 3 }
+  }
+}
+
+const CODE: &str = "no-identical-functions";
+
+#[derive(Display)]
+enum NoIdenticalFunctionsMessage {
+  #[display(fmt = "Function is identical to the one on line {}", _0)]
+  Identical(usize),
+}
+
+#[derive(Display)]
+enum NoIdenticalFunctionsHint {
+  #[display(fmt = "Remove the duplicate and reuse the original function")]
+  Reuse,
+}
+
+impl NoIdenticalFunctions {
+  pub fn with_options(options: NoIdenticalFunctionsOptions) -> Box<Self> {
+    Box::new(NoIdenticalFunctions { options })
+  }
+}
+
+impl LintRule for NoIdenticalFunctions {
+  fn new() -> Box<Self> {
+    NoIdenticalFunctions::with_options(NoIdenticalFunctionsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "minStatements": {
+          "type": "integer",
+          "minimum": 0,
+          "description": "Functions whose bodies have fewer statements than this are not checked.",
+          "default": 3
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor =
+      NoIdenticalFunctionsVisitor::new(context, self.options.min_statements);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows functions with identical implementations
+
+Two functions with the same parameters and body are usually the result of
+copy and paste, and should be merged into one. Whitespace and formatting
+differences are ignored.
+
+Functions whose bodies have fewer statements than the `minStatements` option
+(3 by default) are not checked, so that trivial functions aren't reported.
+
+### Invalid:
+```typescript
+function getFullName(user) {
+  const first = user.firstName.trim();
+  const last = user.lastName.trim();
+  return `${first} ${last}`;
+}
+function getDisplayName(user) {
+  const first = user.firstName.trim();
+  const last = user.lastName.trim();
+  return `${first} ${last}`;
+}
+```
+
+### Valid:
+```typescript
+function getFullName(user) {
+  const first = user.firstName.trim();
+  const last = user.lastName.trim();
+  return `${first} ${last}`;
+}
+const getDisplayName = getFullName;
+```
+"#
+  }
+}
+
+struct FunctionInfo {
+  line: usize,
+  is_async: bool,
+  is_generator: bool,
+  /// Parameters and body with spans dropped, for structural comparison.
+  params: Vec<Pat>,
+  body: BlockStmt,
+}
+
+impl FunctionInfo {
+  fn is_identical_to(&self, other: &FunctionInfo) -> bool {
+    self.is_async == other.is_async
+      && self.is_generator == other.is_generator
+      && self.params == other.params
+      && self.body == other.body
+  }
+}
+
+struct NoIdenticalFunctionsVisitor<'c> {
+  context: &'c mut Context,
+  min_statements: usize,
+  /// Functions seen so far, keyed by their body source with whitespace
+  /// removed.
+  functions: HashMap<String, Vec<FunctionInfo>>,
+}
+
+impl<'c> NoIdenticalFunctionsVisitor<'c> {
+  fn new(context: &'c mut Context, min_statements: usize) -> Self {
+    Self {
+      context,
+      min_statements,
+      functions: HashMap::new(),
+    }
+  }
+
+  fn check_function(
+    &mut self,
+    span: Span,
+    params: Vec<Pat>,
+    body: &BlockStmt,
+    is_async: bool,
+    is_generator: bool,
+  ) {
+    if body.stmts.len() < self.min_statements {
+      return;
+    }
+    let key: String = match self.context.source_map.span_to_snippet(body.span) {
+      Ok(text) => text.chars().filter(|c| !c.is_whitespace()).collect(),
+      Err(_) => return,
+    };
+
+    let info = FunctionInfo {
+      line: self.context.source_map.lookup_char_pos(span.lo()).line,
+      is_async,
+      is_generator,
+      params: drop_span(params),
+      body: drop_span(body.clone()),
+    };
+    // Texts without whitespace may collide, e.g. `"a b"` and `"ab"`, so
+    // functions in the same bucket are compared structurally.
+    let candidates = self.functions.entry(key).or_default();
+    let original_line = match candidates
+      .iter()
+      .find(|original| original.is_identical_to(&info))
+    {
+      Some(original) => original.line,
+      None => {
+        candidates.push(info);
+        return;
+      }
+    };
+
+    self.context.add_diagnostic_with_hint(
+      span,
+      CODE,
+      NoIdenticalFunctionsMessage::Identical(original_line),
+      NoIdenticalFunctionsHint::Reuse,
+    );
+  }
+}
+
+impl<'c> Visit for NoIdenticalFunctionsVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
+    if let Some(body) = &function.body {
+      self.check_function(
+        function.span,
+        function
+          .params
+          .iter()
+          .map(|param| param.pat.clone())
+          .collect(),
+        body,
+        function.is_async,
+        function.is_generator,
+      );
+    }
+    function.visit_children_with(self);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    if let BlockStmtOrExpr::BlockStmt(body) = &arrow_expr.body {
+      self.check_function(
+        arrow_expr.span,
+        arrow_expr.params.clone(),
+        body,
+        arrow_expr.is_async,
+        arrow_expr.is_generator,
+      );
+    }
+    arrow_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn no_identical_functions_valid() {
+    assert_lint_ok! {
+      NoIdenticalFunctions,
+      r#"
+function a(x) {
+  foo();
+  bar();
+  return x;
+}
+function b(x) {
+  foo();
+  bar();
+  return x + 1;
+}
+      "#,
+      r#"
+function a(x) {
+  foo();
+  return x;
+}
+function b(x) {
+  foo();
+  return x;
+}
+      "#,
+      r#"
+function a(x) {
+  foo();
+  bar();
+  return x;
+}
+function b(y) {
+  foo();
+  bar();
+  return y;
+}
+      "#,
+      r#"
+function a() {
+  log("a b");
+  log("c");
+  log("d");
+}
+function b() {
+  log("ab");
+  log("c");
+  log("d");
+}
+      "#,
+      r#"
+function a() {
+  foo();
+  bar();
+  baz();
+}
+async function b() {
+  foo();
+  bar();
+  baz();
+}
+      "#,
+    };
+  }
+
+  #[test]
+  fn no_identical_functions_invalid() {
+    assert_lint_err! {
+      NoIdenticalFunctions,
+      r#"
+function a(x) {
+  foo();
+  bar();
+  return x;
+}
+function b(x) {
+  foo();   bar();
+  return x;
+}
+      "#: [
+        {
+          line: 7,
+          col: 0,
+          message: NoIdenticalFunctionsMessage::Identical(2),
+          hint: NoIdenticalFunctionsHint::Reuse,
+        }
+      ],
+      r#"
+const a = () => {
+  foo();
+  bar();
+  baz();
+};
+const b = () => {
+  foo();
+  bar();
+  baz();
+};
+const c = () => {
+  foo();
+  bar();
+  baz();
+};
+      "#: [
+        {
+          line: 7,
+          col: 10,
+          message: NoIdenticalFunctionsMessage::Identical(2),
+          hint: NoIdenticalFunctionsHint::Reuse,
+        },
+        {
+          line: 12,
+          col: 10,
+          message: NoIdenticalFunctionsMessage::Identical(2),
+          hint: NoIdenticalFunctionsHint::Reuse,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_identical_functions_min_statements() {
+    let options = NoIdenticalFunctionsOptions { min_statements: 1 };
+    assert_lint_err_on_line_with_rule(
+      NoIdenticalFunctions::with_options(options),
+      r#"
+function a() {
+  return foo();
+}
+function b() {
+  return foo();
+}
+      "#,
+      5,
+      0,
+    );
+  }
+}
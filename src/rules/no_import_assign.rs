@@ -62,7 +62,7 @@ poor code readability and difficult maintenance.
 
 ### Invalid:
 ```typescript
-import defaultMod, { namedMod } from './mod.js'; 
+import defaultMod, { namedMod } from './mod.js';
 import * as modNameSpace from './mod2.js';
 
 defaultMod = 0;
@@ -73,7 +73,7 @@ modNameSpace = {};
 
 ### Valid:
 ```typescript
-import defaultMod, { namedMod } from './mod.js'; 
+import defaultMod, { namedMod } from './mod.js';
 import * as modNameSpace from './mod2.js';
 
 // properties of bound imports may be set
@@ -232,6 +232,7 @@ impl<'c> NoImportAssignVisitor<'c> {
         match prop {
           Expr::Ident(Ident { sym, .. })
             if *sym == *"defineProperty"
+              || *sym == *"defineProperties"
               || *sym == *"assign"
               || *sym == *"setPrototypeOf"
               || *sym == *"freeze" =>
@@ -425,6 +426,7 @@ mod tests {
       "import {named} from 'mod'; Object.assign(named, obj);",
       "import * as mod from 'mod'; Object.assign(mod.prop, obj);",
       "import * as mod from 'mod'; Object.assign(obj, mod, other);",
+      "import * as mod from 'mod'; Object.defineProperties(obj, mod);",
       "import * as mod from 'mod'; Object[assign](mod, obj);",
       "import * as mod from 'mod'; Object.getPrototypeOf(mod);",
       "import * as mod from 'mod'; Reflect.set(obj, key, mod);",
@@ -489,6 +491,7 @@ mod tests {
       "import * as mod from 'mod'; Object.assign(mod, obj)": [{ col: 28, message: MESSAGE, hint: HINT }],
       "import * as mod from 'mod'; Object.defineProperty(mod, key, d)": [{ col: 28, message: MESSAGE, hint: HINT }],
       "import * as mod from 'mod'; Object.setPrototypeOf(mod, proto)": [{ col: 28, message: MESSAGE, hint: HINT }],
+      "import * as mod from 'mod'; Object.defineProperties(mod, props)": [{ col: 28, message: MESSAGE, hint: HINT }],
       "import * as mod from 'mod'; Object.freeze(mod)": [{ col: 28, message: MESSAGE, hint: HINT }],
       "import * as mod from 'mod'; Reflect.defineProperty(mod, key, d)": [{ col: 28, message: MESSAGE, hint: HINT }],
       "import * as mod from 'mod'; Reflect.deleteProperty(mod, key)": [{ col: 28, message: MESSAGE, hint: HINT }],
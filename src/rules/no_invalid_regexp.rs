@@ -13,7 +13,7 @@ pub struct NoInvalidRegexp;
 
 const CODE: &str = "no-invalid-regexp";
 const MESSAGE: &str = "Invalid RegExp literal";
-const HINT: &str = "Rework regular expression to be a valid";
+const HINT: &str = "Rework the regular expression to be valid";
 
 impl LintRule for NoInvalidRegexp {
   fn new() -> Box<Self> {
@@ -103,24 +103,29 @@ impl<'c> NoInvalidRegexpVisitor<'c> {
   }
 
   fn check_regex(&mut self, pattern: &str, flags: &str, span: Span) {
-    if self.check_for_invalid_flags(flags)
-      || (!flags.is_empty()
-        && self.check_for_invalid_pattern(pattern, flags.contains('u')))
-      || (self.check_for_invalid_pattern(pattern, true)
-        && self.check_for_invalid_pattern(pattern, false))
-    {
-      self
-        .context
-        .add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+    if let Err(error) = self.validate(pattern, flags) {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        MESSAGE,
+        format!("{}: {}", HINT, error),
+      );
     }
   }
 
-  fn check_for_invalid_flags(&self, flags: &str) -> bool {
-    self.validator.validate_flags(flags).is_err()
-  }
-
-  fn check_for_invalid_pattern(&mut self, source: &str, u_flag: bool) -> bool {
-    self.validator.validate_pattern(source, u_flag).is_err()
+  /// Returns the validator's error for the first problem found, if any.
+  fn validate(&mut self, pattern: &str, flags: &str) -> Result<(), String> {
+    self.validator.validate_flags(flags)?;
+    if !flags.is_empty() {
+      return self
+        .validator
+        .validate_pattern(pattern, flags.contains('u'));
+    }
+    // Without flags the mode is unknown, so accept patterns valid in either
+    if self.validator.validate_pattern(pattern, true).is_ok() {
+      return Ok(());
+    }
+    self.validator.validate_pattern(pattern, false)
   }
 }
 
@@ -209,14 +214,69 @@ let re = new RegExp('foo', x);"#,
   fn no_invalid_regexp_invalid() {
     assert_lint_err! {
       NoInvalidRegexp,
-      r#"RegExp('[');"#: [{ col: 0, message: MESSAGE, hint: HINT }],
-      r#"RegExp('.', 'z');"#: [{ col: 0, message: MESSAGE, hint: HINT }],
-      r#"new RegExp(')');"#: [{ col: 0, message: MESSAGE, hint: HINT }],
-      r#"new RegExp('\\');"#: [{ col: 0, message: MESSAGE, hint: HINT }],
-      r#"var foo = new RegExp('(', '');"#: [{ col: 10, message: MESSAGE, hint: HINT }],
-      r#"/(?<a>a)\k</"#: [{ col: 0, message: MESSAGE, hint: HINT }],
-      r#"/(?<!a){1}/"#: [{ col: 0, message: MESSAGE, hint: HINT }],
-      r#"/(a)(a)(a)(a)(a)(a)(a)(a)(a)(a)\11/u"#: [{ col: 0, message: MESSAGE, hint: HINT }],
+      r#"RegExp('[');"#: [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: format!("{}: Unterminated character class", HINT),
+        }
+      ],
+      r#"RegExp('.', 'z');"#: [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: format!("{}: Invalid flag z", HINT),
+        }
+      ],
+      r#"new RegExp(')');"#: [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: format!("{}: Unmatched ')'", HINT),
+        }
+      ],
+      r#"new RegExp('\\');"#: [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: format!("{}: \\ at end of pattern", HINT),
+        }
+      ],
+      r#"new RegExp('(');"#: [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: format!("{}: Unterminated group", HINT),
+        }
+      ],
+      r#"var foo = new RegExp('(', '');"#: [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: format!("{}: Unterminated group", HINT),
+        }
+      ],
+      r#"/(?<a>a)\k</"#: [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: format!("{}: Invalid capture group name", HINT),
+        }
+      ],
+      r#"/(?<!a){1}/"#: [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: format!("{}: Nothing to repeat", HINT),
+        }
+      ],
+      r#"/(a)(a)(a)(a)(a)(a)(a)(a)(a)(a)\11/u"#: [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: format!("{}: Invalid escape", HINT),
+        }
+      ],
     }
   }
 }
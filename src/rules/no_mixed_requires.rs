@@ -0,0 +1,347 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_ecmascript::ast::{
+  CallExpr, Expr, ExprOrSpread, ExprOrSuper, Lit, VarDecl,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoMixedRequires {
+  options: NoMixedRequiresOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoMixedRequiresOptions {
+  /// Also report declarations requiring different kinds of modules: core
+  /// modules, files, packages and computed specifiers.
+  pub grouping: bool,
+}
+
+const CODE: &str = "no-mixed-requires";
+
+/// Node.js core modules, which can be required without a path.
+const CORE_MODULES: &[&str] = &[
+  "assert",
+  "buffer",
+  "child_process",
+  "cluster",
+  "crypto",
+  "dgram",
+  "dns",
+  "domain",
+  "events",
+  "fs",
+  "http",
+  "https",
+  "net",
+  "os",
+  "path",
+  "punycode",
+  "querystring",
+  "readline",
+  "stream",
+  "string_decoder",
+  "tls",
+  "tty",
+  "url",
+  "util",
+  "vm",
+  "zlib",
+];
+
+#[derive(Display)]
+enum NoMixedRequiresMessage {
+  #[display(fmt = "Do not mix `require` and other declarations")]
+  MixedDeclarations,
+  #[display(fmt = "Do not mix core, module, file and computed requires")]
+  MixedRequires,
+}
+
+#[derive(Display)]
+enum NoMixedRequiresHint {
+  #[display(fmt = "Move the `require` calls to their own declaration")]
+  SeparateDeclarations,
+  #[display(fmt = "Declare each kind of required module separately")]
+  SeparateRequires,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DeclarationKind {
+  Require,
+  Uninitialized,
+  Other,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ModuleKind {
+  Core,
+  File,
+  Module,
+  Computed,
+}
+
+impl NoMixedRequires {
+  pub fn with_options(options: NoMixedRequiresOptions) -> Box<Self> {
+    Box::new(NoMixedRequires { options })
+  }
+}
+
+impl LintRule for NoMixedRequires {
+  fn new() -> Box<Self> {
+    NoMixedRequires::with_options(NoMixedRequiresOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "grouping": {
+          "type": "boolean",
+          "description": "Also report declarations requiring different kinds of modules.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoMixedRequiresVisitor::new(context, &self.options);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows mixing `require` calls with other declarations
+
+Declaring required modules separately from other variables shows the
+dependencies of a module at a glance. This rule reports declarations that
+initialize some variables with `require` and others with something else, or not
+at all.
+
+With the `grouping` option set, declarations requiring different kinds of
+modules are reported as well. The kinds are core modules like `fs`, files like
+`./foo.js`, packages like `express` and computed specifiers.
+
+### Invalid:
+```typescript
+const fs = require("fs"), count = 0;
+let path = require("path"), name;
+// With `grouping: true`
+const fs = require("fs"), foo = require("./foo");
+```
+
+### Valid:
+```typescript
+const fs = require("fs"), path = require("path");
+const count = 0;
+// With `grouping: true`
+const foo = require("./foo"), bar = require("./bar");
+```
+"#
+  }
+}
+
+struct NoMixedRequiresVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o NoMixedRequiresOptions,
+}
+
+impl<'c, 'o> NoMixedRequiresVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o NoMixedRequiresOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  /// Returns the call to the global `require` that `expr` starts with, e.g.
+  /// in `require("foo").bar`.
+  fn find_require_call<'e>(&self, expr: &'e Expr) -> Option<&'e CallExpr> {
+    match expr {
+      Expr::Call(call_expr) => {
+        let callee = match &call_expr.callee {
+          ExprOrSuper::Expr(callee) => callee,
+          ExprOrSuper::Super(_) => return None,
+        };
+        match &**callee {
+          // `require` may be shadowed by a local binding
+          Expr::Ident(ident)
+            if ident.sym == *"require"
+              && self.context.scope().var(&ident.to_id()).is_none() =>
+          {
+            Some(call_expr)
+          }
+          callee => self.find_require_call(callee),
+        }
+      }
+      Expr::Member(member_expr) => match &member_expr.obj {
+        ExprOrSuper::Expr(obj) => self.find_require_call(obj),
+        ExprOrSuper::Super(_) => None,
+      },
+      Expr::Paren(paren_expr) => self.find_require_call(&paren_expr.expr),
+      _ => None,
+    }
+  }
+
+  fn declaration_kind(&self, init: Option<&Expr>) -> DeclarationKind {
+    match init {
+      None => DeclarationKind::Uninitialized,
+      Some(init) if self.find_require_call(init).is_some() => {
+        DeclarationKind::Require
+      }
+      Some(_) => DeclarationKind::Other,
+    }
+  }
+}
+
+fn module_kind(require_call: &CallExpr) -> ModuleKind {
+  let specifier = match require_call.args.first() {
+    Some(ExprOrSpread { spread: None, expr }) => match &**expr {
+      Expr::Lit(Lit::Str(s)) => s.value.to_string(),
+      _ => return ModuleKind::Computed,
+    },
+    _ => return ModuleKind::Computed,
+  };
+  let specifier = specifier.strip_prefix("node:").unwrap_or(&specifier);
+  if CORE_MODULES.contains(&specifier) {
+    ModuleKind::Core
+  } else if specifier.starts_with('.') || specifier.starts_with('/') {
+    ModuleKind::File
+  } else {
+    ModuleKind::Module
+  }
+}
+
+impl<'c, 'o> Visit for NoMixedRequiresVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_var_decl(&mut self, var_decl: &VarDecl, _parent: &dyn Node) {
+    let kinds: Vec<DeclarationKind> = var_decl
+      .decls
+      .iter()
+      .map(|decl| self.declaration_kind(decl.init.as_deref()))
+      .collect();
+
+    if kinds.contains(&DeclarationKind::Require)
+      && kinds.iter().any(|kind| *kind != DeclarationKind::Require)
+    {
+      self.context.add_diagnostic_with_hint(
+        var_decl.span,
+        CODE,
+        NoMixedRequiresMessage::MixedDeclarations,
+        NoMixedRequiresHint::SeparateDeclarations,
+      );
+    } else if self.options.grouping {
+      let mut module_kinds = var_decl.decls.iter().filter_map(|decl| {
+        let init = decl.init.as_deref()?;
+        self.find_require_call(init).map(module_kind)
+      });
+      if let Some(first) = module_kinds.next() {
+        if module_kinds.any(|kind| kind != first) {
+          self.context.add_diagnostic_with_hint(
+            var_decl.span,
+            CODE,
+            NoMixedRequiresMessage::MixedRequires,
+            NoMixedRequiresHint::SeparateRequires,
+          );
+        }
+      }
+    }
+
+    var_decl.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn grouping() -> Box<NoMixedRequires> {
+    NoMixedRequires::with_options(NoMixedRequiresOptions { grouping: true })
+  }
+
+  #[test]
+  fn no_mixed_requires_valid() {
+    assert_lint_ok! {
+      NoMixedRequires,
+      "const fs = require('fs'), path = require('path');",
+      "const a = 1, b = 2;",
+      "let a, b;",
+      "const fs = require('fs');",
+      "const foo = require('fs').foo, bar = require('./bar')();",
+      "const fs = require('fs'), foo = require('./foo');",
+      "function f(require) { const a = require('a'), b = 1; }",
+    };
+
+    let sources = [
+      "const fs = require('fs'), path = require('node:path');",
+      "const foo = require('./foo'), bar = require('../bar');",
+      "const a = require('express'), b = require('lodash').map;",
+      "const a = require(name), b = require(`./${other}`);",
+      "const a = 1, b = 2;",
+    ];
+    for src in &sources {
+      assert_lint_ok_with_rule(grouping(), src);
+    }
+  }
+
+  #[test]
+  fn no_mixed_requires_invalid() {
+    assert_lint_err! {
+      NoMixedRequires,
+      "const fs = require('fs'), count = 0;": [
+        {
+          col: 0,
+          message: NoMixedRequiresMessage::MixedDeclarations,
+          hint: NoMixedRequiresHint::SeparateDeclarations,
+        }
+      ],
+      "let path = require('path'), name;": [
+        {
+          col: 0,
+          message: NoMixedRequiresMessage::MixedDeclarations,
+          hint: NoMixedRequiresHint::SeparateDeclarations,
+        }
+      ],
+      "function f() {\n  var a = foo(), b = require('b').c;\n}": [
+        {
+          line: 2,
+          col: 2,
+          message: NoMixedRequiresMessage::MixedDeclarations,
+          hint: NoMixedRequiresHint::SeparateDeclarations,
+        }
+      ],
+    };
+
+    let sources = [
+      "const fs = require('fs'), foo = require('./foo');",
+      "const a = require('express'), b = require('/abs/b');",
+      "const a = require('fs'), b = require(name);",
+      "const a = require('lodash'), b = require('path');",
+    ];
+    for src in &sources {
+      assert_lint_err_on_line_with_rule(grouping(), src, 1, 0);
+    }
+  }
+}
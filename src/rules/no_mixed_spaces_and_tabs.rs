@@ -2,6 +2,8 @@
 use super::Context;
 use super::LintRule;
 use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 
 use once_cell::sync::Lazy;
 use swc_common::BytePos;
@@ -15,12 +17,30 @@ use swc_ecmascript::visit::Visit;
 
 static RE: Lazy<Regex> =
   Lazy::new(|| Regex::new("^([\t ]*(\t | \t))").unwrap());
+// Only spaces followed by tabs, since tabs followed by spaces are alignment
+static SMART_TABS_RE: Lazy<Regex> =
+  Lazy::new(|| Regex::new("^([\t ]* \t)").unwrap());
 
-pub struct NoMixedSpacesAndTabs;
+pub struct NoMixedSpacesAndTabs {
+  options: NoMixedSpacesAndTabsOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoMixedSpacesAndTabsOptions {
+  /// Allow spaces after tabs, which are used to align code.
+  pub smart_tabs: bool,
+}
+
+impl NoMixedSpacesAndTabs {
+  pub fn with_options(options: NoMixedSpacesAndTabsOptions) -> Box<Self> {
+    Box::new(NoMixedSpacesAndTabs { options })
+  }
+}
 
 impl LintRule for NoMixedSpacesAndTabs {
   fn new() -> Box<Self> {
-    Box::new(NoMixedSpacesAndTabs)
+    NoMixedSpacesAndTabs::with_options(NoMixedSpacesAndTabsOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -31,6 +51,24 @@ impl LintRule for NoMixedSpacesAndTabs {
     "no-mixed-spaces-and-tabs"
   }
 
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "smartTabs": {
+          "type": "boolean",
+          "description": "Allow spaces after tabs, which are used to align code.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
   fn lint_program(
     &self,
     context: &mut Context,
@@ -73,10 +111,15 @@ impl LintRule for NoMixedSpacesAndTabs {
     });
 
     let excluded_ranges = excluded_ranges.iter();
+    let re = if self.options.smart_tabs {
+      &SMART_TABS_RE
+    } else {
+      &RE
+    };
     for line_index in 0..file.count_lines() {
       let line = file.get_line(line_index).unwrap();
       let (byte_pos, _hi) = file.line_bounds(line_index);
-      let whitespace_matches = RE.find_iter(&line);
+      let whitespace_matches = re.find_iter(&line);
       for whitespace_match in whitespace_matches {
         let range = whitespace_match.range();
         let span = Span::new(
@@ -96,6 +139,33 @@ impl LintRule for NoMixedSpacesAndTabs {
       }
     }
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows indentation mixing spaces and tabs
+
+Tabs are displayed with different widths by different editors, so
+indentation mixing them with spaces looks misaligned for some readers. This
+rule reports lines whose indentation contains both. Comments, strings and
+template literals spanning several lines are not checked.
+
+With the `smartTabs` option, spaces after tabs are allowed, since they are
+commonly used to align code after indenting it with tabs.
+
+### Invalid:
+```typescript
+function add(x, y) {
+	 return x + y;
+}
+```
+
+### Valid:
+```typescript
+function add(x, y) {
+	return x + y;
+}
+```
+"#
+  }
 }
 
 struct NoMixedSpacesAndTabsVisitor {
@@ -185,4 +255,30 @@ mod tests {
     assert_lint_err_on_line::<NoMixedSpacesAndTabs>("  \t'';", 1, 0);
     assert_lint_err_on_line::<NoMixedSpacesAndTabs>("''\n\t ", 2, 0);
   }
+
+  #[test]
+  fn no_mixed_spaces_and_tabs_smart_tabs() {
+    let smart_tabs = || {
+      NoMixedSpacesAndTabs::with_options(NoMixedSpacesAndTabsOptions {
+        smart_tabs: true,
+      })
+    };
+    let sources = [
+      "\tvar x = 5;",
+      "\t var x = 5;",
+      "var x = 5,\n\t\t    y = 7;",
+      "    var x = 5;",
+    ];
+    for src in &sources {
+      assert_lint_ok_with_rule(smart_tabs(), src);
+    }
+
+    assert_lint_err_on_line_with_rule(smart_tabs(), " \tvar x = 5;", 1, 0);
+    assert_lint_err_on_line_with_rule(
+      smart_tabs(),
+      "function add(x, y) {\n\t \treturn x + y;\n}",
+      2,
+      0,
+    );
+  }
 }
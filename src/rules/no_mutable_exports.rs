@@ -0,0 +1,214 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use swc_atoms::JsWord;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  Decl, ExportSpecifier, Expr, Ident, Module, ModuleDecl, ModuleItem, Program,
+  Stmt, VarDecl, VarDeclKind,
+};
+use swc_ecmascript::utils::find_ids;
+
+pub struct NoMutableExports;
+
+const CODE: &str = "no-mutable-exports";
+
+#[derive(Display)]
+enum NoMutableExportsMessage {
+  #[display(fmt = "Exporting mutable `{}` binding", _0)]
+  Mutable(&'static str),
+}
+
+#[derive(Display)]
+enum NoMutableExportsHint {
+  #[display(fmt = "Use `const` instead")]
+  UseConst,
+}
+
+impl LintRule for NoMutableExports {
+  fn new() -> Box<Self> {
+    Box::new(NoMutableExports)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, context: &mut Context, program: &Program) {
+    if let Program::Module(module) = program {
+      check_module(context, module);
+    }
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows exporting mutable bindings
+
+An exported `let` or `var` binding can be reassigned by the exporting module,
+and every importing module sees the change. This makes the value hard to
+reason about. This rule reports exported `let` and `var` declarations, as well
+as `let` and `var` bindings exported later by name.
+
+### Invalid:
+```typescript
+export let count = 1;
+var name = "foo";
+export { name };
+```
+
+### Valid:
+```typescript
+export const count = 1;
+const name = "foo";
+export { name };
+```
+"#
+  }
+}
+
+fn check_module(context: &mut Context, module: &Module) {
+  // Top level `let` and `var` bindings, with the kind of their declaration
+  let mut mutable_bindings: Vec<(JsWord, VarDeclKind)> = vec![];
+  for item in &module.body {
+    if let ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) = item {
+      if var_decl.kind != VarDeclKind::Const {
+        for ident in declared_idents(var_decl) {
+          mutable_bindings.push((ident.sym, var_decl.kind));
+        }
+      }
+    }
+  }
+  let find_mutable = |ident: &Ident| {
+    mutable_bindings
+      .iter()
+      .find(|(sym, _)| *sym == ident.sym)
+      .map(|(_, kind)| *kind)
+  };
+
+  for item in &module.body {
+    let module_decl = match item {
+      ModuleItem::ModuleDecl(module_decl) => module_decl,
+      ModuleItem::Stmt(_) => continue,
+    };
+    match module_decl {
+      ModuleDecl::ExportDecl(export_decl) => {
+        if let Decl::Var(var_decl) = &export_decl.decl {
+          if var_decl.kind != VarDeclKind::Const {
+            report(context, export_decl.span, var_decl.kind);
+          }
+        }
+      }
+      // Re-exports from other modules can't be checked here
+      ModuleDecl::ExportNamed(named_export) if named_export.src.is_none() => {
+        for specifier in &named_export.specifiers {
+          if let ExportSpecifier::Named(named) = specifier {
+            if let Some(kind) = find_mutable(&named.orig) {
+              report(context, named.orig.span, kind);
+            }
+          }
+        }
+      }
+      ModuleDecl::ExportDefaultExpr(export_default_expr) => {
+        if let Expr::Ident(ident) = &*export_default_expr.expr {
+          if let Some(kind) = find_mutable(ident) {
+            report(context, ident.span, kind);
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+fn report(context: &mut Context, span: Span, kind: VarDeclKind) {
+  let kind = match kind {
+    VarDeclKind::Let => "let",
+    _ => "var",
+  };
+  context.add_diagnostic_with_hint(
+    span,
+    CODE,
+    NoMutableExportsMessage::Mutable(kind),
+    NoMutableExportsHint::UseConst,
+  );
+}
+
+fn declared_idents(var_decl: &VarDecl) -> Vec<Ident> {
+  let mut idents = vec![];
+  for declarator in &var_decl.decls {
+    let ids: Vec<Ident> = find_ids(&declarator.name);
+    idents.extend(ids);
+  }
+  idents
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_mutable_exports_valid() {
+    assert_lint_ok! {
+      NoMutableExports,
+      "export const x = 1;",
+      "export const { a, b } = obj;",
+      "const x = 1; export { x };",
+      "const x = 1; export { x as y };",
+      "const x = 1; export default x;",
+      "export function foo() {}",
+      "export class Foo {}",
+      "let x = 1; x = 2;",
+      "export { x } from './foo.ts';",
+      "function foo() { let x = 1; } const x = 1; export { x };",
+    };
+  }
+
+  #[test]
+  fn no_mutable_exports_invalid() {
+    assert_lint_err! {
+      NoMutableExports,
+      "export let x = 1;": [
+        {
+          col: 0,
+          message: NoMutableExportsMessage::Mutable("let"),
+          hint: NoMutableExportsHint::UseConst,
+        }
+      ],
+      "export var x = 1;": [
+        {
+          col: 0,
+          message: NoMutableExportsMessage::Mutable("var"),
+          hint: NoMutableExportsHint::UseConst,
+        }
+      ],
+      "let x = 1; export { x };": [
+        {
+          col: 20,
+          message: NoMutableExportsMessage::Mutable("let"),
+          hint: NoMutableExportsHint::UseConst,
+        }
+      ],
+      "var x = 1; export { x as y };": [
+        {
+          col: 20,
+          message: NoMutableExportsMessage::Mutable("var"),
+          hint: NoMutableExportsHint::UseConst,
+        }
+      ],
+      "let { a, b } = obj; export { b };": [
+        {
+          col: 29,
+          message: NoMutableExportsMessage::Mutable("let"),
+          hint: NoMutableExportsHint::UseConst,
+        }
+      ],
+      "let x = 1; export default x;": [
+        {
+          col: 26,
+          message: NoMutableExportsMessage::Mutable("let"),
+          hint: NoMutableExportsHint::UseConst,
+        }
+      ],
+    };
+  }
+}
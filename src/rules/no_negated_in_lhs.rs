@@ -0,0 +1,141 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use swc_ecmascript::ast::{BinExpr, BinaryOp, Expr, UnaryOp};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoNegatedInLhs;
+
+const CODE: &str = "no-negated-in-lhs";
+
+#[derive(Display)]
+enum NoNegatedInLhsMessage {
+  #[display(fmt = "The left operand of `in` must not be negated")]
+  Unexpected,
+}
+
+#[derive(Display)]
+enum NoNegatedInLhsHint {
+  #[display(
+    fmt = "Use `!(key in object)` to negate the whole `in` expression, or `(!key) in object` if the negated value is meant"
+  )]
+  Parenthesize,
+}
+
+impl LintRule for NoNegatedInLhs {
+  fn new() -> Box<Self> {
+    Box::new(NoNegatedInLhs)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoNegatedInLhsVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows negating the left operand of the `in` operator
+
+`!key in object` is parsed as `(!key) in object`, which checks whether `true`
+or `false` is a key of `object`. This is almost never intended; the negation
+is meant to apply to the whole `in` expression. `no-unsafe-negation` covers
+this case as well as `instanceof`.
+
+### Invalid:
+```typescript
+if (!key in object) {}
+```
+
+### Valid:
+```typescript
+if (!(key in object)) {}
+if (("" + !key) in object) {}
+```
+"#
+  }
+}
+
+struct NoNegatedInLhsVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoNegatedInLhsVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c> Visit for NoNegatedInLhsVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    if bin_expr.op == BinaryOp::In {
+      if let Expr::Unary(unary_expr) = &*bin_expr.left {
+        // Not fixed, since either meaning may be intended
+        if unary_expr.op == UnaryOp::Bang {
+          self.context.add_diagnostic_with_hint(
+            bin_expr.span,
+            CODE,
+            NoNegatedInLhsMessage::Unexpected,
+            NoNegatedInLhsHint::Parenthesize,
+          );
+        }
+      }
+    }
+    bin_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_negated_in_lhs_valid() {
+    assert_lint_ok! {
+      NoNegatedInLhs,
+      "key in object;",
+      "!(key in object);",
+      "(!key) instanceof Foo;",
+      "!key instanceof Foo;",
+      "('' + !key) in object;",
+    };
+  }
+
+  #[test]
+  fn no_negated_in_lhs_invalid() {
+    assert_lint_err! {
+      NoNegatedInLhs,
+      "if (!key in object) {}": [
+        {
+          col: 4,
+          message: NoNegatedInLhsMessage::Unexpected,
+          hint: NoNegatedInLhsHint::Parenthesize,
+        }
+      ],
+      "foo(!a in b, (!c in d));": [
+        {
+          col: 4,
+          message: NoNegatedInLhsMessage::Unexpected,
+          hint: NoNegatedInLhsHint::Parenthesize,
+        },
+        {
+          col: 14,
+          message: NoNegatedInLhsMessage::Unexpected,
+          hint: NoNegatedInLhsHint::Parenthesize,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,154 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use swc_ecmascript::ast::{Expr, ExprOrSuper, NewExpr};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoNewRequire;
+
+const CODE: &str = "no-new-require";
+
+#[derive(Display)]
+enum NoNewRequireMessage {
+  #[display(fmt = "Unexpected use of `new` with `require`")]
+  Unexpected,
+}
+
+#[derive(Display)]
+enum NoNewRequireHint {
+  #[display(fmt = "Assign the required module to a variable first")]
+  AssignFirst,
+}
+
+impl LintRule for NoNewRequire {
+  fn new() -> Box<Self> {
+    Box::new(NoNewRequire)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoNewRequireVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows `new` with `require`
+
+`new require("foo")` constructs the value exported by the module, which reads
+as if the module itself was constructed. Requiring the module into a variable
+first makes clear what is constructed.
+
+### Invalid:
+```typescript
+const app = new require("app");
+const server = new (require("server"))();
+```
+
+### Valid:
+```typescript
+const App = require("app");
+const app = new App();
+```
+"#
+  }
+}
+
+struct NoNewRequireVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoNewRequireVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  /// Checks if `callee` is the global `require`, or a call to it.
+  fn is_require(&self, callee: &Expr) -> bool {
+    match callee {
+      // `require` may be shadowed by a local binding
+      Expr::Ident(ident) => {
+        ident.sym == *"require"
+          && self.context.scope().var(&ident.to_id()).is_none()
+      }
+      Expr::Call(call_expr) => match &call_expr.callee {
+        ExprOrSuper::Expr(callee) => self.is_require(callee),
+        ExprOrSuper::Super(_) => false,
+      },
+      Expr::Paren(paren_expr) => self.is_require(&paren_expr.expr),
+      _ => false,
+    }
+  }
+}
+
+impl<'c> Visit for NoNewRequireVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_new_expr(&mut self, new_expr: &NewExpr, _parent: &dyn Node) {
+    if self.is_require(&new_expr.callee) {
+      self.context.add_diagnostic_with_hint(
+        new_expr.span,
+        CODE,
+        NoNewRequireMessage::Unexpected,
+        NoNewRequireHint::AssignFirst,
+      );
+    }
+    new_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_new_require_valid() {
+    assert_lint_ok! {
+      NoNewRequire,
+      "const foo = require('foo');",
+      "const App = require('app'); const app = new App();",
+      "const app = new (require('app').App)();",
+      "const app = new Require('app');",
+      "function f(require) { return new require('foo'); }",
+    };
+  }
+
+  #[test]
+  fn no_new_require_invalid() {
+    assert_lint_err! {
+      NoNewRequire,
+      "const foo = new require('foo');": [
+        {
+          col: 12,
+          message: NoNewRequireMessage::Unexpected,
+          hint: NoNewRequireHint::AssignFirst,
+        }
+      ],
+      "const foo = new (require('foo'))();": [
+        {
+          col: 12,
+          message: NoNewRequireMessage::Unexpected,
+          hint: NoNewRequireHint::AssignFirst,
+        }
+      ],
+      "new require;": [
+        {
+          col: 0,
+          message: NoNewRequireMessage::Unexpected,
+          hint: NoNewRequireHint::AssignFirst,
+        }
+      ],
+    };
+  }
+}
@@ -2,9 +2,11 @@
 use super::Context;
 use super::LintRule;
 use swc_ecmascript::ast::{Expr, NewExpr};
+use swc_ecmascript::utils::ident::IdentLike;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
 
 pub struct NoNewSymbol;
 
@@ -46,14 +48,19 @@ impl<'c> Visit for NoNewSymbolVisitor<'c> {
 
   fn visit_new_expr(&mut self, new_expr: &NewExpr, _parent: &dyn Node) {
     if let Expr::Ident(ident) = &*new_expr.callee {
-      if ident.sym == *"Symbol" {
-        self.context.add_diagnostic(
+      // `Symbol` may be shadowed by a local binding
+      if ident.sym == *"Symbol"
+        && self.context.scope().var(&ident.to_id()).is_none()
+      {
+        self.context.add_diagnostic_with_hint(
           new_expr.span,
           "no-new-symbol",
           "`Symbol` cannot be called as a constructor.",
+          "Call `Symbol()` without `new`",
         );
       }
     }
+    new_expr.visit_children_with(self);
   }
 }
 
@@ -68,11 +75,21 @@ mod tests {
       NoNewSymbol,
       "new Class()",
       "Symbol()",
+      "Symbol(\"x\")",
+      "Symbol.for(\"x\")",
+      "new Symbol.foo()",
+      "new foo.Symbol()",
+      "function f(Symbol) { new Symbol(); }",
+      "class Symbol {} new Symbol();",
+      "import { Symbol } from \"./symbol.ts\"; new Symbol();",
     };
   }
 
   #[test]
   fn no_new_symbol_invalid() {
     assert_lint_err::<NoNewSymbol>("new Symbol()", 0);
+    assert_lint_err::<NoNewSymbol>("new Symbol(\"x\")", 0);
+    assert_lint_err::<NoNewSymbol>("foo(new Symbol())", 4);
+    assert_lint_err::<NoNewSymbol>("function f() { return new Symbol(); }", 22);
   }
 }
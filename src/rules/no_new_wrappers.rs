@@ -0,0 +1,156 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use swc_ecmascript::ast::{Expr, NewExpr};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoNewWrappers;
+
+const CODE: &str = "no-new-wrappers";
+
+#[derive(Display)]
+enum NoNewWrappersMessage {
+  #[display(fmt = "Do not use `{}` as a constructor", _0)]
+  Wrapper(String),
+}
+
+#[derive(Display)]
+enum NoNewWrappersHint {
+  #[display(fmt = "Call `{}()` without `new` to convert a value", _0)]
+  CallWithoutNew(String),
+}
+
+impl LintRule for NoNewWrappers {
+  fn new() -> Box<Self> {
+    Box::new(NoNewWrappers)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoNewWrappersVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows creating primitive wrapper objects with `new`
+
+`new String("x")`, `new Number(1)` and `new Boolean(false)` create objects
+wrapping a primitive value. Their `typeof` is `"object"`, they're never equal to
+another wrapper of the same value, and `new Boolean(false)` is truthy. Calling
+the functions without `new` converts a value to the primitive type instead.
+
+### Invalid:
+```typescript
+const s = new String("x");
+const n = new Number(value);
+const b = new Boolean(false);
+```
+
+### Valid:
+```typescript
+const s = String(value);
+const n = Number(value);
+const b = Boolean(value);
+```
+"#
+  }
+}
+
+struct NoNewWrappersVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoNewWrappersVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c> Visit for NoNewWrappersVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_new_expr(&mut self, new_expr: &NewExpr, _parent: &dyn Node) {
+    if let Expr::Ident(ident) = &*new_expr.callee {
+      let name = &*ident.sym;
+      // The wrappers may be shadowed by local bindings
+      if matches!(name, "String" | "Number" | "Boolean")
+        && self.context.scope().var(&ident.to_id()).is_none()
+      {
+        self.context.add_diagnostic_with_hint(
+          new_expr.span,
+          CODE,
+          NoNewWrappersMessage::Wrapper(name.to_string()),
+          NoNewWrappersHint::CallWithoutNew(name.to_string()),
+        );
+      }
+    }
+    new_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_new_wrappers_valid() {
+    assert_lint_ok! {
+      NoNewWrappers,
+      "const s = String(x);",
+      "const n = Number(x);",
+      "const b = Boolean(x);",
+      "const o = new Object();",
+      "const s = new foo.String('x');",
+      "function f(String) { return new String('x'); }",
+      "class Number {} new Number(1);",
+      "import { Boolean } from './boolean.ts'; new Boolean(true);",
+    };
+  }
+
+  #[test]
+  fn no_new_wrappers_invalid() {
+    assert_lint_err! {
+      NoNewWrappers,
+      r#"new String("x");"#: [
+        {
+          col: 0,
+          message: NoNewWrappersMessage::Wrapper("String".to_string()),
+          hint: NoNewWrappersHint::CallWithoutNew("String".to_string()),
+        }
+      ],
+      "const n = new Number(1);": [
+        {
+          col: 10,
+          message: NoNewWrappersMessage::Wrapper("Number".to_string()),
+          hint: NoNewWrappersHint::CallWithoutNew("Number".to_string()),
+        }
+      ],
+      "if (new Boolean(false)) {}": [
+        {
+          col: 4,
+          message: NoNewWrappersMessage::Wrapper("Boolean".to_string()),
+          hint: NoNewWrappersHint::CallWithoutNew("Boolean".to_string()),
+        }
+      ],
+      "function f() { return new String; }": [
+        {
+          col: 22,
+          message: NoNewWrappersMessage::Wrapper("String".to_string()),
+          hint: NoNewWrappersHint::CallWithoutNew("String".to_string()),
+        }
+      ],
+    };
+  }
+}
@@ -5,10 +5,13 @@ use swc_common::Span;
 use swc_ecmascript::ast::CallExpr;
 use swc_ecmascript::ast::Expr;
 use swc_ecmascript::ast::ExprOrSuper;
+use swc_ecmascript::ast::Ident;
 use swc_ecmascript::ast::NewExpr;
+use swc_ecmascript::utils::ident::IdentLike;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
 
 pub struct NoObjCalls;
 
@@ -39,6 +42,29 @@ impl LintRule for NoObjCalls {
     let mut visitor = NoObjCallsVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows calling built-in global objects like functions
+
+`Math`, `JSON`, `Reflect` and `Atomics` are plain objects holding functions,
+not constructors or functions themselves. Calling them, with or without `new`,
+throws a `TypeError`.
+
+### Invalid:
+```typescript
+const math = Math();
+const json = new JSON();
+const reflect = Reflect();
+```
+
+### Valid:
+```typescript
+const area = Math.PI * r * r;
+const obj = JSON.parse("{}");
+const value = Reflect.get(obj, "x");
+```
+"#
+  }
 }
 
 struct NoObjCallsVisitor<'c> {
@@ -50,15 +76,16 @@ impl<'c> NoObjCallsVisitor<'c> {
     Self { context }
   }
 
-  fn check_callee(&mut self, callee_name: impl AsRef<str>, span: Span) {
-    let callee_name = callee_name.as_ref();
+  fn check_callee(&mut self, callee: &Ident, span: Span) {
+    let callee_name = callee.sym.as_ref();
     match callee_name {
       "Math" | "JSON" | "Reflect" | "Atomics" => {
-        self.context.add_diagnostic(
-          span,
-          "no-obj-calls",
-          get_message(callee_name),
-        );
+        // The global may be shadowed by a local binding
+        if self.context.scope().var(&callee.to_id()).is_none() {
+          self
+            .context
+            .add_diagnostic(span, CODE, get_message(callee_name));
+        }
       }
       _ => {}
     }
@@ -71,15 +98,17 @@ impl<'c> Visit for NoObjCallsVisitor<'c> {
   fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
     if let ExprOrSuper::Expr(expr) = &call_expr.callee {
       if let Expr::Ident(ident) = expr.as_ref() {
-        self.check_callee(&ident.sym, call_expr.span);
+        self.check_callee(ident, call_expr.span);
       }
     }
+    call_expr.visit_children_with(self);
   }
 
   fn visit_new_expr(&mut self, new_expr: &NewExpr, _parent: &dyn Node) {
     if let Expr::Ident(ident) = &*new_expr.callee {
-      self.check_callee(&ident.sym, new_expr.span);
+      self.check_callee(ident, new_expr.span);
     }
+    new_expr.visit_children_with(self);
   }
 }
 
@@ -95,6 +124,10 @@ mod tests {
       "JSON.parse(\"{}\");",
       "Reflect.get({ x: 1, y: 2 }, \"x\");",
       "Atomics.load(foo, 0);",
+      "Math.floor(x);",
+      "const Math = () => 1; Math();",
+      "function f(JSON) { return new JSON(); }",
+      "foo.Math();",
     };
   }
 
@@ -110,6 +143,8 @@ mod tests {
       "new Reflect();": [{col: 0, message: get_message("Reflect")}],
       "Atomics();": [{col: 0, message: get_message("Atomics")}],
       "new Atomics();": [{col: 0, message: get_message("Atomics")}],
+      "f(Math());": [{col: 2, message: get_message("Math")}],
+      "const a = () => JSON();": [{col: 16, message: get_message("JSON")}],
     }
   }
 }
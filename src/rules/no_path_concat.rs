@@ -0,0 +1,174 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use swc_common::Span;
+use swc_ecmascript::ast::{BinExpr, BinaryOp, Expr, Tpl};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoPathConcat;
+
+const CODE: &str = "no-path-concat";
+
+#[derive(Display)]
+enum NoPathConcatMessage {
+  #[display(fmt = "Paths built with string concatenation are not portable")]
+  Concat,
+}
+
+#[derive(Display)]
+enum NoPathConcatHint {
+  #[display(fmt = "Use `path.join()` or `path.resolve()` instead")]
+  UsePathJoin,
+}
+
+impl LintRule for NoPathConcat {
+  fn new() -> Box<Self> {
+    Box::new(NoPathConcat)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoPathConcatVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows string concatenation with `__dirname` and `__filename`
+
+Paths built by concatenating strings use whatever separator the string
+contains, which is wrong on some platforms. `path.join()` and `path.resolve()`
+use the separator of the platform the code runs on.
+
+### Invalid:
+```typescript
+const file = __dirname + "/foo.js";
+const other = `${__filename}.map`;
+```
+
+### Valid:
+```typescript
+const file = path.join(__dirname, "foo.js");
+const other = path.resolve(__dirname, "bar.js");
+```
+"#
+  }
+}
+
+struct NoPathConcatVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoPathConcatVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  /// Checks if `expr` is the global `__dirname` or `__filename`.
+  fn is_path_global(&self, expr: &Expr) -> bool {
+    match expr {
+      Expr::Ident(ident) => {
+        (ident.sym == *"__dirname" || ident.sym == *"__filename")
+          && self.context.scope().var(&ident.to_id()).is_none()
+      }
+      _ => false,
+    }
+  }
+
+  fn report(&mut self, span: Span) {
+    self.context.add_diagnostic_with_hint(
+      span,
+      CODE,
+      NoPathConcatMessage::Concat,
+      NoPathConcatHint::UsePathJoin,
+    );
+  }
+}
+
+impl<'c> Visit for NoPathConcatVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    if bin_expr.op == BinaryOp::Add
+      && (self.is_path_global(&bin_expr.left)
+        || self.is_path_global(&bin_expr.right))
+    {
+      self.report(bin_expr.span);
+    }
+    bin_expr.visit_children_with(self);
+  }
+
+  fn visit_tpl(&mut self, tpl: &Tpl, _parent: &dyn Node) {
+    // A template containing only the path itself doesn't concatenate anything
+    let has_text = tpl.quasis.iter().any(|quasi| !quasi.raw.value.is_empty());
+    if has_text && tpl.exprs.iter().any(|expr| self.is_path_global(expr)) {
+      self.report(tpl.span);
+    }
+    tpl.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_path_concat_valid() {
+    assert_lint_ok! {
+      NoPathConcat,
+      "const a = path.join(__dirname, 'foo');",
+      "const a = path.resolve(__filename, '..');",
+      "const a = __dirname;",
+      "const a = `${__dirname}`;",
+      "const a = dirname + '/foo';",
+      "const a = __dirname - 1;",
+      "const __dirname = '.'; const a = __dirname + '/foo';",
+    };
+  }
+
+  #[test]
+  fn no_path_concat_invalid() {
+    assert_lint_err! {
+      NoPathConcat,
+      "const a = __dirname + '/foo';": [
+        {
+          col: 10,
+          message: NoPathConcatMessage::Concat,
+          hint: NoPathConcatHint::UsePathJoin,
+        }
+      ],
+      "const a = '../' + __filename;": [
+        {
+          col: 10,
+          message: NoPathConcatMessage::Concat,
+          hint: NoPathConcatHint::UsePathJoin,
+        }
+      ],
+      "const a = `${__dirname}/foo`;": [
+        {
+          col: 10,
+          message: NoPathConcatMessage::Concat,
+          hint: NoPathConcatHint::UsePathJoin,
+        }
+      ],
+      "f(__dirname + '/a' + '/b');": [
+        {
+          col: 2,
+          message: NoPathConcatMessage::Concat,
+          hint: NoPathConcatHint::UsePathJoin,
+        }
+      ],
+    };
+  }
+}
@@ -5,12 +5,14 @@ use super::LintRule;
 use swc_ecmascript::ast::CallExpr;
 use swc_ecmascript::ast::Expr;
 use swc_ecmascript::ast::ExprOrSuper;
+use swc_ecmascript::ast::Lit;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
 
 const BANNED_PROPERTIES: &[&str] =
-  &["hasOwnProperty", "isPrototypeOf", "propertyIsEnumberable"];
+  &["hasOwnProperty", "isPrototypeOf", "propertyIsEnumerable"];
 
 pub struct NoPrototypeBuiltins;
 
@@ -23,6 +25,14 @@ fn get_message(prop: &str) -> String {
   )
 }
 
+fn get_hint(prop: &str) -> String {
+  format!(
+    "Use `Object.prototype.{}.call(obj, ...)` instead, which works even if \
+     `obj` overrides it or has no prototype",
+    prop
+  )
+}
+
 impl LintRule for NoPrototypeBuiltins {
   fn new() -> Box<Self> {
     Box::new(NoPrototypeBuiltins)
@@ -44,6 +54,30 @@ impl LintRule for NoPrototypeBuiltins {
     let mut visitor = NoPrototypeBuiltinsVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows calling `Object.prototype` builtins directly on objects
+
+An object can shadow `hasOwnProperty`, `isPrototypeOf` and
+`propertyIsEnumerable` with its own properties, and objects created with
+`Object.create(null)` don't have them at all. Calling them through
+`Object.prototype` works for every object.
+
+### Invalid:
+```typescript
+foo.hasOwnProperty("bar");
+foo.isPrototypeOf(bar);
+foo.propertyIsEnumerable("bar");
+```
+
+### Valid:
+```typescript
+Object.prototype.hasOwnProperty.call(foo, "bar");
+Object.prototype.isPrototypeOf.call(foo, bar);
+Object.prototype.propertyIsEnumerable.call(foo, "bar");
+```
+"#
+  }
 }
 
 struct NoPrototypeBuiltinsVisitor<'c> {
@@ -60,32 +94,39 @@ impl<'c> Visit for NoPrototypeBuiltinsVisitor<'c> {
   noop_visit_type!();
 
   fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
-    let member_expr = match &call_expr.callee {
-      ExprOrSuper::Expr(boxed_expr) => match &**boxed_expr {
-        Expr::Member(member_expr) => {
-          if member_expr.computed {
-            return;
-          }
-          member_expr
-        }
-        _ => return,
-      },
-      ExprOrSuper::Super(_) => return,
-    };
-
-    if let Expr::Ident(ident) = &*member_expr.prop {
-      let prop_name = ident.sym.as_ref();
-      if BANNED_PROPERTIES.contains(&prop_name) {
-        self.context.add_diagnostic(
-          call_expr.span,
-          CODE,
-          get_message(prop_name),
-        );
-      }
+    if let Some(prop_name) = banned_property(call_expr) {
+      self.context.add_diagnostic_with_hint(
+        call_expr.span,
+        CODE,
+        get_message(prop_name),
+        get_hint(prop_name),
+      );
     }
+    call_expr.visit_children_with(self);
   }
 }
 
+/// Returns the name of the builtin `call_expr` calls as a method, if any.
+fn banned_property(call_expr: &CallExpr) -> Option<&'static str> {
+  let member_expr = match &call_expr.callee {
+    ExprOrSuper::Expr(boxed_expr) => match &**boxed_expr {
+      Expr::Member(member_expr) => member_expr,
+      _ => return None,
+    },
+    ExprOrSuper::Super(_) => return None,
+  };
+
+  let prop_name = match &*member_expr.prop {
+    Expr::Ident(ident) if !member_expr.computed => ident.sym.as_ref(),
+    Expr::Lit(Lit::Str(s)) if member_expr.computed => s.value.as_ref(),
+    _ => return None,
+  };
+  BANNED_PROPERTIES
+    .iter()
+    .copied()
+    .find(|banned| *banned == prop_name)
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -97,19 +138,21 @@ mod tests {
       r#"
   Object.prototype.hasOwnProperty.call(foo, "bar");
   Object.prototype.isPrototypeOf.call(foo, "bar");
-  Object.prototype.propertyIsEnumberable.call(foo, "bar");
+  Object.prototype.propertyIsEnumerable.call(foo, "bar");
   Object.prototype.hasOwnProperty.apply(foo, ["bar"]);
   Object.prototype.isPrototypeOf.apply(foo, ["bar"]);
-  Object.prototype.propertyIsEnumberable.apply(foo, ["bar"]);
+  Object.prototype.propertyIsEnumerable.apply(foo, ["bar"]);
   hasOwnProperty(foo, "bar");
   isPrototypeOf(foo, "bar");
-  propertyIsEnumberable(foo, "bar");
+  propertyIsEnumerable(foo, "bar");
   ({}.hasOwnProperty.call(foo, "bar"));
   ({}.isPrototypeOf.call(foo, "bar"));
-  ({}.propertyIsEnumberable.call(foo, "bar"));
+  ({}.propertyIsEnumerable.call(foo, "bar"));
   ({}.hasOwnProperty.apply(foo, ["bar"]));
   ({}.isPrototypeOf.apply(foo, ["bar"]));
-  ({}.propertyIsEnumberable.apply(foo, ["bar"]));
+  ({}.propertyIsEnumerable.apply(foo, ["bar"]));
+  foo[hasOwnProperty]("bar");
+  foo.hasOwnProperties("bar");
       "#,
     };
   }
@@ -118,10 +161,48 @@ mod tests {
   fn no_prototype_builtins_invalid() {
     assert_lint_err! {
       NoPrototypeBuiltins,
-      "foo.hasOwnProperty('bar');": [{col: 0, message: get_message("hasOwnProperty")}],
-      "foo.isPrototypeOf('bar');": [{col: 0, message: get_message("isPrototypeOf")}],
-      "foo.propertyIsEnumberable('bar');": [{col: 0, message: get_message("propertyIsEnumberable")}],
-      "foo.bar.baz.hasOwnProperty('bar');": [{col: 0, message: get_message("hasOwnProperty")}],
+      "foo.hasOwnProperty('bar');": [
+        {
+          col: 0,
+          message: get_message("hasOwnProperty"),
+          hint: get_hint("hasOwnProperty"),
+        }
+      ],
+      "foo.isPrototypeOf('bar');": [
+        {
+          col: 0,
+          message: get_message("isPrototypeOf"),
+          hint: get_hint("isPrototypeOf"),
+        }
+      ],
+      "foo.propertyIsEnumerable('bar');": [
+        {
+          col: 0,
+          message: get_message("propertyIsEnumerable"),
+          hint: get_hint("propertyIsEnumerable"),
+        }
+      ],
+      "foo.bar.baz.hasOwnProperty('bar');": [
+        {
+          col: 0,
+          message: get_message("hasOwnProperty"),
+          hint: get_hint("hasOwnProperty"),
+        }
+      ],
+      "foo['hasOwnProperty']('bar');": [
+        {
+          col: 0,
+          message: get_message("hasOwnProperty"),
+          hint: get_hint("hasOwnProperty"),
+        }
+      ],
+      "if (check(foo.hasOwnProperty('bar'))) {}": [
+        {
+          col: 10,
+          message: get_message("hasOwnProperty"),
+          hint: get_hint("hasOwnProperty"),
+        }
+      ],
     }
   }
 }
@@ -0,0 +1,306 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  Decl, ExportDecl, ExportDefaultDecl, ExportDefaultExpr, ExportSpecifier,
+  Ident, NamedExport,
+};
+use swc_ecmascript::utils::find_ids;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+pub struct NoRestrictedExports {
+  options: NoRestrictedExportsOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoRestrictedExportsOptions {
+  /// Names that must not be exported.
+  pub restricted_named_exports: Vec<String>,
+  /// Disallow default exports.
+  pub restrict_default_exports: bool,
+}
+
+const CODE: &str = "no-restricted-exports";
+
+#[derive(Display)]
+enum NoRestrictedExportsMessage {
+  #[display(
+    fmt = "'{}' is restricted from being used as an exported name",
+    _0
+  )]
+  RestrictedName(String),
+  #[display(fmt = "Default exports are restricted")]
+  RestrictedDefault,
+}
+
+#[derive(Display)]
+enum NoRestrictedExportsHint {
+  #[display(fmt = "Export it under a different name")]
+  Rename,
+  #[display(fmt = "Use a named export instead")]
+  UseNamedExport,
+}
+
+impl NoRestrictedExports {
+  pub fn with_options(options: NoRestrictedExportsOptions) -> Box<Self> {
+    Box::new(NoRestrictedExports { options })
+  }
+}
+
+impl LintRule for NoRestrictedExports {
+  fn new() -> Box<Self> {
+    NoRestrictedExports::with_options(NoRestrictedExportsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "restrictedNamedExports": {
+          "type": "array",
+          "items": {
+            "type": "string"
+          },
+          "uniqueItems": true,
+          "description": "Names that must not be exported.",
+          "default": []
+        },
+        "restrictDefaultExports": {
+          "type": "boolean",
+          "description": "Disallow default exports.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoRestrictedExportsVisitor::new(context, &self.options);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows specified names in exports
+
+Some export names are confusing or are reserved by convention in a project.
+This rule reports exports whose name is listed in the `restrictedNamedExports`
+option. With the `restrictDefaultExports` option set, default exports are
+reported as well.
+
+Nothing is restricted by default.
+
+### Invalid:
+```typescript
+// With `restrictedNamedExports: ["then"]` and `restrictDefaultExports: true`
+export function then() {}
+export { foo as then };
+export default class Foo {}
+```
+
+### Valid:
+```typescript
+// With `restrictedNamedExports: ["then"]` and `restrictDefaultExports: true`
+export function next() {}
+export { foo };
+export class Foo {}
+```
+"#
+  }
+}
+
+struct NoRestrictedExportsVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o NoRestrictedExportsOptions,
+}
+
+impl<'c, 'o> NoRestrictedExportsVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o NoRestrictedExportsOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn check_name(&mut self, ident: &Ident) {
+    let name = ident.sym.as_ref();
+    if self
+      .options
+      .restricted_named_exports
+      .iter()
+      .any(|restricted| restricted == name)
+    {
+      self.context.add_diagnostic_with_hint(
+        ident.span,
+        CODE,
+        NoRestrictedExportsMessage::RestrictedName(name.to_string()),
+        NoRestrictedExportsHint::Rename,
+      );
+    }
+  }
+
+  fn check_default(&mut self, span: Span) {
+    if self.options.restrict_default_exports {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        NoRestrictedExportsMessage::RestrictedDefault,
+        NoRestrictedExportsHint::UseNamedExport,
+      );
+    }
+  }
+}
+
+impl<'c, 'o> Visit for NoRestrictedExportsVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_export_decl(
+    &mut self,
+    export_decl: &ExportDecl,
+    _parent: &dyn Node,
+  ) {
+    match &export_decl.decl {
+      Decl::Class(class_decl) => self.check_name(&class_decl.ident),
+      Decl::Fn(fn_decl) => self.check_name(&fn_decl.ident),
+      Decl::Var(var_decl) => {
+        for declarator in &var_decl.decls {
+          let idents: Vec<Ident> = find_ids(&declarator.name);
+          for ident in &idents {
+            self.check_name(ident);
+          }
+        }
+      }
+      Decl::TsEnum(ts_enum) => self.check_name(&ts_enum.id),
+      _ => {}
+    }
+  }
+
+  fn visit_named_export(
+    &mut self,
+    named_export: &NamedExport,
+    _parent: &dyn Node,
+  ) {
+    for specifier in &named_export.specifiers {
+      match specifier {
+        ExportSpecifier::Named(named) => {
+          let exported = named.exported.as_ref().unwrap_or(&named.orig);
+          if exported.sym == *"default" {
+            self.check_default(exported.span);
+          } else {
+            self.check_name(exported);
+          }
+        }
+        ExportSpecifier::Namespace(namespace) => {
+          self.check_name(&namespace.name)
+        }
+        ExportSpecifier::Default(default) => {
+          self.check_default(default.exported.span)
+        }
+      }
+    }
+  }
+
+  fn visit_export_default_decl(
+    &mut self,
+    export_default_decl: &ExportDefaultDecl,
+    _parent: &dyn Node,
+  ) {
+    self.check_default(export_default_decl.span);
+  }
+
+  fn visit_export_default_expr(
+    &mut self,
+    export_default_expr: &ExportDefaultExpr,
+    _parent: &dyn Node,
+  ) {
+    self.check_default(export_default_expr.span);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn restricted(names: &[&str], default: bool) -> Box<NoRestrictedExports> {
+    NoRestrictedExports::with_options(NoRestrictedExportsOptions {
+      restricted_named_exports: names.iter().map(|n| n.to_string()).collect(),
+      restrict_default_exports: default,
+    })
+  }
+
+  #[test]
+  fn no_restricted_exports_valid() {
+    assert_lint_ok! {
+      NoRestrictedExports,
+      "export const then = 1;",
+      "export default function () {}",
+    };
+
+    let sources = [
+      "export const foo = 1;",
+      "export function bar() {}",
+      "const then = 1; export { then as next };",
+      "export { then as next } from './foo.ts';",
+      "export class Foo {}",
+      "import then from './foo.ts';",
+      "const obj = { then: 1 };",
+    ];
+    for src in &sources {
+      assert_lint_ok_with_rule(restricted(&["then"], true), src);
+    }
+    assert_lint_ok_with_rule(
+      restricted(&["then"], false),
+      "export default class Foo {}",
+    );
+  }
+
+  #[test]
+  fn no_restricted_exports_invalid() {
+    let sources = [
+      ("export const then = 1;", 13),
+      ("export let { a: then } = obj;", 16),
+      ("export function then() {}", 16),
+      ("export class then {}", 13),
+      ("const foo = 1; export { foo as then };", 31),
+      ("const then = 1; export { then };", 25),
+      ("export { foo as then } from './foo.ts';", 16),
+    ];
+    for (src, col) in &sources {
+      assert_lint_err_on_line_with_rule(
+        restricted(&["then"], false),
+        src,
+        1,
+        *col,
+      );
+    }
+
+    let sources = [
+      ("export default class Foo {}", 0),
+      ("export default 42;", 0),
+      ("const foo = 1; export { foo as default };", 31),
+    ];
+    for (src, col) in &sources {
+      assert_lint_err_on_line_with_rule(restricted(&[], true), src, 1, *col);
+    }
+  }
+}
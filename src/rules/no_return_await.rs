@@ -0,0 +1,247 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use std::mem;
+use swc_ecmascript::ast::{
+  ArrowExpr, BlockStmtOrExpr, Expr, Function, ReturnStmt, TryStmt,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoReturnAwait;
+
+const CODE: &str = "no-return-await";
+
+#[derive(Display)]
+enum NoReturnAwaitMessage {
+  #[display(fmt = "Redundant use of `await` on a return value")]
+  Redundant,
+}
+
+#[derive(Display)]
+enum NoReturnAwaitHint {
+  #[display(fmt = "Remove `await` and return the promise directly")]
+  Remove,
+}
+
+impl LintRule for NoReturnAwait {
+  fn new() -> Box<Self> {
+    Box::new(NoReturnAwait)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoReturnAwaitVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows unnecessary `return await`
+
+The return value of an async function is always wrapped in a promise, so
+awaiting a promise just to return its result adds an extra microtask without
+changing what callers receive.
+
+The exception is a `return await` inside a `try` block, or inside a `catch`
+block that has a `finally` block. There, `await` makes rejections get caught
+or the `finally` block run after the promise settles, which is not redundant.
+
+### Invalid:
+```typescript
+async function foo() {
+  return await bar();
+}
+const baz = async () => await bar();
+```
+
+### Valid:
+```typescript
+async function foo() {
+  return bar();
+}
+async function baz() {
+  try {
+    return await bar();
+  } catch (e) {
+    handle(e);
+  }
+}
+```
+"#
+  }
+}
+
+struct NoReturnAwaitVisitor<'c> {
+  context: &'c mut Context,
+  /// Whether errors thrown at the current position are handled by an
+  /// enclosing `try` statement of the current function.
+  in_try: bool,
+}
+
+impl<'c> NoReturnAwaitVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self {
+      context,
+      in_try: false,
+    }
+  }
+
+  /// Reports `await` in positions whose value is the returned value.
+  fn check_returned_expr(&mut self, expr: &Expr) {
+    match expr {
+      Expr::Await(await_expr) => self.context.add_diagnostic_with_hint(
+        await_expr.span,
+        CODE,
+        NoReturnAwaitMessage::Redundant,
+        NoReturnAwaitHint::Remove,
+      ),
+      Expr::Paren(paren_expr) => self.check_returned_expr(&paren_expr.expr),
+      Expr::Cond(cond_expr) => {
+        self.check_returned_expr(&cond_expr.cons);
+        self.check_returned_expr(&cond_expr.alt);
+      }
+      Expr::Seq(seq_expr) => {
+        if let Some(last) = seq_expr.exprs.last() {
+          self.check_returned_expr(last);
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+impl<'c> Visit for NoReturnAwaitVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_return_stmt(
+    &mut self,
+    return_stmt: &ReturnStmt,
+    _parent: &dyn Node,
+  ) {
+    if !self.in_try {
+      if let Some(arg) = &return_stmt.arg {
+        self.check_returned_expr(arg);
+      }
+    }
+    return_stmt.visit_children_with(self);
+  }
+
+  fn visit_try_stmt(&mut self, try_stmt: &TryStmt, _parent: &dyn Node) {
+    let in_try = self.in_try;
+
+    self.in_try = true;
+    try_stmt.block.visit_with(try_stmt, self);
+
+    if let Some(handler) = &try_stmt.handler {
+      // A `finally` block runs only after an awaited promise settles
+      self.in_try = in_try || try_stmt.finalizer.is_some();
+      handler.visit_with(try_stmt, self);
+    }
+
+    self.in_try = in_try;
+    if let Some(finalizer) = &try_stmt.finalizer {
+      finalizer.visit_with(try_stmt, self);
+    }
+  }
+
+  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
+    let in_try = mem::replace(&mut self.in_try, false);
+    function.visit_children_with(self);
+    self.in_try = in_try;
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    let in_try = mem::replace(&mut self.in_try, false);
+    if let BlockStmtOrExpr::Expr(expr) = &arrow_expr.body {
+      self.check_returned_expr(expr);
+    }
+    arrow_expr.visit_children_with(self);
+    self.in_try = in_try;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_return_await_valid() {
+    assert_lint_ok! {
+      NoReturnAwait,
+      "async function foo() { return bar(); }",
+      "async function foo() { await bar(); return; }",
+      "async function foo() { const x = await bar(); return x; }",
+      "async function foo() { return (await bar()).baz; }",
+      "async function foo() { return await bar() + 1; }",
+      "async function foo() { try { return await bar(); } catch (e) {} }",
+      "async function foo() { try { if (a) { return await bar(); } } finally {} }",
+      "async function foo() { try {} catch (e) { return await bar(); } finally {} }",
+      "const foo = async () => (await bar()).baz;",
+      "const foo = async () => { await bar(); };",
+    };
+  }
+
+  #[test]
+  fn no_return_await_invalid() {
+    assert_lint_err! {
+      NoReturnAwait,
+      "async function foo() { return await bar(); }": [
+        {
+          col: 30,
+          message: NoReturnAwaitMessage::Redundant,
+          hint: NoReturnAwaitHint::Remove,
+        }
+      ],
+      "const foo = async () => await bar();": [
+        {
+          col: 24,
+          message: NoReturnAwaitMessage::Redundant,
+          hint: NoReturnAwaitHint::Remove,
+        }
+      ],
+      "async function foo() { return a ? await b : (await c); }": [
+        {
+          col: 34,
+          message: NoReturnAwaitMessage::Redundant,
+          hint: NoReturnAwaitHint::Remove,
+        },
+        {
+          col: 45,
+          message: NoReturnAwaitMessage::Redundant,
+          hint: NoReturnAwaitHint::Remove,
+        }
+      ],
+      "async function foo() { try {} catch (e) { return await bar(); } }": [
+        {
+          col: 49,
+          message: NoReturnAwaitMessage::Redundant,
+          hint: NoReturnAwaitHint::Remove,
+        }
+      ],
+      "async function foo() { try {} finally { return await bar(); } }": [
+        {
+          col: 47,
+          message: NoReturnAwaitMessage::Redundant,
+          hint: NoReturnAwaitHint::Remove,
+        }
+      ],
+      "async function foo() { try { return async () => await bar(); } catch {} }": [
+        {
+          col: 48,
+          message: NoReturnAwaitMessage::Redundant,
+          hint: NoReturnAwaitHint::Remove,
+        }
+      ],
+    };
+  }
+}
@@ -1,13 +1,19 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
+use swc_common::{Span, Spanned};
 use swc_ecmascript::ast::{
-  CallExpr, Class, Constructor, ExprOrSuper, Super, ThisExpr,
+  ArrowExpr, BinExpr, BinaryOp, BreakStmt, CallExpr, Class, CondExpr,
+  Constructor, DoWhileStmt, ExprOrSuper, ForInStmt, ForOfStmt, ForStmt,
+  Function, IfStmt, Stmt, Super, SwitchStmt, ThisExpr, WhileStmt,
 };
-use swc_ecmascript::visit::{noop_visit_type, Node, Visit};
+use swc_ecmascript::visit::{noop_visit_type, Node, Visit, VisitWith};
 
 pub struct NoThisBeforeSuper;
 
+const CODE: &str = "no-this-before-super";
+const MESSAGE: &str = "'this' / 'super' are not allowed before 'super()'.";
+
 impl LintRule for NoThisBeforeSuper {
   fn new() -> Box<Self> {
     Box::new(NoThisBeforeSuper)
@@ -18,7 +24,7 @@ impl LintRule for NoThisBeforeSuper {
   }
 
   fn code(&self) -> &'static str {
-    "no-this-before-super"
+    CODE
   }
 
   fn lint_program(
@@ -29,6 +35,57 @@ impl LintRule for NoThisBeforeSuper {
     let mut visitor = NoThisBeforeSuperVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows use of `this` or `super` before calling `super()` in constructors.
+
+The constructor of a derived class must call `super()` before using `this` or
+`super`, otherwise a `ReferenceError` is thrown. This rule reports uses of
+`this` and `super` that can run before `super()` has been called on some path
+through the constructor, e.g. when `super()` is only called in one branch of
+an `if` statement.
+
+### Invalid:
+```typescript
+class A extends B {
+  constructor() {
+    this.foo = 0;
+    super();
+  }
+}
+
+class C extends D {
+  constructor(bar: boolean) {
+    if (bar) {
+      super();
+    }
+    this.bar = bar;
+  }
+}
+```
+
+### Valid:
+```typescript
+class A extends B {
+  constructor() {
+    super();
+    this.foo = 0;
+  }
+}
+
+class C extends D {
+  constructor(bar: boolean) {
+    if (bar) {
+      super(1);
+    } else {
+      super(2);
+    }
+    this.bar = bar;
+  }
+}
+```
+"#
+  }
 }
 
 struct NoThisBeforeSuperVisitor<'c> {
@@ -86,7 +143,11 @@ impl<'a> Visit for ClassVisitor<'a> {
 
 struct ConstructorVisitor<'a> {
   context: &'a mut Context,
+  /// Whether `super()` has been called on every path to the current position.
   super_called: bool,
+  /// One entry per enclosing loop or `switch`, innermost last. The entry of
+  /// a `switch` holds the state at each `break` leaving it.
+  break_states: Vec<Option<Vec<bool>>>,
 }
 
 impl<'a> ConstructorVisitor<'a> {
@@ -94,17 +155,72 @@ impl<'a> ConstructorVisitor<'a> {
     Self {
       context,
       super_called: false,
+      break_states: Vec::new(),
+    }
+  }
+
+  /// Visits the body of a loop, restoring the state from before it since it
+  /// may not be executed.
+  fn visit_loop_body<N>(&mut self, body: &N, parent: &dyn Node)
+  where
+    N: VisitWith<Self>,
+  {
+    self.break_states.push(None);
+    self.visit_conditional(body, parent);
+    self.break_states.pop();
+  }
+
+  /// Visits `node`, which may not be executed, and restores the state from
+  /// before it. Returns whether `super()` is called on every path through it.
+  fn visit_conditional<N>(&mut self, node: &N, parent: &dyn Node) -> bool
+  where
+    N: VisitWith<Self>,
+  {
+    let super_called = self.super_called;
+    node.visit_with(parent, self);
+    std::mem::replace(&mut self.super_called, super_called)
+  }
+
+  fn stops_execution(&self, stmt: &Stmt) -> bool {
+    self
+      .context
+      .control_flow()
+      .meta(stmt.span().lo)
+      .map_or(false, |meta| meta.stops_execution())
+  }
+
+  fn report(&mut self, span: Span) {
+    if !self.super_called {
+      self.context.add_diagnostic(span, CODE, MESSAGE);
     }
   }
 }
 
 impl<'a> Visit for ConstructorVisitor<'a> {
+  noop_visit_type!();
+
   fn visit_class(&mut self, class: &Class, parent: &dyn Node) {
     let mut class_visitor =
       ClassVisitor::new(self.context, class.super_class.is_some());
     swc_ecmascript::visit::visit_class(&mut class_visitor, class, parent);
   }
 
+  // Functions have their own `this`, and arrow functions may be called after
+  // `super()`, so only classes inside them are checked.
+  fn visit_function(&mut self, function: &Function, parent: &dyn Node) {
+    let mut class_visitor = ClassVisitor::new(self.context, false);
+    swc_ecmascript::visit::visit_function(&mut class_visitor, function, parent);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, parent: &dyn Node) {
+    let mut class_visitor = ClassVisitor::new(self.context, false);
+    swc_ecmascript::visit::visit_arrow_expr(
+      &mut class_visitor,
+      arrow_expr,
+      parent,
+    );
+  }
+
   fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
     for arg in &call_expr.args {
       self.visit_expr(&*arg.expr, call_expr);
@@ -117,24 +233,142 @@ impl<'a> Visit for ConstructorVisitor<'a> {
   }
 
   fn visit_this_expr(&mut self, this_expr: &ThisExpr, _parent: &dyn Node) {
-    if !self.super_called {
-      self.context.add_diagnostic(
-        this_expr.span,
-        "no-this-before-super",
-        "'this' / 'super' are not allowed before 'super()'.",
-      );
-    }
+    self.report(this_expr.span);
   }
 
   fn visit_super(&mut self, sup: &Super, _parent: &dyn Node) {
-    if !self.super_called {
-      self.context.add_diagnostic(
-        sup.span,
-        "no-this-before-super",
-        "'this' / 'super' are not allowed before 'super()'.",
-      );
+    self.report(sup.span);
+  }
+
+  fn visit_if_stmt(&mut self, if_stmt: &IfStmt, _parent: &dyn Node) {
+    if_stmt.test.visit_with(if_stmt, self);
+    let cons_called = self.visit_conditional(&if_stmt.cons, if_stmt);
+    let alt_called = match &if_stmt.alt {
+      Some(alt) => self.visit_conditional(alt, if_stmt),
+      None => self.super_called,
+    };
+
+    // A branch that returns or throws doesn't lead to the code after the `if`
+    let cons_stops = self.stops_execution(&if_stmt.cons);
+    let alt_stops = if_stmt
+      .alt
+      .as_ref()
+      .map_or(false, |alt| self.stops_execution(alt));
+    self.super_called = match (cons_stops, alt_stops) {
+      (true, false) => alt_called,
+      (false, true) => cons_called,
+      _ => cons_called && alt_called,
+    };
+  }
+
+  fn visit_cond_expr(&mut self, cond_expr: &CondExpr, _parent: &dyn Node) {
+    cond_expr.test.visit_with(cond_expr, self);
+    let cons_called = self.visit_conditional(&cond_expr.cons, cond_expr);
+    let alt_called = self.visit_conditional(&cond_expr.alt, cond_expr);
+    self.super_called = cons_called && alt_called;
+  }
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    bin_expr.left.visit_with(bin_expr, self);
+    if matches!(
+      bin_expr.op,
+      BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing
+    ) {
+      self.visit_conditional(&bin_expr.right, bin_expr);
+    } else {
+      bin_expr.right.visit_with(bin_expr, self);
     }
   }
+
+  fn visit_switch_stmt(
+    &mut self,
+    switch_stmt: &SwitchStmt,
+    _parent: &dyn Node,
+  ) {
+    switch_stmt.discriminant.visit_with(switch_stmt, self);
+    let super_called = self.super_called;
+    self.break_states.push(Some(Vec::new()));
+    // Any case may be jumped to directly
+    for case in &switch_stmt.cases {
+      self.super_called = super_called;
+      case.visit_with(switch_stmt, self);
+    }
+    let breaks = self.break_states.pop().flatten().unwrap_or_default();
+
+    // The code after the switch is reached by a `break`, by falling out of
+    // the last case, or, without a `default`, when no case matches
+    let falls_out = switch_stmt.cases.last().map_or(false, |case| {
+      case
+        .cons
+        .last()
+        .map_or(true, |stmt| !self.stops_execution(stmt))
+    });
+    let has_default = switch_stmt.cases.iter().any(|case| case.test.is_none());
+    self.super_called = breaks.iter().all(|called| *called)
+      && (!falls_out || self.super_called)
+      && (has_default || super_called);
+  }
+
+  fn visit_break_stmt(&mut self, break_stmt: &BreakStmt, _parent: &dyn Node) {
+    // A labeled `break` may leave a switch from inside a loop, so it's
+    // counted for the innermost switch as well
+    let states = if break_stmt.label.is_some() {
+      self.break_states.iter_mut().rev().find_map(Option::as_mut)
+    } else {
+      self.break_states.last_mut().and_then(Option::as_mut)
+    };
+    if let Some(states) = states {
+      states.push(self.super_called);
+    }
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _parent: &dyn Node) {
+    while_stmt.test.visit_with(while_stmt, self);
+    self.visit_loop_body(&while_stmt.body, while_stmt);
+  }
+
+  fn visit_do_while_stmt(
+    &mut self,
+    do_while_stmt: &DoWhileStmt,
+    _parent: &dyn Node,
+  ) {
+    // The body runs at least once
+    self.break_states.push(None);
+    do_while_stmt.body.visit_with(do_while_stmt, self);
+    self.break_states.pop();
+    do_while_stmt.test.visit_with(do_while_stmt, self);
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _parent: &dyn Node) {
+    for_stmt.init.visit_with(for_stmt, self);
+    for_stmt.test.visit_with(for_stmt, self);
+    let super_called = self.super_called;
+    self.break_states.push(None);
+    for_stmt.body.visit_with(for_stmt, self);
+    for_stmt.update.visit_with(for_stmt, self);
+    self.break_states.pop();
+    self.super_called = super_called;
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _parent: &dyn Node) {
+    for_in_stmt.right.visit_with(for_in_stmt, self);
+    let super_called = self.super_called;
+    self.break_states.push(None);
+    for_in_stmt.left.visit_with(for_in_stmt, self);
+    for_in_stmt.body.visit_with(for_in_stmt, self);
+    self.break_states.pop();
+    self.super_called = super_called;
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _parent: &dyn Node) {
+    for_of_stmt.right.visit_with(for_of_stmt, self);
+    let super_called = self.super_called;
+    self.break_states.push(None);
+    for_of_stmt.left.visit_with(for_of_stmt, self);
+    for_of_stmt.body.visit_with(for_of_stmt, self);
+    self.break_states.pop();
+    self.super_called = super_called;
+  }
 }
 
 #[cfg(test)]
@@ -414,4 +648,184 @@ class A extends B {
       8,
     );
   }
+
+  #[test]
+  fn no_this_before_super_branches() {
+    assert_lint_ok! {
+      NoThisBeforeSuper,
+      r#"
+class A extends B {
+  constructor(a) {
+    if (a) {
+      super(1);
+    } else {
+      super(2);
+    }
+    this.a = a;
+  }
+}
+      "#,
+      r#"
+class A extends B {
+  constructor(a) {
+    if (!a) {
+      throw new Error();
+    } else {
+      super();
+    }
+    this.a = a;
+  }
+}
+      "#,
+      r#"
+class A extends B {
+  constructor(a) {
+    a ? super(1) : super(2);
+    this.a = a;
+  }
+}
+      "#,
+      r#"
+class A extends B {
+  constructor(k) {
+    switch (k) {
+      case 1:
+        super(1);
+        break;
+      default:
+        super(2);
+    }
+    this.x = 1;
+  }
+}
+      "#,
+      r#"
+class A extends B {
+  constructor(k) {
+    switch (k) {
+      case 1:
+        return;
+      case 2:
+        for (const x of k) {
+          break;
+        }
+        super(2);
+        break;
+      default:
+        throw new Error();
+    }
+    this.x = 1;
+  }
+}
+      "#,
+      r#"
+class A extends B {
+  constructor() {
+    const f = () => this.a;
+    function g() { return this.b; }
+    super();
+  }
+}
+      "#,
+    };
+
+    assert_lint_err_on_line::<NoThisBeforeSuper>(
+      r#"
+class A extends B {
+  constructor(a) {
+    if (a) {
+      super();
+    }
+    this.a = a;
+  }
+}
+      "#,
+      7,
+      4,
+    );
+
+    assert_lint_err_on_line::<NoThisBeforeSuper>(
+      r#"
+class A extends B {
+  constructor(a) {
+    a && super();
+    this.a = a;
+  }
+}
+      "#,
+      5,
+      4,
+    );
+
+    assert_lint_err_on_line::<NoThisBeforeSuper>(
+      r#"
+class A extends B {
+  constructor(a) {
+    for (const x of a) {
+      super();
+    }
+    this.a = a;
+  }
+}
+      "#,
+      7,
+      4,
+    );
+
+    assert_lint_err_on_line::<NoThisBeforeSuper>(
+      r#"
+class A extends B {
+  constructor(a) {
+    switch (a) {
+      case 1:
+        super();
+      case 2:
+        this.a = a;
+    }
+  }
+}
+      "#,
+      8,
+      8,
+    );
+
+    assert_lint_err_on_line::<NoThisBeforeSuper>(
+      r#"
+class A extends B {
+  constructor(k) {
+    switch (k) {
+      case 1:
+        super(1);
+        break;
+    }
+    this.x = 1;
+  }
+}
+      "#,
+      9,
+      4,
+    );
+
+    assert_lint_err_on_line::<NoThisBeforeSuper>(
+      r#"
+class A extends B {
+  constructor(k) {
+    switch (k) {
+      case 1:
+        if (k) {
+          break;
+        }
+        super(1);
+        break;
+      default:
+        super(2);
+    }
+    this.x = 1;
+  }
+}
+      "#,
+      14,
+      4,
+    );
+  }
 }
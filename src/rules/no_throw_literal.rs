@@ -5,16 +5,19 @@ use swc_ecmascript::ast::{Expr, ThrowStmt};
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
 
 pub struct NoThrowLiteral;
 
+const CODE: &str = "no-throw-literal";
+
 impl LintRule for NoThrowLiteral {
   fn new() -> Box<Self> {
     Box::new(NoThrowLiteral)
   }
 
   fn code(&self) -> &'static str {
-    "no-throw-literal"
+    CODE
   }
 
   fn lint_program(
@@ -25,6 +28,33 @@ impl LintRule for NoThrowLiteral {
     let mut visitor = NoThrowLiteralVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows throwing literals and other values that can't be errors
+
+Only `Error` objects carry a stack trace showing where they were created, and
+code catching exceptions usually expects an `Error`. This rule reports `throw`
+statements whose argument is a literal, an object literal, a template literal
+or `undefined`.
+
+### Invalid:
+```typescript
+throw "error";
+throw 404;
+throw { message: "error" };
+throw `error: ${message}`;
+throw undefined;
+```
+
+### Valid:
+```typescript
+throw new Error("error");
+throw new NotFoundError();
+throw err;
+throw createError();
+```
+"#
+  }
 }
 
 struct NoThrowLiteralVisitor<'c> {
@@ -41,21 +71,24 @@ impl<'c> Visit for NoThrowLiteralVisitor<'c> {
   noop_visit_type!();
 
   fn visit_throw_stmt(&mut self, throw_stmt: &ThrowStmt, _parent: &dyn Node) {
-    match &*throw_stmt.arg {
-      Expr::Lit(_) => self.context.add_diagnostic(
-        throw_stmt.span,
-        "no-throw-literal",
-        "expected an error object to be thrown",
-      ),
-      Expr::Ident(ident) if ident.sym == *"undefined" => {
-        self.context.add_diagnostic(
-          throw_stmt.span,
-          "no-throw-literal",
-          "do not throw undefined",
-        )
-      }
-      _ => {}
+    if let Some(message) = not_error_message(&throw_stmt.arg) {
+      self.context.add_diagnostic(throw_stmt.span, CODE, message);
+    }
+    throw_stmt.visit_children_with(self);
+  }
+}
+
+/// Returns the message to report if `expr` can't evaluate to an error object.
+fn not_error_message(expr: &Expr) -> Option<&'static str> {
+  match expr {
+    Expr::Lit(_) | Expr::Object(_) | Expr::Tpl(_) => {
+      Some("expected an error object to be thrown")
+    }
+    Expr::Ident(ident) if ident.sym == *"undefined" => {
+      Some("do not throw undefined")
     }
+    Expr::Paren(paren_expr) => not_error_message(&paren_expr.expr),
+    _ => None,
   }
 }
 
@@ -69,6 +102,12 @@ mod tests {
     assert_lint_ok! {
       NoThrowLiteral,
       "throw e",
+      "throw new Error('kumiko')",
+      "throw new CustomError()",
+      "throw Error('kumiko')",
+      "throw createError()",
+      "throw err.cause",
+      "throw tag`kumiko`",
     };
   }
 
@@ -79,5 +118,9 @@ mod tests {
     assert_lint_err::<NoThrowLiteral>("throw 1096", 0);
     assert_lint_err::<NoThrowLiteral>("throw null", 0);
     assert_lint_err::<NoThrowLiteral>("throw undefined", 0);
+    assert_lint_err::<NoThrowLiteral>("throw { message: 'kumiko' }", 0);
+    assert_lint_err::<NoThrowLiteral>("throw `kumiko ${name}`", 0);
+    assert_lint_err::<NoThrowLiteral>("throw ('kumiko')", 0);
+    assert_lint_err::<NoThrowLiteral>("function f() { throw 'kumiko'; }", 15);
   }
 }
@@ -0,0 +1,265 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::LintFix;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_common::Spanned;
+use swc_ecmascript::ast::{Expr, Pat, VarDecl, VarDeclKind};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoUndefInit {
+  options: NoUndefInitOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoUndefInitOptions {
+  /// Also reports `const` declarations initialized to `undefined`. These are
+  /// never fixed, because `const` requires an initializer.
+  pub check_const: bool,
+}
+
+const CODE: &str = "no-undef-init";
+
+#[derive(Display)]
+enum NoUndefInitMessage {
+  #[display(fmt = "Variable is explicitly initialized to `undefined`")]
+  Unexpected,
+}
+
+#[derive(Display)]
+enum NoUndefInitFix {
+  #[display(fmt = "Remove the initializer")]
+  RemoveInitializer,
+}
+
+impl NoUndefInit {
+  pub fn with_options(options: NoUndefInitOptions) -> Box<Self> {
+    Box::new(NoUndefInit { options })
+  }
+}
+
+impl LintRule for NoUndefInit {
+  fn new() -> Box<Self> {
+    NoUndefInit::with_options(NoUndefInitOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "checkConst": {
+          "type": "boolean",
+          "description": "Also reports `const` declarations initialized to `undefined`.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor =
+      NoUndefInitVisitor::new(context, self.options.check_const);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows initializing variables to `undefined`
+
+Variables declared with `let` or `var` without an initializer are already
+`undefined`, so initializing them to `undefined` explicitly is redundant.
+
+Only `let` declarations are fixed. A `var` declaration without an initializer
+keeps the value of the variable when it's declared again or run again in a
+loop, so removing `= undefined` from it can change the behavior.
+
+### Invalid:
+```typescript
+let foo = undefined;
+var bar: string | undefined = undefined;
+```
+
+### Valid:
+```typescript
+let foo;
+var bar: string | undefined;
+const baz = undefined;
+```
+"#
+  }
+}
+
+struct NoUndefInitVisitor<'c> {
+  context: &'c mut Context,
+  check_const: bool,
+}
+
+impl<'c> NoUndefInitVisitor<'c> {
+  fn new(context: &'c mut Context, check_const: bool) -> Self {
+    Self {
+      context,
+      check_const,
+    }
+  }
+
+  fn is_undefined(&self, expr: &Expr) -> bool {
+    if let Expr::Ident(ident) = expr {
+      // `undefined` may be shadowed by a local binding
+      return ident.sym == *"undefined"
+        && self.context.scope().var(&ident.to_id()).is_none();
+    }
+    false
+  }
+}
+
+impl<'c> Visit for NoUndefInitVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_var_decl(&mut self, var_decl: &VarDecl, _parent: &dyn Node) {
+    let is_const = var_decl.kind == VarDeclKind::Const;
+    if !is_const || self.check_const {
+      for decl in &var_decl.decls {
+        let ident = match &decl.name {
+          Pat::Ident(ident) => ident,
+          _ => continue,
+        };
+        let init = match &decl.init {
+          Some(init) if self.is_undefined(init) => init,
+          _ => continue,
+        };
+
+        // A `var` isn't reset by a redeclaration or on each loop iteration,
+        // so removing its initializer can change the behavior
+        if var_decl.kind != VarDeclKind::Let {
+          self.context.add_diagnostic(
+            decl.span,
+            CODE,
+            NoUndefInitMessage::Unexpected,
+          );
+          continue;
+        }
+
+        // Keep the type annotation, if any, and remove ` = undefined`.
+        let name_end = match &ident.type_ann {
+          Some(type_ann) => type_ann.span.hi(),
+          None => ident.span.hi(),
+        };
+        let fix = LintFix {
+          description: NoUndefInitFix::RemoveInitializer.to_string(),
+          changes: vec![self
+            .context
+            .create_fix_change(init.span().with_lo(name_end), "")],
+          priority: 0,
+        };
+        self.context.add_diagnostic_with_fix(
+          decl.span,
+          CODE,
+          NoUndefInitMessage::Unexpected,
+          fix,
+        );
+      }
+    }
+
+    var_decl.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn no_undef_init_valid() {
+    assert_lint_ok! {
+      NoUndefInit,
+      "let foo;",
+      "var foo = null;",
+      "let foo = void 0;",
+      "const foo = undefined;",
+      "let [foo] = undefined;",
+      "function f(undefined) { let foo = undefined; }",
+    };
+  }
+
+  #[test]
+  fn no_undef_init_invalid() {
+    assert_lint_err! {
+      NoUndefInit,
+      "let foo = undefined;": [
+        {
+          col: 4,
+          message: NoUndefInitMessage::Unexpected,
+        }
+      ],
+      "var foo = undefined, bar = 1, baz = undefined;": [
+        {
+          col: 4,
+          message: NoUndefInitMessage::Unexpected,
+        },
+        {
+          col: 30,
+          message: NoUndefInitMessage::Unexpected,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_undef_init_fix() {
+    assert_lint_fix::<NoUndefInit>("let foo = undefined;", "let foo;");
+    assert_lint_fix::<NoUndefInit>(
+      "let foo: string | undefined = undefined;",
+      "let foo: string | undefined;",
+    );
+    assert_lint_fix::<NoUndefInit>(
+      "let foo = undefined, bar = 1, baz = undefined;",
+      "let foo, bar = 1, baz;",
+    );
+    // Removing the initializer of a `var` can change the behavior
+    assert_lint_fix::<NoUndefInit>(
+      "var foo = undefined, bar = 1, baz = undefined;",
+      "var foo = undefined, bar = 1, baz = undefined;",
+    );
+    assert_lint_fix::<NoUndefInit>(
+      "for (const a of b) { var foo = undefined; }",
+      "for (const a of b) { var foo = undefined; }",
+    );
+  }
+
+  #[test]
+  fn no_undef_init_check_const() {
+    let options = NoUndefInitOptions { check_const: true };
+    assert_lint_err_on_line_with_rule(
+      NoUndefInit::with_options(options.clone()),
+      "const foo = undefined;",
+      1,
+      6,
+    );
+    // `const` requires an initializer, so it is reported but never fixed.
+    assert_lint_fix_with_rule(
+      NoUndefInit::with_options(options),
+      "const foo = undefined;",
+      "const foo = undefined;",
+    );
+  }
+}
@@ -0,0 +1,414 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::mem;
+use swc_atoms::JsWord;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{
+  ArrowExpr, Class, ContinueStmt, DoWhileStmt, ForInStmt, ForOfStmt, ForStmt,
+  Function, LabeledStmt, Stmt, WhileStmt,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoUnreachableLoop {
+  options: NoUnreachableLoopOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoUnreachableLoopOptions {
+  /// Kinds of loops that are never reported.
+  pub ignore: Vec<LoopKind>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LoopKind {
+  While,
+  DoWhile,
+  For,
+  ForIn,
+  ForOf,
+}
+
+const CODE: &str = "no-unreachable-loop";
+
+#[derive(Display)]
+enum NoUnreachableLoopMessage {
+  #[display(fmt = "This loop can never reach a second iteration")]
+  Unreachable,
+}
+
+#[derive(Display)]
+enum NoUnreachableLoopHint {
+  #[display(fmt = "Use an `if` statement if the body should only run once")]
+  UseIf,
+}
+
+impl NoUnreachableLoop {
+  pub fn with_options(options: NoUnreachableLoopOptions) -> Box<Self> {
+    Box::new(NoUnreachableLoop { options })
+  }
+}
+
+impl LintRule for NoUnreachableLoop {
+  fn new() -> Box<Self> {
+    NoUnreachableLoop::with_options(NoUnreachableLoopOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "ignore": {
+          "type": "array",
+          "items": {
+            "type": "string",
+            "enum": ["while", "doWhile", "for", "forIn", "forOf"]
+          },
+          "uniqueItems": true,
+          "description": "Kinds of loops that are never reported.",
+          "default": []
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoUnreachableLoopVisitor::new(context, &self.options);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows loops whose body can only run once
+
+A loop whose body always returns, throws or breaks out of it before reaching
+the end never gets to a second iteration, which makes it an `if` statement in
+disguise. This is usually a mistake, like a `return` meant to be inside a
+condition.
+
+The `ignore` option takes the kinds of loops to skip: `"while"`, `"doWhile"`,
+`"for"`, `"forIn"` and `"forOf"`.
+
+### Invalid:
+```typescript
+for (const item of items) {
+  if (item.done) {
+    count++;
+  }
+  return count;
+}
+
+while (queue.length) {
+  process(queue.pop());
+  break;
+}
+```
+
+### Valid:
+```typescript
+for (const item of items) {
+  if (item.done) {
+    return item;
+  }
+}
+
+while (queue.length) {
+  if (!process(queue.pop())) {
+    continue;
+  }
+  break;
+}
+```
+"#
+  }
+}
+
+struct NoUnreachableLoopVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o NoUnreachableLoopOptions,
+  /// Labels of the loop about to be visited, e.g. `outer` in
+  /// `outer: for (;;) {}`.
+  pending_labels: Vec<JsWord>,
+}
+
+impl<'c, 'o> NoUnreachableLoopVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o NoUnreachableLoopOptions,
+  ) -> Self {
+    Self {
+      context,
+      options,
+      pending_labels: Vec::new(),
+    }
+  }
+
+  fn check_loop(&mut self, kind: LoopKind, span: Span, body: &Stmt) {
+    let labels = mem::take(&mut self.pending_labels);
+    if self.options.ignore.contains(&kind) {
+      return;
+    }
+    if self.always_exits(body) && !has_continue(body, &labels) {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        NoUnreachableLoopMessage::Unreachable,
+        NoUnreachableLoopHint::UseIf,
+      );
+    }
+  }
+
+  /// Checks if every path through `body` returns, throws or breaks.
+  fn always_exits(&self, body: &Stmt) -> bool {
+    match body {
+      // The control flow of the body itself is overwritten by the loop, so
+      // look at its statements
+      Stmt::Block(block_stmt) => block_stmt.stmts.iter().any(|stmt| {
+        self
+          .context
+          .control_flow()
+          .meta(stmt.span().lo)
+          .map_or(false, |meta| meta.stops_execution())
+      }),
+      Stmt::Return(_) | Stmt::Throw(_) | Stmt::Break(_) => true,
+      _ => false,
+    }
+  }
+}
+
+impl<'c, 'o> Visit for NoUnreachableLoopVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_labeled_stmt(
+    &mut self,
+    labeled_stmt: &LabeledStmt,
+    _parent: &dyn Node,
+  ) {
+    match &*labeled_stmt.body {
+      Stmt::For(_)
+      | Stmt::ForIn(_)
+      | Stmt::ForOf(_)
+      | Stmt::While(_)
+      | Stmt::DoWhile(_)
+      | Stmt::Labeled(_) => {
+        self.pending_labels.push(labeled_stmt.label.sym.clone())
+      }
+      _ => self.pending_labels.clear(),
+    }
+    labeled_stmt.visit_children_with(self);
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _parent: &dyn Node) {
+    self.check_loop(LoopKind::For, for_stmt.span, &for_stmt.body);
+    for_stmt.visit_children_with(self);
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _parent: &dyn Node) {
+    self.check_loop(LoopKind::ForIn, for_in_stmt.span, &for_in_stmt.body);
+    for_in_stmt.visit_children_with(self);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _parent: &dyn Node) {
+    self.check_loop(LoopKind::ForOf, for_of_stmt.span, &for_of_stmt.body);
+    for_of_stmt.visit_children_with(self);
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _parent: &dyn Node) {
+    self.check_loop(LoopKind::While, while_stmt.span, &while_stmt.body);
+    while_stmt.visit_children_with(self);
+  }
+
+  fn visit_do_while_stmt(
+    &mut self,
+    do_while_stmt: &DoWhileStmt,
+    _parent: &dyn Node,
+  ) {
+    self.check_loop(LoopKind::DoWhile, do_while_stmt.span, &do_while_stmt.body);
+    do_while_stmt.visit_children_with(self);
+  }
+}
+
+/// Checks if `body` contains a `continue` of the loop labeled with `labels`.
+fn has_continue(body: &Stmt, labels: &[JsWord]) -> bool {
+  let mut finder = ContinueFinder {
+    labels,
+    loop_depth: 0,
+    found: false,
+  };
+  body.visit_with(body, &mut finder);
+  finder.found
+}
+
+struct ContinueFinder<'l> {
+  labels: &'l [JsWord],
+  loop_depth: usize,
+  found: bool,
+}
+
+impl<'l> ContinueFinder<'l> {
+  fn visit_nested_loop<N: VisitWith<Self>>(&mut self, node: &N) {
+    self.loop_depth += 1;
+    node.visit_children_with(self);
+    self.loop_depth -= 1;
+  }
+}
+
+impl<'l> Visit for ContinueFinder<'l> {
+  noop_visit_type!();
+
+  fn visit_continue_stmt(
+    &mut self,
+    continue_stmt: &ContinueStmt,
+    _parent: &dyn Node,
+  ) {
+    match &continue_stmt.label {
+      Some(label) => self.found |= self.labels.contains(&label.sym),
+      None => self.found |= self.loop_depth == 0,
+    }
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _parent: &dyn Node) {
+    self.visit_nested_loop(for_stmt);
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _parent: &dyn Node) {
+    self.visit_nested_loop(for_in_stmt);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _parent: &dyn Node) {
+    self.visit_nested_loop(for_of_stmt);
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _parent: &dyn Node) {
+    self.visit_nested_loop(while_stmt);
+  }
+
+  fn visit_do_while_stmt(
+    &mut self,
+    do_while_stmt: &DoWhileStmt,
+    _parent: &dyn Node,
+  ) {
+    self.visit_nested_loop(do_while_stmt);
+  }
+
+  // `continue` can't cross function boundaries
+  fn visit_function(&mut self, _function: &Function, _parent: &dyn Node) {}
+
+  fn visit_arrow_expr(&mut self, _arrow_expr: &ArrowExpr, _parent: &dyn Node) {}
+
+  fn visit_class(&mut self, _class: &Class, _parent: &dyn Node) {}
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn no_unreachable_loop_valid() {
+    assert_lint_ok! {
+      NoUnreachableLoop,
+      "for (let i = 0; i < 10; i++) { foo(i); }",
+      "for (const x of xs) { if (x) { return x; } }",
+      "while (a) { if (b) break; foo(); }",
+      "while (a) { if (b) continue; return; }",
+      "do { if (a) { continue; } break; } while (b);",
+      "for (const x in obj) { try { return foo(x); } catch (e) {} }",
+      "outer: for (;;) { for (const x of xs) { if (x) continue outer; } return; }",
+      "for (;;) { for (const x of xs) { if (x) break; } }",
+      "for (;;) { const f = () => { return; }; }",
+      "while (a) { switch (b) { case 1: break; default: foo(); } }",
+      "while (a) foo();",
+    };
+
+    let options = NoUnreachableLoopOptions {
+      ignore: vec![LoopKind::ForOf, LoopKind::While],
+    };
+    let sources = ["for (const x of xs) { return x; }", "while (a) { break; }"];
+    for src in &sources {
+      assert_lint_ok_with_rule(
+        NoUnreachableLoop::with_options(options.clone()),
+        src,
+      );
+    }
+  }
+
+  #[test]
+  fn no_unreachable_loop_invalid() {
+    assert_lint_err! {
+      NoUnreachableLoop,
+      "function f() { for (let i = 0; i < 10; i++) { return i; } }": [
+        {
+          col: 15,
+          message: NoUnreachableLoopMessage::Unreachable,
+          hint: NoUnreachableLoopHint::UseIf,
+        }
+      ],
+      "while (a) { foo(); break; }": [
+        {
+          col: 0,
+          message: NoUnreachableLoopMessage::Unreachable,
+          hint: NoUnreachableLoopHint::UseIf,
+        }
+      ],
+      "do { throw new Error(); } while (a);": [
+        {
+          col: 0,
+          message: NoUnreachableLoopMessage::Unreachable,
+          hint: NoUnreachableLoopHint::UseIf,
+        }
+      ],
+      "for (const x of xs) { if (x) { return 1; } else { break; } }": [
+        {
+          col: 0,
+          message: NoUnreachableLoopMessage::Unreachable,
+          hint: NoUnreachableLoopHint::UseIf,
+        }
+      ],
+      "for (const x in obj) break;": [
+        {
+          col: 0,
+          message: NoUnreachableLoopMessage::Unreachable,
+          hint: NoUnreachableLoopHint::UseIf,
+        }
+      ],
+      "outer: while (a) { for (;;) { continue outer; } }": [
+        {
+          col: 19,
+          message: NoUnreachableLoopMessage::Unreachable,
+          hint: NoUnreachableLoopHint::UseIf,
+        }
+      ],
+    };
+
+    let options = NoUnreachableLoopOptions {
+      ignore: vec![LoopKind::ForOf],
+    };
+    assert_lint_err_on_line_with_rule(
+      NoUnreachableLoop::with_options(options),
+      "while (a) { return; }",
+      1,
+      0,
+    );
+  }
+}
@@ -1,10 +1,15 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule};
+use swc_atoms::JsWord;
 use swc_common::Span;
-use swc_ecmascript::ast::Program;
-use swc_ecmascript::ast::Stmt::{Break, Continue, Return, Throw};
-use swc_ecmascript::ast::TryStmt;
-use swc_ecmascript::visit::{noop_visit_type, Node, VisitAll, VisitAllWith};
+use swc_ecmascript::ast::{
+  ArrowExpr, BreakStmt, Class, ContinueStmt, DoWhileStmt, ForInStmt, ForOfStmt,
+  ForStmt, Function, LabeledStmt, Program, ReturnStmt, SwitchStmt, ThrowStmt,
+  TryStmt, WhileStmt,
+};
+use swc_ecmascript::visit::{
+  noop_visit_type, Node, Visit, VisitAll, VisitAllWith, VisitWith,
+};
 
 pub struct NoUnsafeFinally;
 
@@ -92,19 +97,114 @@ impl<'c> VisitAll for NoUnsafeFinallyVisitor<'c> {
 
   fn visit_try_stmt(&mut self, try_stmt: &TryStmt, _parent: &dyn Node) {
     if let Some(finally_block) = &try_stmt.finalizer {
-      for stmt in &finally_block.stmts {
-        match stmt {
-          Break(_) => self.add_diagnostic(finally_block.span, "Break"),
-          Continue(_) => self.add_diagnostic(finally_block.span, "Continue"),
-          Return(_) => self.add_diagnostic(finally_block.span, "Return"),
-          Throw(_) => self.add_diagnostic(finally_block.span, "Throw"),
-          _ => {}
-        }
+      let mut finder = ControlFlowFinder::default();
+      finally_block.visit_children_with(&mut finder);
+      for stmt_type in finder.found {
+        self.add_diagnostic(finally_block.span, stmt_type);
       }
     }
   }
 }
 
+/// Collects the statements in a `finally` block that jump out of it.
+/// Functions and classes have their own control flow, and nested `finally`
+/// blocks are checked on their own.
+#[derive(Default)]
+struct ControlFlowFinder {
+  found: Vec<&'static str>,
+  /// Number of enclosing loops and `switch` statements, which unlabeled
+  /// `break` exits.
+  breakable_depth: usize,
+  /// Number of enclosing loops, which unlabeled `continue` exits.
+  loop_depth: usize,
+  labels: Vec<JsWord>,
+}
+
+impl ControlFlowFinder {
+  fn visit_loop<N: VisitWith<Self>>(&mut self, n: &N) {
+    self.breakable_depth += 1;
+    self.loop_depth += 1;
+    n.visit_children_with(self);
+    self.loop_depth -= 1;
+    self.breakable_depth -= 1;
+  }
+}
+
+impl Visit for ControlFlowFinder {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, _function: &Function, _parent: &dyn Node) {}
+
+  fn visit_arrow_expr(&mut self, _arrow_expr: &ArrowExpr, _parent: &dyn Node) {}
+
+  fn visit_class(&mut self, _class: &Class, _parent: &dyn Node) {}
+
+  fn visit_try_stmt(&mut self, try_stmt: &TryStmt, _parent: &dyn Node) {
+    try_stmt.block.visit_with(try_stmt, self);
+    try_stmt.handler.visit_with(try_stmt, self);
+  }
+
+  fn visit_return_stmt(&mut self, _stmt: &ReturnStmt, _parent: &dyn Node) {
+    self.found.push("Return");
+  }
+
+  fn visit_throw_stmt(&mut self, _stmt: &ThrowStmt, _parent: &dyn Node) {
+    self.found.push("Throw");
+  }
+
+  fn visit_break_stmt(&mut self, stmt: &BreakStmt, _parent: &dyn Node) {
+    let is_local = match &stmt.label {
+      Some(label) => self.labels.contains(&label.sym),
+      None => self.breakable_depth > 0,
+    };
+    if !is_local {
+      self.found.push("Break");
+    }
+  }
+
+  fn visit_continue_stmt(&mut self, stmt: &ContinueStmt, _parent: &dyn Node) {
+    let is_local = match &stmt.label {
+      Some(label) => self.labels.contains(&label.sym),
+      None => self.loop_depth > 0,
+    };
+    if !is_local {
+      self.found.push("Continue");
+    }
+  }
+
+  fn visit_labeled_stmt(&mut self, stmt: &LabeledStmt, _parent: &dyn Node) {
+    self.labels.push(stmt.label.sym.clone());
+    stmt.body.visit_with(stmt, self);
+    self.labels.pop();
+  }
+
+  fn visit_switch_stmt(&mut self, stmt: &SwitchStmt, _parent: &dyn Node) {
+    self.breakable_depth += 1;
+    stmt.visit_children_with(self);
+    self.breakable_depth -= 1;
+  }
+
+  fn visit_for_stmt(&mut self, stmt: &ForStmt, _parent: &dyn Node) {
+    self.visit_loop(stmt);
+  }
+
+  fn visit_for_in_stmt(&mut self, stmt: &ForInStmt, _parent: &dyn Node) {
+    self.visit_loop(stmt);
+  }
+
+  fn visit_for_of_stmt(&mut self, stmt: &ForOfStmt, _parent: &dyn Node) {
+    self.visit_loop(stmt);
+  }
+
+  fn visit_while_stmt(&mut self, stmt: &WhileStmt, _parent: &dyn Node) {
+    self.visit_loop(stmt);
+  }
+
+  fn visit_do_while_stmt(&mut self, stmt: &DoWhileStmt, _parent: &dyn Node) {
+    self.visit_loop(stmt);
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -154,6 +254,29 @@ let foo = function(a) {
   }
 };
       "#,
+      r#"
+function foo() {
+  try {}
+  finally {
+    const f = () => { return 1; };
+    class A { m() { throw new Error(); } }
+  }
+}
+      "#,
+      r#"
+function foo(xs) {
+  try {}
+  finally {
+    for (const x of xs) {
+      if (x) continue;
+      break;
+    }
+    inner: {
+      break inner;
+    }
+  }
+}
+      "#,
     };
   }
 
@@ -232,5 +355,48 @@ finally {
       5,
       10,
     );
+    assert_lint_err_on_line::<NoUnsafeFinally>(
+      r#"
+function foo(a) {
+  try {}
+  finally {
+    if (a) {
+      return 1;
+    }
+  }
+}
+     "#,
+      4,
+      10,
+    );
+    assert_lint_err_on_line::<NoUnsafeFinally>(
+      r#"
+outer: for (;;) {
+  try {}
+  finally {
+    for (const x of xs) {
+      break outer;
+    }
+  }
+}
+     "#,
+      4,
+      10,
+    );
+    assert_lint_err_on_line::<NoUnsafeFinally>(
+      r#"
+for (;;) {
+  try {}
+  finally {
+    switch (a) {
+      case 1:
+        continue;
+    }
+  }
+}
+     "#,
+      4,
+      10,
+    );
   }
 }
@@ -1,6 +1,10 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
+use anyhow::Context as _;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use swc_ecmascript::utils::find_ids;
 use swc_ecmascript::utils::ident::IdentLike;
 use swc_ecmascript::utils::Id;
@@ -21,17 +25,96 @@ use swc_ecmascript::{
 
 use std::collections::HashSet;
 
-pub struct NoUnusedVars;
+pub struct NoUnusedVars {
+  options: NoUnusedVarsOptions,
+  args_ignore_pattern: Option<Regex>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoUnusedVarsOptions {
+  /// Regular expression matching the names of function parameters that may
+  /// be left unused.
+  pub args_ignore_pattern: Option<String>,
+}
+
+impl NoUnusedVars {
+  /// Creates the rule with the given options. Fails if `argsIgnorePattern`
+  /// isn't a valid regular expression.
+  pub fn try_with_options(
+    options: NoUnusedVarsOptions,
+  ) -> anyhow::Result<Box<Self>> {
+    let args_ignore_pattern = match &options.args_ignore_pattern {
+      Some(pattern) => Some(
+        Regex::new(pattern)
+          .with_context(|| format!("Invalid argsIgnorePattern: {}", pattern))?,
+      ),
+      None => None,
+    };
+    Ok(Box::new(NoUnusedVars {
+      options,
+      args_ignore_pattern,
+    }))
+  }
+}
 
 impl LintRule for NoUnusedVars {
   fn new() -> Box<Self> {
-    Box::new(NoUnusedVars)
+    Box::new(NoUnusedVars {
+      options: NoUnusedVarsOptions::default(),
+      args_ignore_pattern: None,
+    })
   }
 
   fn code(&self) -> &'static str {
     "no-unused-vars"
   }
 
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "argsIgnorePattern": {
+          "type": "string",
+          "description": "Regular expression matching the names of function parameters that may be left unused."
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows variables, functions, imports and parameters that are never used
+
+Unused bindings are usually left over from refactoring, or a sign of a bug
+where another binding is used by mistake. Exported declarations count as used,
+and names starting with `_` are never reported.
+
+The `argsIgnorePattern` option is a regular expression. Function parameters
+whose names match it are not reported. An invalid pattern is an error when the
+rule is created.
+
+### Invalid:
+```typescript
+const unused = 1;
+import { foo } from "./foo.ts";
+function bar(x) {}
+bar();
+```
+
+### Valid:
+```typescript
+const used = 1;
+console.log(used);
+export function bar(_x) {}
+```
+"#
+  }
+
   fn lint_program(&self, context: &mut Context, program: &Program) {
     let mut collector = Collector {
       used_vars: Default::default(),
@@ -44,6 +127,7 @@ impl LintRule for NoUnusedVars {
       context,
       collector.used_vars,
       collector.used_types,
+      self.args_ignore_pattern.clone(),
     );
     program.visit_with(program, &mut visitor);
   }
@@ -242,6 +326,7 @@ struct NoUnusedVarVisitor<'c> {
   context: &'c mut Context,
   used_vars: HashSet<Id>,
   used_types: HashSet<Id>,
+  args_ignore_pattern: Option<Regex>,
 }
 
 impl<'c> NoUnusedVarVisitor<'c> {
@@ -249,11 +334,13 @@ impl<'c> NoUnusedVarVisitor<'c> {
     context: &'c mut Context,
     used_vars: HashSet<Id>,
     used_types: HashSet<Id>,
+    args_ignore_pattern: Option<Regex>,
   ) -> Self {
     Self {
       context,
       used_vars,
       used_types,
+      args_ignore_pattern,
     }
   }
 }
@@ -273,6 +360,15 @@ impl<'c> NoUnusedVarVisitor<'c> {
       );
     }
   }
+
+  fn handle_param_id(&mut self, ident: &Ident) {
+    if let Some(pattern) = &self.args_ignore_pattern {
+      if pattern.is_match(&ident.sym) {
+        return;
+      }
+    }
+    self.handle_id(ident);
+  }
 }
 
 impl<'c> Visit for NoUnusedVarVisitor<'c> {
@@ -280,7 +376,7 @@ impl<'c> Visit for NoUnusedVarVisitor<'c> {
     let declared_idents: Vec<Ident> = find_ids(&expr.params);
 
     for ident in declared_idents {
-      self.handle_id(&ident);
+      self.handle_param_id(&ident);
     }
     expr.body.visit_with(expr, self)
   }
@@ -351,7 +447,7 @@ impl<'c> Visit for NoUnusedVarVisitor<'c> {
     let declared_idents: Vec<Ident> = find_ids(&param.pat);
 
     for ident in declared_idents {
-      self.handle_id(&ident);
+      self.handle_param_id(&ident);
     }
     param.visit_children_with(self)
   }
@@ -1499,6 +1595,44 @@ export interface Bar extends baz.test {}
     );
   }
 
+  #[test]
+  fn no_unused_vars_args_ignore_pattern() {
+    let options = NoUnusedVarsOptions {
+      args_ignore_pattern: Some("^(_|ignored)".to_string()),
+    };
+    let sources = [
+      "export function f(_ignored) {}",
+      "export function f(ignoredArg, b) { return b; }",
+      "export const f = (ignored) => {};",
+      "export class A { m(ignored) {} }",
+    ];
+    for src in &sources {
+      assert_lint_ok_with_rule(
+        NoUnusedVars::try_with_options(options.clone()).unwrap(),
+        src,
+      );
+    }
+
+    let sources = [
+      ("export function f(a, ignored) {}", 18),
+      ("const ignored = 1;", 6),
+      ("export function f() { const ignoredVar = 1; }", 28),
+    ];
+    for (src, col) in &sources {
+      assert_lint_err_on_line_with_rule(
+        NoUnusedVars::try_with_options(options.clone()).unwrap(),
+        src,
+        1,
+        *col,
+      );
+    }
+
+    let options = NoUnusedVarsOptions {
+      args_ignore_pattern: Some("(".to_string()),
+    };
+    assert!(NoUnusedVars::try_with_options(options).is_err());
+  }
+
   #[test]
   #[ignore = "pure method analysis is not implemented yet"]
   fn no_unused_vars_err_array_methods() {
@@ -0,0 +1,384 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::LintFix;
+use derive_more::Display;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{
+  ArrayLit, Expr, ExprOrSpread, ForOfStmt, NewExpr, ObjectLit, Prop, PropName,
+  PropOrSpread,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoUselessSpread;
+
+const CODE: &str = "no-useless-spread";
+
+#[derive(Display)]
+enum NoUselessSpreadMessage {
+  #[display(fmt = "Spreading an array literal into an array is unnecessary")]
+  ArrayIntoArray,
+  #[display(fmt = "Spreading an object literal into an object is unnecessary")]
+  ObjectIntoObject,
+  #[display(
+    fmt = "Spreading an iterable into a new array is unnecessary here"
+  )]
+  IterableToArray,
+}
+
+#[derive(Display)]
+enum NoUselessSpreadFix {
+  #[display(fmt = "Inline the elements")]
+  InlineElements,
+  #[display(fmt = "Inline the properties")]
+  InlineProperties,
+  #[display(fmt = "Use the iterable directly")]
+  UseIterable,
+}
+
+/// Constructors that accept any iterable, so `new Set([...x])` is equivalent
+/// to `new Set(x)`.
+const ITERABLE_CONSTRUCTORS: &[&str] = &["Set", "Map", "WeakSet", "WeakMap"];
+
+impl LintRule for NoUselessSpread {
+  fn new() -> Box<Self> {
+    Box::new(NoUselessSpread)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoUselessSpreadVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows unnecessary spread of literals and iterables
+
+Spreading an array literal into another array literal, or an object literal
+into another object literal, allocates an intermediate value for nothing; the
+elements or properties can be written in place. Likewise, wrapping an iterable
+in a new array just to pass it to a constructor that accepts any iterable, or to
+iterate it with `for...of`, is redundant.
+
+### Invalid:
+```typescript
+const a = [...[1, 2], 3];
+const b = { ...{ foo: 1 }, bar: 2 };
+const c = new Set([...iterable]);
+for (const x of [...iterable]) {}
+```
+
+### Valid:
+```typescript
+const a = [1, 2, 3];
+const b = { foo: 1, bar: 2 };
+const c = new Set(iterable);
+for (const x of iterable) {}
+const d = [...iterable];
+```
+"#
+  }
+}
+
+struct NoUselessSpreadVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> NoUselessSpreadVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  fn report(
+    &mut self,
+    span: Span,
+    message: NoUselessSpreadMessage,
+    fix: Option<(NoUselessSpreadFix, String)>,
+  ) {
+    match fix {
+      Some((description, new_text)) => {
+        let fix = LintFix {
+          description: description.to_string(),
+          changes: vec![self.context.create_fix_change(span, new_text)],
+          priority: 0,
+        };
+        self
+          .context
+          .add_diagnostic_with_fix(span, CODE, message, fix);
+      }
+      None => self.context.add_diagnostic(span, CODE, message),
+    }
+  }
+
+  /// Returns the source text from the start of `first` to the end of `last`.
+  fn snippet_between(&self, first: Span, last: Span) -> Option<String> {
+    self.context.source_map.span_to_snippet(first.to(last)).ok()
+  }
+
+  /// `[...[1, 2]]` can be fixed to `[1, 2]` unless the inner array is empty,
+  /// has holes, because removing the spread would leave holes behind, or has
+  /// spread elements of its own.
+  fn inline_elements_fix(&self, inner: &ArrayLit) -> Option<String> {
+    let first = inner.elems.first()?.as_ref()?;
+    let last = inner.elems.last()?.as_ref()?;
+    let is_plain = inner
+      .elems
+      .iter()
+      .all(|elem| matches!(elem, Some(ExprOrSpread { spread: None, .. })));
+    if !is_plain {
+      return None;
+    }
+    self.snippet_between(first.expr.span(), last.expr.span())
+  }
+
+  /// `{ ...{ a: 1 } }` can be fixed to `{ a: 1 }` unless the inner object is
+  /// empty, has accessors or `__proto__`, which behave differently once they
+  /// are not spread, or has spread properties of its own.
+  fn inline_properties_fix(&self, inner: &ObjectLit) -> Option<String> {
+    let first = inner.props.first()?;
+    let last = inner.props.last()?;
+    let has_special_prop = inner.props.iter().any(|prop| match prop {
+      PropOrSpread::Prop(prop) => match &**prop {
+        Prop::Getter(_) | Prop::Setter(_) => true,
+        Prop::KeyValue(kv) => is_proto_key(&kv.key),
+        Prop::Shorthand(ident) => ident.sym == *"__proto__",
+        _ => false,
+      },
+      PropOrSpread::Spread(_) => true,
+    });
+    if has_special_prop {
+      return None;
+    }
+    self.snippet_between(first.span(), last.span())
+  }
+
+  /// Checks `[...x]` passed where any iterable is accepted.
+  fn check_iterable(&mut self, expr: &Expr) {
+    if let Expr::Array(array_lit) = expr {
+      if let [Some(ExprOrSpread {
+        spread: Some(_),
+        expr: iterable,
+      })] = array_lit.elems.as_slice()
+      {
+        let fix = self
+          .context
+          .source_map
+          .span_to_snippet(iterable.span())
+          .ok()
+          .map(|text| (NoUselessSpreadFix::UseIterable, text));
+        self.report(
+          array_lit.span,
+          NoUselessSpreadMessage::IterableToArray,
+          fix,
+        );
+      }
+    }
+  }
+}
+
+fn is_proto_key(key: &PropName) -> bool {
+  match key {
+    PropName::Ident(ident) => ident.sym == *"__proto__",
+    PropName::Str(s) => s.value == *"__proto__",
+    _ => false,
+  }
+}
+
+impl<'c> Visit for NoUselessSpreadVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_array_lit(&mut self, array_lit: &ArrayLit, _parent: &dyn Node) {
+    for elem in array_lit.elems.iter().flatten() {
+      if let (Some(dot3), Expr::Array(inner)) = (elem.spread, &*elem.expr) {
+        let fix = self
+          .inline_elements_fix(inner)
+          .map(|text| (NoUselessSpreadFix::InlineElements, text));
+        self.report(
+          dot3.to(inner.span),
+          NoUselessSpreadMessage::ArrayIntoArray,
+          fix,
+        );
+      }
+    }
+    array_lit.visit_children_with(self);
+  }
+
+  fn visit_object_lit(&mut self, object_lit: &ObjectLit, _parent: &dyn Node) {
+    for prop in &object_lit.props {
+      if let PropOrSpread::Spread(spread) = prop {
+        if let Expr::Object(inner) = &*spread.expr {
+          let fix = self
+            .inline_properties_fix(inner)
+            .map(|text| (NoUselessSpreadFix::InlineProperties, text));
+          self.report(
+            spread.dot3_token.to(inner.span),
+            NoUselessSpreadMessage::ObjectIntoObject,
+            fix,
+          );
+        }
+      }
+    }
+    object_lit.visit_children_with(self);
+  }
+
+  fn visit_new_expr(&mut self, new_expr: &NewExpr, _parent: &dyn Node) {
+    if let Expr::Ident(ident) = &*new_expr.callee {
+      let is_iterable_constructor = ITERABLE_CONSTRUCTORS
+        .contains(&ident.sym.as_ref())
+        && self.context.scope().var(&ident.to_id()).is_none();
+      if is_iterable_constructor {
+        if let Some([ExprOrSpread { spread: None, expr }]) =
+          new_expr.args.as_deref()
+        {
+          self.check_iterable(expr);
+        }
+      }
+    }
+    new_expr.visit_children_with(self);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _parent: &dyn Node) {
+    self.check_iterable(&for_of_stmt.right);
+    for_of_stmt.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn no_useless_spread_valid() {
+    assert_lint_ok! {
+      NoUselessSpread,
+      "const a = [1, 2, 3];",
+      "const a = [...foo];",
+      "const a = [...foo, ...bar];",
+      "const a = [...([1, 2])];",
+      "const b = { ...foo };",
+      "const b = { foo: { ...bar } };",
+      "const c = new Set(foo);",
+      "const c = new Set([...foo, bar]);",
+      "const c = new Set([foo]);",
+      "const c = new Foo([...foo]);",
+      "class Set {} new Set([...foo]);",
+      "for (const x of foo) {}",
+      "for (const x of [...foo, bar]) {}",
+      "foo([...bar]);",
+    };
+  }
+
+  #[test]
+  fn no_useless_spread_invalid() {
+    assert_lint_err! {
+      NoUselessSpread,
+      "const a = [...[1, 2]];": [
+        {
+          col: 11,
+          message: NoUselessSpreadMessage::ArrayIntoArray,
+        }
+      ],
+      "const a = [0, ...[1, 2], ...[]];": [
+        {
+          col: 14,
+          message: NoUselessSpreadMessage::ArrayIntoArray,
+        },
+        {
+          col: 25,
+          message: NoUselessSpreadMessage::ArrayIntoArray,
+        }
+      ],
+      "const b = { ...{ a: 1 } };": [
+        {
+          col: 12,
+          message: NoUselessSpreadMessage::ObjectIntoObject,
+        }
+      ],
+      "const c = new Set([...foo]);": [
+        {
+          col: 18,
+          message: NoUselessSpreadMessage::IterableToArray,
+        }
+      ],
+      "new WeakMap([...entries]);": [
+        {
+          col: 12,
+          message: NoUselessSpreadMessage::IterableToArray,
+        }
+      ],
+      "for (const x of [...foo]) {}": [
+        {
+          col: 16,
+          message: NoUselessSpreadMessage::IterableToArray,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_useless_spread_fix() {
+    assert_lint_fix::<NoUselessSpread>(
+      "const a = [...[1, 2]];",
+      "const a = [1, 2];",
+    );
+    assert_lint_fix::<NoUselessSpread>(
+      "const a = [0, ...[1, 2,], 3];",
+      "const a = [0, 1, 2, 3];",
+    );
+    assert_lint_fix::<NoUselessSpread>(
+      "const b = { ...{ a: 1, c }, d };",
+      "const b = { a: 1, c, d };",
+    );
+    assert_lint_fix::<NoUselessSpread>(
+      "const c = new Set([...foo]);",
+      "const c = new Set(foo);",
+    );
+    assert_lint_fix::<NoUselessSpread>(
+      "for (const x of [...foo.bar]) {}",
+      "for (const x of foo.bar) {}",
+    );
+  }
+
+  #[test]
+  fn no_useless_spread_unsafe_fix() {
+    // Inlining these would leave holes in the array or change how the
+    // properties are defined, so they are reported without a fix. Inner
+    // spreads aren't inlined either.
+    assert_lint_fix::<NoUselessSpread>(
+      "const a = [0, ...[], 1];",
+      "const a = [0, ...[], 1];",
+    );
+    assert_lint_fix::<NoUselessSpread>(
+      "const a = [...[1, , 2]];",
+      "const a = [...[1, , 2]];",
+    );
+    assert_lint_fix::<NoUselessSpread>(
+      "const a = [...[1, ...b]];",
+      "const a = [...[1, ...b]];",
+    );
+    assert_lint_fix::<NoUselessSpread>(
+      "const b = { ...{ a: 1, ...c }, d };",
+      "const b = { ...{ a: 1, ...c }, d };",
+    );
+    assert_lint_fix::<NoUselessSpread>(
+      "const b = { ...{ get a() { return 1; } } };",
+      "const b = { ...{ get a() { return 1; } } };",
+    );
+    assert_lint_fix::<NoUselessSpread>(
+      "const b = { ...{ __proto__: null } };",
+      "const b = { ...{ __proto__: null } };",
+    );
+  }
+}
@@ -0,0 +1,564 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::LintFix;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_common::{BytePos, Span, Spanned};
+use swc_ecmascript::ast::{
+  ArrowExpr, AssignPat, AssignPatProp, BlockStmtOrExpr, CallExpr, Expr,
+  ExprOrSpread, ExprOrSuper, Pat, ReturnStmt, YieldExpr,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoUselessUndefined {
+  options: NoUselessUndefinedOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoUselessUndefinedOptions {
+  /// Reports trailing `undefined` arguments of function calls.
+  pub check_arguments: bool,
+  /// Additional callees whose arguments are never checked, e.g. `"is"` or
+  /// `"assert.equal"`. A name without a dot matches both `is(...)` and
+  /// `foo.is(...)`.
+  pub ignore: Vec<String>,
+}
+
+impl Default for NoUselessUndefinedOptions {
+  fn default() -> Self {
+    Self {
+      check_arguments: true,
+      ignore: Vec::new(),
+    }
+  }
+}
+
+/// Callees for which passing `undefined` explicitly is meaningful, because
+/// they behave differently depending on the number of arguments.
+const IGNORED_CALLEES: &[&str] = &[
+  "Object.assign",
+  "assertEquals",
+  "assertNotEquals",
+  "assertStrictEquals",
+  "equal",
+  "strictEqual",
+  "notEqual",
+  "notStrictEqual",
+  "deepEqual",
+  "is",
+  "toBe",
+  "toEqual",
+  "toStrictEqual",
+  "toHaveBeenCalledWith",
+  "add",
+  "set",
+  "push",
+  "unshift",
+  "has",
+  "includes",
+  "indexOf",
+  "lastIndexOf",
+];
+
+const CODE: &str = "no-useless-undefined";
+
+#[derive(Display)]
+enum NoUselessUndefinedMessage {
+  #[display(fmt = "`undefined` is unnecessary here")]
+  Unnecessary,
+}
+
+#[derive(Display)]
+enum NoUselessUndefinedFix {
+  #[display(fmt = "Remove `undefined`")]
+  Remove,
+  #[display(fmt = "Replace with an empty block")]
+  EmptyBlock,
+}
+
+impl NoUselessUndefined {
+  pub fn with_options(options: NoUselessUndefinedOptions) -> Box<Self> {
+    Box::new(NoUselessUndefined { options })
+  }
+}
+
+impl LintRule for NoUselessUndefined {
+  fn new() -> Box<Self> {
+    NoUselessUndefined::with_options(NoUselessUndefinedOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "checkArguments": {
+          "type": "boolean",
+          "description": "Reports trailing `undefined` arguments of function calls.",
+          "default": true
+        },
+        "ignore": {
+          "type": "array",
+          "items": {
+            "type": "string"
+          },
+          "description": "Additional callees whose arguments are never checked, e.g. `\"is\"` or `\"assert.equal\"`.",
+          "default": []
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoUselessUndefinedVisitor::new(
+      context,
+      self.options.check_arguments,
+      &self.options.ignore,
+    );
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows explicitly using `undefined` where it is already implied
+
+Functions return `undefined` when they return without a value, missing
+arguments are `undefined`, and so are default values of parameters and
+destructured properties. Spelling out `undefined` in these places adds noise.
+
+Calls to functions whose behavior depends on the number of arguments, such as
+`assertEquals(x, undefined)` or `set.add(undefined)`, are not checked. More
+callees can be ignored with the `ignore` option, and argument checks can be
+disabled entirely by setting `checkArguments` to `false`.
+
+Defaults of parameters with a type annotation are reported without a fix, since
+removing the default would make the parameter required in TypeScript.
+
+### Invalid:
+```typescript
+function foo() {
+  return undefined;
+}
+function* bar() {
+  yield undefined;
+}
+const baz = () => undefined;
+function qux(a = undefined) {}
+const { a = undefined } = obj;
+foo(1, undefined);
+```
+
+### Valid:
+```typescript
+function foo() {
+  return;
+}
+function* bar() {
+  yield;
+}
+const baz = () => {};
+function qux(a) {}
+const { a } = obj;
+foo(1);
+assertEquals(value, undefined);
+```
+"#
+  }
+}
+
+struct NoUselessUndefinedVisitor<'c> {
+  context: &'c mut Context,
+  check_arguments: bool,
+  ignore: &'c [String],
+}
+
+impl<'c> NoUselessUndefinedVisitor<'c> {
+  fn new(
+    context: &'c mut Context,
+    check_arguments: bool,
+    ignore: &'c [String],
+  ) -> Self {
+    Self {
+      context,
+      check_arguments,
+      ignore,
+    }
+  }
+
+  fn is_undefined(&self, expr: &Expr) -> bool {
+    if let Expr::Ident(ident) = expr {
+      // `undefined` may be shadowed by a local binding
+      return ident.sym == *"undefined"
+        && self.context.scope().var(&ident.to_id()).is_none();
+    }
+    false
+  }
+
+  /// Reports `undefined` at `span`, fixing it by replacing `fix_span` with
+  /// `new_text`.
+  fn report(
+    &mut self,
+    span: Span,
+    fix_span: Span,
+    description: NoUselessUndefinedFix,
+    new_text: &str,
+  ) {
+    let fix = LintFix {
+      description: description.to_string(),
+      changes: vec![self.context.create_fix_change(fix_span, new_text)],
+      priority: 0,
+    };
+    self.context.add_diagnostic_with_fix(
+      span,
+      CODE,
+      NoUselessUndefinedMessage::Unnecessary,
+      fix,
+    );
+  }
+
+  fn is_ignored_callee(&self, callee: &ExprOrSuper) -> bool {
+    let (full_name, name) = match callee_names(callee) {
+      Some(names) => names,
+      None => return false,
+    };
+    IGNORED_CALLEES
+      .iter()
+      .copied()
+      .chain(self.ignore.iter().map(String::as_str))
+      .any(|ignored| {
+        if ignored.contains('.') {
+          ignored == full_name
+        } else {
+          ignored == name
+        }
+      })
+  }
+
+  fn is_removable_arg(&self, arg: &ExprOrSpread) -> bool {
+    arg.spread.is_none() && self.is_undefined(&arg.expr)
+  }
+}
+
+/// Returns the full dotted name and the last name of the callee, e.g.
+/// `("assert.equal", "equal")` for `assert.equal(...)`.
+fn callee_names(callee: &ExprOrSuper) -> Option<(String, String)> {
+  let callee = match callee {
+    ExprOrSuper::Expr(callee) => callee,
+    ExprOrSuper::Super(_) => return None,
+  };
+  match &**callee {
+    Expr::Ident(ident) => Some((ident.sym.to_string(), ident.sym.to_string())),
+    Expr::Member(member_expr) if !member_expr.computed => {
+      let prop = match &*member_expr.prop {
+        Expr::Ident(prop) => prop.sym.to_string(),
+        _ => return None,
+      };
+      let full_name = match &member_expr.obj {
+        ExprOrSuper::Expr(obj) => match &**obj {
+          Expr::Ident(obj) => format!("{}.{}", obj.sym, prop),
+          _ => prop.clone(),
+        },
+        ExprOrSuper::Super(_) => prop.clone(),
+      };
+      Some((full_name, prop))
+    }
+    _ => None,
+  }
+}
+
+/// Returns the end of `pat`, including its type annotation if any.
+/// Checks if `pat` has a TypeScript type annotation, like `a: number`.
+fn has_type_ann(pat: &Pat) -> bool {
+  match pat {
+    Pat::Ident(ident) => ident.type_ann.is_some(),
+    Pat::Array(array_pat) => array_pat.type_ann.is_some(),
+    Pat::Object(object_pat) => object_pat.type_ann.is_some(),
+    _ => false,
+  }
+}
+
+impl<'c> Visit for NoUselessUndefinedVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_return_stmt(
+    &mut self,
+    return_stmt: &ReturnStmt,
+    _parent: &dyn Node,
+  ) {
+    if let Some(arg) = &return_stmt.arg {
+      if self.is_undefined(arg) {
+        let keyword_end =
+          return_stmt.span.lo() + BytePos("return".len() as u32);
+        self.report(
+          arg.span(),
+          arg.span().with_lo(keyword_end),
+          NoUselessUndefinedFix::Remove,
+          "",
+        );
+      }
+    }
+    return_stmt.visit_children_with(self);
+  }
+
+  fn visit_yield_expr(&mut self, yield_expr: &YieldExpr, _parent: &dyn Node) {
+    if let Some(arg) = &yield_expr.arg {
+      // `yield* undefined` throws, so it is not the same as `yield*`
+      if !yield_expr.delegate && self.is_undefined(arg) {
+        let keyword_end = yield_expr.span.lo() + BytePos("yield".len() as u32);
+        self.report(
+          arg.span(),
+          arg.span().with_lo(keyword_end),
+          NoUselessUndefinedFix::Remove,
+          "",
+        );
+      }
+    }
+    yield_expr.visit_children_with(self);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    if let BlockStmtOrExpr::Expr(expr) = &arrow_expr.body {
+      if self.is_undefined(expr) {
+        self.report(
+          expr.span(),
+          expr.span(),
+          NoUselessUndefinedFix::EmptyBlock,
+          "{}",
+        );
+      }
+    }
+    arrow_expr.visit_children_with(self);
+  }
+
+  fn visit_assign_pat(&mut self, assign_pat: &AssignPat, _parent: &dyn Node) {
+    if self.is_undefined(&assign_pat.right) {
+      // Removing the default of a typed parameter makes it required, so it's
+      // reported without a fix
+      if assign_pat.type_ann.is_some() || has_type_ann(&assign_pat.left) {
+        self.context.add_diagnostic(
+          assign_pat.right.span(),
+          CODE,
+          NoUselessUndefinedMessage::Unnecessary,
+        );
+      } else {
+        self.report(
+          assign_pat.right.span(),
+          assign_pat.right.span().with_lo(assign_pat.left.span().hi()),
+          NoUselessUndefinedFix::Remove,
+          "",
+        );
+      }
+    }
+    assign_pat.visit_children_with(self);
+  }
+
+  fn visit_assign_pat_prop(
+    &mut self,
+    assign_pat_prop: &AssignPatProp,
+    _parent: &dyn Node,
+  ) {
+    if let Some(value) = &assign_pat_prop.value {
+      if self.is_undefined(value) {
+        self.report(
+          value.span(),
+          value.span().with_lo(assign_pat_prop.key.span.hi()),
+          NoUselessUndefinedFix::Remove,
+          "",
+        );
+      }
+    }
+    assign_pat_prop.visit_children_with(self);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    let args = &call_expr.args;
+    if self.check_arguments && !self.is_ignored_callee(&call_expr.callee) {
+      let mut first_removable = args.len();
+      while first_removable > 0
+        && self.is_removable_arg(&args[first_removable - 1])
+      {
+        first_removable -= 1;
+      }
+
+      if let Some(last) = args.last() {
+        if first_removable < args.len() {
+          let span = args[first_removable].expr.span().to(last.expr.span());
+          // Remove the preceding comma as well, if any.
+          let fix_span = match first_removable {
+            0 => span,
+            _ => span.with_lo(args[first_removable - 1].expr.span().hi()),
+          };
+          self.report(span, fix_span, NoUselessUndefinedFix::Remove, "");
+        }
+      }
+    }
+    call_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn no_useless_undefined_valid() {
+    assert_lint_ok! {
+      NoUselessUndefined,
+      "function f() { return; }",
+      "function f() { return null; }",
+      "function* f() { yield; }",
+      "function* f() { yield* undefined; }",
+      "const f = () => {};",
+      "const f = () => null;",
+      "function f(a) {}",
+      "function f(a = null) {}",
+      "const { a } = b;",
+      "const [a = 1] = b;",
+      "foo(1);",
+      "foo(undefined, 1);",
+      "foo(...undefined);",
+      "assertEquals(value, undefined);",
+      "set.add(undefined);",
+      "Object.assign(target, undefined);",
+      "function f(undefined) { return undefined; }",
+      "let undefined = 1; foo(undefined);",
+    };
+  }
+
+  #[test]
+  fn no_useless_undefined_invalid() {
+    assert_lint_err! {
+      NoUselessUndefined,
+      "function f() { return undefined; }": [
+        {
+          col: 22,
+          message: NoUselessUndefinedMessage::Unnecessary,
+        }
+      ],
+      "function* f() { yield undefined; }": [
+        {
+          col: 22,
+          message: NoUselessUndefinedMessage::Unnecessary,
+        }
+      ],
+      "const f = () => undefined;": [
+        {
+          col: 16,
+          message: NoUselessUndefinedMessage::Unnecessary,
+        }
+      ],
+      "function f(a = undefined) {}": [
+        {
+          col: 15,
+          message: NoUselessUndefinedMessage::Unnecessary,
+        }
+      ],
+      "const { a = undefined } = b;": [
+        {
+          col: 12,
+          message: NoUselessUndefinedMessage::Unnecessary,
+        }
+      ],
+      "const [a = undefined] = b;": [
+        {
+          col: 11,
+          message: NoUselessUndefinedMessage::Unnecessary,
+        }
+      ],
+      "foo(1, undefined, undefined);": [
+        {
+          col: 7,
+          message: NoUselessUndefinedMessage::Unnecessary,
+        }
+      ],
+      "foo.bar(undefined);": [
+        {
+          col: 8,
+          message: NoUselessUndefinedMessage::Unnecessary,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_useless_undefined_fix() {
+    assert_lint_fix::<NoUselessUndefined>(
+      "function f() { return undefined; }",
+      "function f() { return; }",
+    );
+    assert_lint_fix::<NoUselessUndefined>(
+      "function* f() { yield undefined; }",
+      "function* f() { yield; }",
+    );
+    assert_lint_fix::<NoUselessUndefined>(
+      "const f = () => undefined;",
+      "const f = () => {};",
+    );
+    assert_lint_fix::<NoUselessUndefined>(
+      "function f(a = undefined, b = undefined) {}",
+      "function f(a, b) {}",
+    );
+    // `b: number` would make the parameter required
+    assert_lint_fix::<NoUselessUndefined>(
+      "function f(a = undefined, b: number = undefined) {}",
+      "function f(a, b: number = undefined) {}",
+    );
+    assert_lint_fix::<NoUselessUndefined>(
+      "const f = ({ a }: Foo = undefined) => a;",
+      "const f = ({ a }: Foo = undefined) => a;",
+    );
+    assert_lint_fix::<NoUselessUndefined>(
+      "const { a = undefined } = b;",
+      "const { a } = b;",
+    );
+    assert_lint_fix::<NoUselessUndefined>(
+      "foo(1, undefined, undefined);",
+      "foo(1);",
+    );
+    assert_lint_fix::<NoUselessUndefined>("foo(undefined);", "foo();");
+  }
+
+  #[test]
+  fn no_useless_undefined_options() {
+    assert_lint_ok_with_rule(
+      NoUselessUndefined::with_options(NoUselessUndefinedOptions {
+        check_arguments: false,
+        ..Default::default()
+      }),
+      "foo(undefined);",
+    );
+    let options = NoUselessUndefinedOptions {
+      ignore: vec!["bar".to_string(), "assert.ok".to_string()],
+      ..Default::default()
+    };
+    assert_lint_ok_with_rule(
+      NoUselessUndefined::with_options(options.clone()),
+      "foo.bar(undefined); assert.ok(undefined);",
+    );
+    assert_lint_err_on_line_with_rule(
+      NoUselessUndefined::with_options(options),
+      "other.ok(undefined);",
+      1,
+      9,
+    );
+  }
+}
@@ -1,21 +1,37 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::Context;
 use super::LintRule;
+use derive_more::Display;
 use swc_ecmascript::ast::VarDecl;
 use swc_ecmascript::ast::VarDeclKind;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
 
 pub struct NoVar;
 
+const CODE: &str = "no-var";
+
+#[derive(Display)]
+enum NoVarMessage {
+  #[display(fmt = "`var` keyword is not allowed")]
+  Var,
+}
+
+#[derive(Display)]
+enum NoVarHint {
+  #[display(fmt = "Use `let` or `const` instead")]
+  UseLetOrConst,
+}
+
 impl LintRule for NoVar {
   fn new() -> Box<Self> {
     Box::new(NoVar)
   }
 
   fn code(&self) -> &'static str {
-    "no-var"
+    CODE
   }
 
   fn lint_program(
@@ -26,6 +42,31 @@ impl LintRule for NoVar {
     let mut visitor = NoVarVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the `var` keyword
+
+Variables declared with `var` are scoped to the whole function rather than the
+block they're declared in, and can be used before their declaration. `let` and
+`const` don't have these surprises.
+
+Ambient declarations like `declare var process: any;` describe globals defined
+elsewhere, so they're allowed.
+
+### Invalid:
+```typescript
+var count = 1;
+for (var i = 0; i < 10; i++) {}
+```
+
+### Valid:
+```typescript
+let count = 1;
+for (let i = 0; i < 10; i++) {}
+declare var process: any;
+```
+"#
+  }
 }
 
 struct NoVarVisitor<'c> {
@@ -42,13 +83,18 @@ impl<'c> Visit for NoVarVisitor<'c> {
   noop_visit_type!();
 
   fn visit_var_decl(&mut self, var_decl: &VarDecl, _parent: &dyn Node) {
+    if var_decl.declare {
+      return;
+    }
     if var_decl.kind == VarDeclKind::Var {
-      self.context.add_diagnostic(
+      self.context.add_diagnostic_with_hint(
         var_decl.span,
-        "no-var",
-        "`var` keyword is not allowed",
+        CODE,
+        NoVarMessage::Var,
+        NoVarHint::UseLetOrConst,
       );
     }
+    var_decl.visit_children_with(self);
   }
 }
 
@@ -57,11 +103,48 @@ mod tests {
   use super::*;
   use crate::test_util::*;
 
+  #[test]
+  fn no_var_valid() {
+    assert_lint_ok! {
+      NoVar,
+      "let x = 1;",
+      "const x = 1;",
+      "for (let i = 0; i < 10; i++) {}",
+      "declare var process: any;",
+      "declare global { var deno: any; }",
+      "declare module 'foo' { var bar: number; }",
+    };
+  }
+
   #[test]
   fn no_var_invalid() {
     assert_lint_err::<NoVar>(
       r#"var someVar = "someString"; const c = "c"; let a = "a";"#,
       0,
     );
+    assert_lint_err! {
+      NoVar,
+      "var x = 1;": [
+        {
+          col: 0,
+          message: NoVarMessage::Var,
+          hint: NoVarHint::UseLetOrConst,
+        }
+      ],
+      "for (var i = 0; i < 10; i++) {}": [
+        {
+          col: 5,
+          message: NoVarMessage::Var,
+          hint: NoVarHint::UseLetOrConst,
+        }
+      ],
+      "const f = function () { var x; };": [
+        {
+          col: 24,
+          message: NoVarMessage::Var,
+          hint: NoVarHint::UseLetOrConst,
+        }
+      ],
+    };
   }
 }
@@ -0,0 +1,226 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_ecmascript::ast::{Expr, ExprStmt, UnaryExpr, UnaryOp};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoVoid {
+  options: NoVoidOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct NoVoidOptions {
+  /// Allows `void` used as a statement, e.g. `void doStuff();`, which is a
+  /// common way of marking a promise as intentionally not awaited.
+  pub allow_as_statement: bool,
+}
+
+const CODE: &str = "no-void";
+
+#[derive(Display)]
+enum NoVoidMessage {
+  #[display(fmt = "`void` operator is not allowed")]
+  Unexpected,
+}
+
+#[derive(Display)]
+enum NoVoidHint {
+  #[display(fmt = "Use `undefined` instead")]
+  UseUndefined,
+}
+
+impl NoVoid {
+  pub fn with_options(options: NoVoidOptions) -> Box<Self> {
+    Box::new(NoVoid { options })
+  }
+}
+
+impl LintRule for NoVoid {
+  fn new() -> Box<Self> {
+    NoVoid::with_options(NoVoidOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "allowAsStatement": {
+          "type": "boolean",
+          "description": "Allows `void` used as a statement, e.g. `void doStuff();`.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor =
+      NoVoidVisitor::new(context, self.options.allow_as_statement);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows the use of the `void` operator
+
+The `void` operator evaluates an expression and returns `undefined`. It is
+rarely needed in modern code, where `undefined` can be used directly, and
+makes code harder to read.
+
+When `allowAsStatement` option is enabled, `void` used as a statement is
+allowed. This is useful to mark a promise as intentionally not awaited.
+
+### Invalid:
+```typescript
+const x = void 0;
+```
+```typescript
+function foo() {
+  return void bar();
+}
+```
+
+### Valid:
+```typescript
+const x = undefined;
+```
+```typescript
+// with `allowAsStatement` option
+void doStuff();
+```
+"#
+  }
+}
+
+struct NoVoidVisitor<'c> {
+  context: &'c mut Context,
+  allow_as_statement: bool,
+}
+
+impl<'c> NoVoidVisitor<'c> {
+  fn new(context: &'c mut Context, allow_as_statement: bool) -> Self {
+    Self {
+      context,
+      allow_as_statement,
+    }
+  }
+}
+
+impl<'c> Visit for NoVoidVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_expr_stmt(&mut self, expr_stmt: &ExprStmt, _parent: &dyn Node) {
+    if self.allow_as_statement {
+      if let Expr::Unary(unary_expr) = &*expr_stmt.expr {
+        if unary_expr.op == UnaryOp::Void {
+          unary_expr.arg.visit_with(unary_expr, self);
+          return;
+        }
+      }
+    }
+    expr_stmt.visit_children_with(self);
+  }
+
+  fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr, _parent: &dyn Node) {
+    if unary_expr.op == UnaryOp::Void {
+      self.context.add_diagnostic_with_hint(
+        unary_expr.span,
+        CODE,
+        NoVoidMessage::Unexpected,
+        NoVoidHint::UseUndefined,
+      );
+    }
+    unary_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn no_void_valid() {
+    assert_lint_ok! {
+      NoVoid,
+      "const x = undefined;",
+      "typeof foo;",
+      "delete foo.bar;",
+    };
+  }
+
+  #[test]
+  fn no_void_invalid() {
+    assert_lint_err! {
+      NoVoid,
+      "const x = void 0;": [
+        {
+          col: 10,
+          message: NoVoidMessage::Unexpected,
+          hint: NoVoidHint::UseUndefined,
+        }
+      ],
+      "void doStuff();": [
+        {
+          col: 0,
+          message: NoVoidMessage::Unexpected,
+          hint: NoVoidHint::UseUndefined,
+        }
+      ],
+      "function foo() { return void bar(void 0); }": [
+        {
+          col: 24,
+          message: NoVoidMessage::Unexpected,
+          hint: NoVoidHint::UseUndefined,
+        },
+        {
+          col: 33,
+          message: NoVoidMessage::Unexpected,
+          hint: NoVoidHint::UseUndefined,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_void_allow_as_statement() {
+    let options = NoVoidOptions {
+      allow_as_statement: true,
+    };
+    assert_lint_ok_with_rule(
+      NoVoid::with_options(options.clone()),
+      "void doStuff();",
+    );
+    assert_lint_err_on_line_with_rule(
+      NoVoid::with_options(options.clone()),
+      "const x = void 0;",
+      1,
+      10,
+    );
+    assert_lint_err_on_line_with_rule(
+      NoVoid::with_options(options),
+      "void foo(void 0);",
+      1,
+      9,
+    );
+  }
+}
@@ -0,0 +1,246 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::LintFix;
+use derive_more::Display;
+use std::collections::HashSet;
+use swc_common::Span;
+use swc_ecmascript::ast::{Expr, ExprOrSuper, Lit, MemberExpr, Number};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct NoZeroFractions;
+
+const CODE: &str = "no-zero-fractions";
+
+#[derive(Display)]
+enum NoZeroFractionsMessage {
+  #[display(fmt = "Unnecessary zeros in the fraction of a number")]
+  ZeroFraction,
+  #[display(fmt = "Unnecessary decimal point at the end of a number")]
+  DanglingDot,
+}
+
+#[derive(Display)]
+enum NoZeroFractionsFix {
+  #[display(fmt = "Remove the zeros")]
+  RemoveZeros,
+  #[display(fmt = "Remove the decimal point")]
+  RemoveDot,
+}
+
+impl LintRule for NoZeroFractions {
+  fn new() -> Box<Self> {
+    Box::new(NoZeroFractions)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = NoZeroFractionsVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows zero fractions and dangling decimal points in numbers
+
+Trailing zeros in the fraction of a number, and a decimal point without a
+fraction after it, don't change its value and only add noise.
+
+### Invalid:
+```typescript
+const a = 1.0;
+const b = 1.;
+const c = 1.50;
+const d = 2.0e3;
+```
+
+### Valid:
+```typescript
+const a = 1;
+const b = 1.5;
+const c = 2e3;
+```
+"#
+  }
+}
+
+struct NoZeroFractionsVisitor<'c> {
+  context: &'c mut Context,
+  /// Spans of numbers used as the object of a member expression, which need
+  /// parentheses once they lose their decimal point, as in `(1).toFixed()`.
+  member_objects: HashSet<Span>,
+}
+
+impl<'c> NoZeroFractionsVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self {
+      context,
+      member_objects: HashSet::new(),
+    }
+  }
+}
+
+/// Removes the zeros at the end of the fraction of `raw`, the source of a
+/// numeric literal, and the decimal point if nothing is left after it.
+fn remove_zero_fraction(raw: &str) -> Option<(String, NoZeroFractionsMessage)> {
+  let lowercase = raw.to_ascii_lowercase();
+  if lowercase.starts_with("0x")
+    || lowercase.starts_with("0b")
+    || lowercase.starts_with("0o")
+  {
+    return None;
+  }
+  let (mantissa, exponent) = match lowercase.find('e') {
+    Some(index) => (&raw[..index], &raw[index..]),
+    None => (raw, ""),
+  };
+  let dot = mantissa.find('.')?;
+  let (integer, fraction) = (&mantissa[..dot], &mantissa[dot + 1..]);
+  let trimmed = fraction.trim_end_matches(|c| c == '0' || c == '_');
+  let message = if fraction.is_empty() {
+    NoZeroFractionsMessage::DanglingDot
+  } else if trimmed.len() < fraction.len() {
+    NoZeroFractionsMessage::ZeroFraction
+  } else {
+    return None;
+  };
+
+  let fixed = if trimmed.is_empty() {
+    // `.0` has no integer part to keep
+    let integer = if integer.is_empty() { "0" } else { integer };
+    format!("{}{}", integer, exponent)
+  } else {
+    format!("{}.{}{}", integer, trimmed, exponent)
+  };
+  Some((fixed, message))
+}
+
+impl<'c> Visit for NoZeroFractionsVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_member_expr(
+    &mut self,
+    member_expr: &MemberExpr,
+    _parent: &dyn Node,
+  ) {
+    if let ExprOrSuper::Expr(obj) = &member_expr.obj {
+      if let Expr::Lit(Lit::Num(number)) = &**obj {
+        self.member_objects.insert(number.span);
+      }
+    }
+    member_expr.visit_children_with(self);
+  }
+
+  fn visit_number(&mut self, number: &Number, _parent: &dyn Node) {
+    let raw = match self.context.source_map.span_to_snippet(number.span) {
+      Ok(raw) => raw,
+      Err(_) => return,
+    };
+    let (mut fixed, message) = match remove_zero_fraction(&raw) {
+      Some(result) => result,
+      None => return,
+    };
+    if self.member_objects.contains(&number.span) && !fixed.contains('.') {
+      fixed = format!("({})", fixed);
+    }
+
+    let description = match message {
+      NoZeroFractionsMessage::ZeroFraction => NoZeroFractionsFix::RemoveZeros,
+      NoZeroFractionsMessage::DanglingDot => NoZeroFractionsFix::RemoveDot,
+    };
+    let fix = LintFix {
+      description: description.to_string(),
+      changes: vec![self.context.create_fix_change(number.span, fixed)],
+      priority: 0,
+    };
+    self
+      .context
+      .add_diagnostic_with_fix(number.span, CODE, message, fix);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn no_zero_fractions_valid() {
+    assert_lint_ok! {
+      NoZeroFractions,
+      "const a = 1;",
+      "const a = 1.5;",
+      "const a = 1.05;",
+      "const a = .5;",
+      "const a = 1e10;",
+      "const a = 1.5e10;",
+      "const a = 0x10;",
+      "const a = 100;",
+      "const a = '1.0';",
+    };
+  }
+
+  #[test]
+  fn no_zero_fractions_invalid() {
+    assert_lint_err! {
+      NoZeroFractions,
+      "const a = 1.0;": [
+        {
+          col: 10,
+          message: NoZeroFractionsMessage::ZeroFraction,
+        }
+      ],
+      "const a = 1.;": [
+        {
+          col: 10,
+          message: NoZeroFractionsMessage::DanglingDot,
+        }
+      ],
+      "const a = 1.50;": [
+        {
+          col: 10,
+          message: NoZeroFractionsMessage::ZeroFraction,
+        }
+      ],
+      "f(.0, 2.00e3);": [
+        {
+          col: 2,
+          message: NoZeroFractionsMessage::ZeroFraction,
+        },
+        {
+          col: 6,
+          message: NoZeroFractionsMessage::ZeroFraction,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_zero_fractions_fix() {
+    let cases = [
+      ("1.0;", "1;"),
+      ("1.;", "1;"),
+      ("1.50;", "1.5;"),
+      ("1.500_000;", "1.5;"),
+      ("1.0e10;", "1e10;"),
+      ("1.E10;", "1E10;"),
+      (".0;", "0;"),
+      (".50;", ".5;"),
+      ("1.0.toFixed();", "(1).toFixed();"),
+      ("1..toFixed();", "(1).toFixed();"),
+      ("1.50.toFixed();", "1.5.toFixed();"),
+    ];
+    for (src, expected) in &cases {
+      assert_lint_fix::<NoZeroFractions>(src, expected);
+    }
+  }
+}
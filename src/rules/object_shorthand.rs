@@ -0,0 +1,407 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::LintFix;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_common::Spanned;
+use swc_ecmascript::ast::{Expr, Function, Prop, PropName};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct ObjectShorthand {
+  options: ObjectShorthandOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct ObjectShorthandOptions {
+  /// Which properties must use the shorthand syntax.
+  pub mode: ShorthandMode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ShorthandMode {
+  /// Require shorthand properties and methods.
+  Always,
+  /// Require shorthand methods only.
+  Methods,
+  /// Require shorthand properties only.
+  Properties,
+  /// Disallow shorthand properties and methods.
+  Never,
+}
+
+impl Default for ShorthandMode {
+  fn default() -> Self {
+    ShorthandMode::Always
+  }
+}
+
+const CODE: &str = "object-shorthand";
+
+#[derive(Display)]
+enum ObjectShorthandMessage {
+  #[display(fmt = "Expected property shorthand")]
+  ExpectedPropertyShorthand,
+  #[display(fmt = "Expected method shorthand")]
+  ExpectedMethodShorthand,
+  #[display(fmt = "Expected longform property syntax")]
+  ExpectedPropertyLongform,
+  #[display(fmt = "Expected longform method syntax")]
+  ExpectedMethodLongform,
+}
+
+#[derive(Display)]
+enum ObjectShorthandFix {
+  #[display(fmt = "Use the shorthand syntax")]
+  ToShorthand,
+  #[display(fmt = "Use the longform syntax")]
+  ToLongform,
+}
+
+impl ObjectShorthand {
+  pub fn with_options(options: ObjectShorthandOptions) -> Box<Self> {
+    Box::new(ObjectShorthand { options })
+  }
+}
+
+impl LintRule for ObjectShorthand {
+  fn new() -> Box<Self> {
+    ObjectShorthand::with_options(ObjectShorthandOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "mode": {
+          "type": "string",
+          "enum": ["always", "methods", "properties", "never"],
+          "description": "Which properties must use the shorthand syntax.",
+          "default": "always"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = ObjectShorthandVisitor::new(context, self.options.mode);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces the shorthand syntax for object literal properties and methods
+
+Object literals can define a property holding a variable of the same name as
+`{ foo }` instead of `{ foo: foo }`, and a method as `{ bar() {} }` instead of
+`{ bar: function () {} }`.
+
+The `mode` option is one of:
+
+- `always` (the default) requires both shorthands.
+- `methods` requires the method shorthand only.
+- `properties` requires the property shorthand only.
+- `never` disallows both shorthands.
+
+Named function expressions are not reported, since their name may be used to
+refer to the function from within.
+
+### Invalid:
+```typescript
+// With `mode: "always"`
+const obj = {
+  foo: foo,
+  bar: function () {},
+};
+// With `mode: "never"`
+const other = { foo, bar() {} };
+```
+
+### Valid:
+```typescript
+// With `mode: "always"`
+const obj = {
+  foo,
+  bar() {},
+  baz: qux,
+};
+// With `mode: "never"`
+const other = { foo: foo, bar: function () {} };
+```
+"#
+  }
+}
+
+struct ObjectShorthandVisitor<'c> {
+  context: &'c mut Context,
+  mode: ShorthandMode,
+}
+
+impl<'c> ObjectShorthandVisitor<'c> {
+  fn new(context: &'c mut Context, mode: ShorthandMode) -> Self {
+    Self { context, mode }
+  }
+
+  fn requires_property_shorthand(&self) -> bool {
+    matches!(self.mode, ShorthandMode::Always | ShorthandMode::Properties)
+  }
+
+  fn requires_method_shorthand(&self) -> bool {
+    matches!(self.mode, ShorthandMode::Always | ShorthandMode::Methods)
+  }
+
+  /// Returns the source of `function` after the `function` keyword, e.g.
+  /// `(a, b): T { ... }`.
+  fn signature_and_body(&self, function: &Function) -> Option<String> {
+    let source_map = &self.context.source_map;
+    let mut text = String::new();
+    if let Some(type_params) = &function.type_params {
+      text.push_str(&source_map.span_to_snippet(type_params.span).ok()?);
+    }
+    let mut params = Vec::with_capacity(function.params.len());
+    for param in &function.params {
+      params.push(source_map.span_to_snippet(param.span()).ok()?);
+    }
+    text.push('(');
+    text.push_str(&params.join(", "));
+    text.push(')');
+    if let Some(return_type) = &function.return_type {
+      let type_ann = source_map.span_to_snippet(return_type.type_ann.span());
+      text.push_str(": ");
+      text.push_str(&type_ann.ok()?);
+    }
+    let body = function.body.as_ref()?;
+    text.push(' ');
+    text.push_str(&source_map.span_to_snippet(body.span).ok()?);
+    Some(text)
+  }
+
+  fn to_method(&self, key: &PropName, function: &Function) -> Option<String> {
+    let key = self.context.source_map.span_to_snippet(key.span()).ok()?;
+    Some(format!(
+      "{}{}{}{}",
+      if function.is_async { "async " } else { "" },
+      if function.is_generator { "*" } else { "" },
+      key,
+      self.signature_and_body(function)?,
+    ))
+  }
+
+  fn to_longform_method(
+    &self,
+    key: &PropName,
+    function: &Function,
+  ) -> Option<String> {
+    let key = self.context.source_map.span_to_snippet(key.span()).ok()?;
+    Some(format!(
+      "{}: function {}",
+      key,
+      self.signature_and_body(function)?
+    ))
+  }
+
+  fn report(
+    &mut self,
+    prop: &Prop,
+    message: ObjectShorthandMessage,
+    fix: Option<(ObjectShorthandFix, String)>,
+  ) {
+    match fix {
+      Some((description, new_text)) => {
+        let fix = LintFix {
+          description: description.to_string(),
+          changes: vec![self.context.create_fix_change(prop.span(), new_text)],
+          priority: 0,
+        };
+        self
+          .context
+          .add_diagnostic_with_fix(prop.span(), CODE, message, fix);
+      }
+      None => self.context.add_diagnostic(prop.span(), CODE, message),
+    }
+  }
+}
+
+impl<'c> Visit for ObjectShorthandVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_prop(&mut self, prop: &Prop, _parent: &dyn Node) {
+    match prop {
+      Prop::KeyValue(key_value) => match (&key_value.key, &*key_value.value) {
+        (PropName::Ident(key), Expr::Ident(value))
+          if key.sym == value.sym && self.requires_property_shorthand() =>
+        {
+          let fix = (ObjectShorthandFix::ToShorthand, value.sym.to_string());
+          self.report(
+            prop,
+            ObjectShorthandMessage::ExpectedPropertyShorthand,
+            Some(fix),
+          );
+        }
+        (key, Expr::Fn(fn_expr))
+          if fn_expr.ident.is_none() && self.requires_method_shorthand() =>
+        {
+          let fix = self
+            .to_method(key, &fn_expr.function)
+            .map(|new_text| (ObjectShorthandFix::ToShorthand, new_text));
+          self.report(
+            prop,
+            ObjectShorthandMessage::ExpectedMethodShorthand,
+            fix,
+          );
+        }
+        _ => {}
+      },
+      Prop::Shorthand(ident) if self.mode == ShorthandMode::Never => {
+        let fix = (
+          ObjectShorthandFix::ToLongform,
+          format!("{}: {}", ident.sym, ident.sym),
+        );
+        self.report(
+          prop,
+          ObjectShorthandMessage::ExpectedPropertyLongform,
+          Some(fix),
+        );
+      }
+      Prop::Method(method) if self.mode == ShorthandMode::Never => {
+        // The span of the property starts at the key, after any `async` or
+        // `*`, so those methods can't be replaced as a whole
+        let function = &method.function;
+        let fix = if function.is_async || function.is_generator {
+          None
+        } else {
+          self
+            .to_longform_method(&method.key, function)
+            .map(|new_text| (ObjectShorthandFix::ToLongform, new_text))
+        };
+        self.report(prop, ObjectShorthandMessage::ExpectedMethodLongform, fix);
+      }
+      _ => {}
+    }
+    prop.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  fn with_mode(mode: ShorthandMode) -> Box<ObjectShorthand> {
+    ObjectShorthand::with_options(ObjectShorthandOptions { mode })
+  }
+
+  #[test]
+  fn object_shorthand_valid() {
+    assert_lint_ok! {
+      ObjectShorthand,
+      "const obj = { foo, bar() {} };",
+      "const obj = { foo: bar };",
+      "const obj = { 'foo': foo };",
+      "const obj = { [foo]: foo };",
+      "const obj = { foo: foo.bar };",
+      "const obj = { foo: function foo() { return foo; } };",
+      "const obj = { foo: () => {} };",
+      "const { foo: foo } = obj;",
+      "const obj = { get foo() { return 1; } };",
+    };
+
+    let sources = [
+      ("const obj = { foo: foo };", ShorthandMode::Methods),
+      (
+        "const obj = { foo: function () {} };",
+        ShorthandMode::Properties,
+      ),
+      (
+        "const obj = { foo: foo, bar: function () {} };",
+        ShorthandMode::Never,
+      ),
+    ];
+    for (src, mode) in &sources {
+      assert_lint_ok_with_rule(with_mode(*mode), src);
+    }
+  }
+
+  #[test]
+  fn object_shorthand_invalid() {
+    assert_lint_err! {
+      ObjectShorthand,
+      "const obj = { foo: foo };": [
+        {
+          col: 14,
+          message: ObjectShorthandMessage::ExpectedPropertyShorthand,
+        }
+      ],
+      "const obj = { foo: function () {} };": [
+        {
+          col: 14,
+          message: ObjectShorthandMessage::ExpectedMethodShorthand,
+        }
+      ],
+      "const obj = { a: 1, [foo]: async function* () {} };": [
+        {
+          col: 20,
+          message: ObjectShorthandMessage::ExpectedMethodShorthand,
+        }
+      ],
+    };
+
+    let sources = [
+      ("const obj = { foo };", ShorthandMode::Never, 14),
+      ("const obj = { foo() {} };", ShorthandMode::Never, 14),
+      ("const obj = { foo: foo };", ShorthandMode::Properties, 14),
+      (
+        "const obj = { foo: function () {} };",
+        ShorthandMode::Methods,
+        14,
+      ),
+    ];
+    for (src, mode, col) in &sources {
+      assert_lint_err_on_line_with_rule(with_mode(*mode), src, 1, *col);
+    }
+  }
+
+  #[test]
+  fn object_shorthand_fix() {
+    assert_lint_fix::<ObjectShorthand>(
+      "const obj = { foo: foo, bar: bar };",
+      "const obj = { foo, bar };",
+    );
+    assert_lint_fix::<ObjectShorthand>(
+      "const obj = { foo: function (a, b) { return a + b; } };",
+      "const obj = { foo(a, b) { return a + b; } };",
+    );
+    assert_lint_fix::<ObjectShorthand>(
+      "const obj = { [foo]: async function* <T>(a: T): T {} };",
+      "const obj = { async *[foo]<T>(a: T): T {} };",
+    );
+    assert_lint_fix_with_rule(
+      with_mode(ShorthandMode::Never),
+      "const obj = { foo, bar(a) {} };",
+      "const obj = { foo: foo, bar: function (a) {} };",
+    );
+    assert_lint_fix_with_rule(
+      with_mode(ShorthandMode::Never),
+      "const obj = { async *foo() {} };",
+      "const obj = { async *foo() {} };",
+    );
+  }
+}
@@ -0,0 +1,373 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::LintFix;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_common::Spanned;
+use swc_ecmascript::ast::{
+  CallExpr, Class, Expr, ExprOrSpread, FnExpr, Function, Ident, MemberExpr,
+  MetaPropExpr, NewExpr, ThisExpr,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct PreferArrowCallback {
+  options: PreferArrowCallbackOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct PreferArrowCallbackOptions {
+  /// Allow named function expressions as callbacks.
+  pub allow_named_functions: bool,
+}
+
+const CODE: &str = "prefer-arrow-callback";
+
+#[derive(Display)]
+enum PreferArrowCallbackMessage {
+  #[display(fmt = "Unexpected function expression as a callback")]
+  Unexpected,
+}
+
+#[derive(Display)]
+enum PreferArrowCallbackFix {
+  #[display(fmt = "Convert to an arrow function")]
+  ToArrow,
+}
+
+impl PreferArrowCallback {
+  pub fn with_options(options: PreferArrowCallbackOptions) -> Box<Self> {
+    Box::new(PreferArrowCallback { options })
+  }
+}
+
+impl LintRule for PreferArrowCallback {
+  fn new() -> Box<Self> {
+    PreferArrowCallback::with_options(PreferArrowCallbackOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "allowNamedFunctions": {
+          "type": "boolean",
+          "description": "Allow named function expressions as callbacks.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = PreferArrowCallbackVisitor::new(context, &self.options);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires arrow functions as callbacks
+
+Arrow functions are shorter than function expressions and don't rebind `this`,
+which makes them the natural choice for callbacks. Function expressions using
+`this`, `arguments`, `new.target` or `yield`, or referring to themselves by
+name, can't be converted and are not reported.
+
+Set `allowNamedFunctions` to allow named function expressions as callbacks.
+
+### Invalid:
+```typescript
+arr.map(function (x) {
+  return x * 2;
+});
+setTimeout(async function () {
+  await foo();
+});
+```
+
+### Valid:
+```typescript
+arr.map((x) => x * 2);
+el.addEventListener("click", function () {
+  this.remove();
+});
+run(function loop(n) {
+  if (n > 0) loop(n - 1);
+});
+```
+"#
+  }
+}
+
+struct PreferArrowCallbackVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o PreferArrowCallbackOptions,
+}
+
+impl<'c, 'o> PreferArrowCallbackVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o PreferArrowCallbackOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn check_args(&mut self, args: &[ExprOrSpread]) {
+    for arg in args {
+      if let Expr::Fn(fn_expr) = &*arg.expr {
+        self.check_callback(fn_expr);
+      }
+    }
+  }
+
+  fn check_callback(&mut self, fn_expr: &FnExpr) {
+    let function = &fn_expr.function;
+    if function.is_generator
+      || (fn_expr.ident.is_some() && self.options.allow_named_functions)
+    {
+      return;
+    }
+    let body = match &function.body {
+      Some(body) => body,
+      None => return,
+    };
+
+    let mut finder = FunctionDependencyFinder {
+      name: fn_expr.ident.as_ref().map(|ident| ident.to_id()),
+      depth: 0,
+      found: false,
+    };
+    function.params.visit_with(function, &mut finder);
+    body.visit_with(function, &mut finder);
+    if finder.found {
+      return;
+    }
+
+    match self.to_arrow(function) {
+      Some(arrow) => {
+        let fix = LintFix {
+          description: PreferArrowCallbackFix::ToArrow.to_string(),
+          changes: vec![self.context.create_fix_change(function.span, arrow)],
+          priority: 0,
+        };
+        self.context.add_diagnostic_with_fix(
+          function.span,
+          CODE,
+          PreferArrowCallbackMessage::Unexpected,
+          fix,
+        );
+      }
+      None => self.context.add_diagnostic(
+        function.span,
+        CODE,
+        PreferArrowCallbackMessage::Unexpected,
+      ),
+    }
+  }
+
+  /// Builds the source of an arrow function equivalent to `function`.
+  fn to_arrow(&self, function: &Function) -> Option<String> {
+    let source_map = &self.context.source_map;
+    let mut arrow = String::new();
+    if function.is_async {
+      arrow.push_str("async ");
+    }
+    if let Some(type_params) = &function.type_params {
+      arrow.push_str(&source_map.span_to_snippet(type_params.span).ok()?);
+    }
+    let mut params = Vec::with_capacity(function.params.len());
+    for param in &function.params {
+      params.push(source_map.span_to_snippet(param.span()).ok()?);
+    }
+    arrow.push('(');
+    arrow.push_str(&params.join(", "));
+    arrow.push(')');
+    if let Some(return_type) = &function.return_type {
+      let type_ann = source_map.span_to_snippet(return_type.type_ann.span());
+      arrow.push_str(": ");
+      arrow.push_str(&type_ann.ok()?);
+    }
+    arrow.push_str(" => ");
+    let body = function.body.as_ref()?;
+    arrow.push_str(&source_map.span_to_snippet(body.span).ok()?);
+    Some(arrow)
+  }
+}
+
+impl<'c, 'o> Visit for PreferArrowCallbackVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    self.check_args(&call_expr.args);
+    call_expr.visit_children_with(self);
+  }
+
+  fn visit_new_expr(&mut self, new_expr: &NewExpr, _parent: &dyn Node) {
+    if let Some(args) = &new_expr.args {
+      self.check_args(args);
+    }
+    new_expr.visit_children_with(self);
+  }
+}
+
+/// Looks for what keeps a function expression from becoming an arrow
+/// function: `this`, `arguments` and `new.target` outside nested functions
+/// and classes, and references to the function's own name.
+struct FunctionDependencyFinder {
+  name: Option<Id>,
+  depth: usize,
+  found: bool,
+}
+
+impl Visit for FunctionDependencyFinder {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
+    self.depth += 1;
+    function.visit_children_with(self);
+    self.depth -= 1;
+  }
+
+  fn visit_class(&mut self, class: &Class, _parent: &dyn Node) {
+    self.depth += 1;
+    class.visit_children_with(self);
+    self.depth -= 1;
+  }
+
+  fn visit_this_expr(&mut self, _this_expr: &ThisExpr, _parent: &dyn Node) {
+    if self.depth == 0 {
+      self.found = true;
+    }
+  }
+
+  fn visit_meta_prop_expr(
+    &mut self,
+    meta_prop_expr: &MetaPropExpr,
+    _parent: &dyn Node,
+  ) {
+    if self.depth == 0 && meta_prop_expr.meta.sym == *"new" {
+      self.found = true;
+    }
+  }
+
+  fn visit_ident(&mut self, ident: &Ident, _parent: &dyn Node) {
+    if (self.depth == 0 && ident.sym == *"arguments")
+      || self.name.as_ref() == Some(&ident.to_id())
+    {
+      self.found = true;
+    }
+  }
+
+  fn visit_member_expr(
+    &mut self,
+    member_expr: &MemberExpr,
+    _parent: &dyn Node,
+  ) {
+    member_expr.obj.visit_with(member_expr, self);
+    // `foo.arguments` doesn't refer to `arguments`
+    if member_expr.computed {
+      member_expr.prop.visit_with(member_expr, self);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn prefer_arrow_callback_valid() {
+    assert_lint_ok! {
+      PreferArrowCallback,
+      "arr.map((x) => x);",
+      "arr.map(function (x) { return this.f(x); });",
+      "arr.map(function () { return arguments[0]; });",
+      "foo(function () { return new.target; });",
+      "foo(function* () { yield 1; });",
+      "foo(function bar(n) { return n > 0 ? bar(n - 1) : 0; });",
+      "foo(function () { return () => this; });",
+      "const f = function () {};",
+      "foo(bar);",
+    };
+
+    assert_lint_ok_with_rule(
+      PreferArrowCallback::with_options(PreferArrowCallbackOptions {
+        allow_named_functions: true,
+      }),
+      "foo(function bar() {});",
+    );
+  }
+
+  #[test]
+  fn prefer_arrow_callback_invalid() {
+    assert_lint_err! {
+      PreferArrowCallback,
+      "arr.map(function (x) { return x; });": [
+        {
+          col: 8,
+          message: PreferArrowCallbackMessage::Unexpected,
+        }
+      ],
+      "foo(function bar() {});": [
+        {
+          col: 4,
+          message: PreferArrowCallbackMessage::Unexpected,
+        }
+      ],
+      "new Promise(function (resolve) { resolve(); });": [
+        {
+          col: 12,
+          message: PreferArrowCallbackMessage::Unexpected,
+        }
+      ],
+      "foo(function () { return function () { return this; }; });": [
+        {
+          col: 4,
+          message: PreferArrowCallbackMessage::Unexpected,
+        }
+      ],
+      "foo(function () { return bar.arguments; });": [
+        {
+          col: 4,
+          message: PreferArrowCallbackMessage::Unexpected,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_arrow_callback_fix() {
+    assert_lint_fix::<PreferArrowCallback>(
+      "arr.map(function(x){return x})",
+      "arr.map((x) => {return x})",
+    );
+    assert_lint_fix::<PreferArrowCallback>(
+      "foo(async function named(a, b) { await a; });",
+      "foo(async (a, b) => { await a; });",
+    );
+    assert_lint_fix::<PreferArrowCallback>(
+      "foo(function <T>(x: T): T { return x; });",
+      "foo(<T>(x: T): T => { return x; });",
+    );
+  }
+}
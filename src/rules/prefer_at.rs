@@ -0,0 +1,337 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::LintFix;
+use derive_more::Display;
+use swc_common::Span;
+use swc_common::Spanned;
+use swc_ecmascript::ast::{
+  AssignExpr, BinaryOp, Expr, ExprOrSuper, Lit, MemberExpr, Pat, PatOrExpr,
+  UnaryExpr, UnaryOp, UpdateExpr,
+};
+use swc_ecmascript::utils::drop_span;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct PreferAt;
+
+const CODE: &str = "prefer-at";
+
+#[derive(Display)]
+enum PreferAtMessage {
+  #[display(
+    fmt = "Use `.at()` with a negative index to access elements from the end"
+  )]
+  Unexpected,
+}
+
+#[derive(Display)]
+enum PreferAtFix {
+  #[display(fmt = "Replace with `.at()`")]
+  UseAt,
+}
+
+impl LintRule for PreferAt {
+  fn new() -> Box<Self> {
+    Box::new(PreferAt)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = PreferAtVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Prefers `.at()` with a negative index over `x[x.length - N]`
+
+`Array.prototype.at` and `String.prototype.at` accept negative indices that
+count from the end, which is shorter and doesn't repeat the object expression.
+
+Only objects that are variables, `this` or static property accesses on them
+are checked, since repeating other expressions may have side effects. Elements
+that are assigned to or deleted are skipped, since `.at()` can't be written
+to, and so is `arguments`, which has no `.at()` method.
+
+### Invalid:
+```typescript
+const last = arr[arr.length - 1];
+const secondToLast = foo.bar[foo.bar.length - 2];
+```
+
+### Valid:
+```typescript
+const last = arr.at(-1);
+const first = arr[0];
+arr[arr.length - 1] = 0;
+[arr[arr.length - 1]] = values;
+delete arr[arr.length - 1];
+foo()[foo().length - 1];
+arguments[arguments.length - 1];
+```
+"#
+  }
+}
+
+struct PreferAtVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> PreferAtVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  fn report(&mut self, member_expr: &MemberExpr, obj_span: Span, n_span: Span) {
+    let source_map = &self.context.source_map;
+    let (obj_text, n_text) = match (
+      source_map.span_to_snippet(obj_span),
+      source_map.span_to_snippet(n_span),
+    ) {
+      (Ok(obj_text), Ok(n_text)) => (obj_text, n_text),
+      _ => return,
+    };
+
+    let fix = LintFix {
+      description: PreferAtFix::UseAt.to_string(),
+      changes: vec![self.context.create_fix_change(
+        member_expr.span,
+        format!("{}.at(-{})", obj_text, n_text),
+      )],
+      priority: 0,
+    };
+    self.context.add_diagnostic_with_fix(
+      member_expr.span,
+      CODE,
+      PreferAtMessage::Unexpected,
+      fix,
+    );
+  }
+
+  /// `.at()` can't be assigned to or deleted, so member expressions that are
+  /// written to are skipped.
+  fn visit_write_target(&mut self, expr: &Expr, parent: &dyn Node) {
+    if let Expr::Member(member_expr) = expr {
+      member_expr.visit_children_with(self);
+    } else {
+      expr.visit_with(parent, self);
+    }
+  }
+}
+
+/// Returns the spans of `x` and `N` if `member_expr` is of the form
+/// `x[x.length - N]`.
+fn match_length_access(member_expr: &MemberExpr) -> Option<(Span, Span)> {
+  if !member_expr.computed {
+    return None;
+  }
+  let obj = match &member_expr.obj {
+    ExprOrSuper::Expr(obj) => obj,
+    ExprOrSuper::Super(_) => return None,
+  };
+  let bin_expr = match &*member_expr.prop {
+    Expr::Bin(bin_expr) if bin_expr.op == BinaryOp::Sub => bin_expr,
+    _ => return None,
+  };
+  if !is_positive_integer(&bin_expr.right) {
+    return None;
+  }
+  let length_expr = match &*bin_expr.left {
+    Expr::Member(length_expr) if !length_expr.computed => length_expr,
+    _ => return None,
+  };
+  match &*length_expr.prop {
+    Expr::Ident(ident) if ident.sym == *"length" => {}
+    _ => return None,
+  }
+  match &length_expr.obj {
+    ExprOrSuper::Expr(length_obj)
+      if is_static_chain(obj)
+        && drop_span(obj.clone()) == drop_span(length_obj.clone()) =>
+    {
+      Some((obj.span(), bin_expr.right.span()))
+    }
+    _ => None,
+  }
+}
+
+/// Checks if `expr` is a variable or `this` followed by static property
+/// accesses, like `this.foo.bar`, so that evaluating it twice has no side
+/// effects beyond getters. `arguments` has no `.at()` method, so it's left out.
+fn is_static_chain(expr: &Expr) -> bool {
+  match expr {
+    Expr::Ident(ident) => ident.sym != *"arguments",
+    Expr::This(_) => true,
+    Expr::Member(MemberExpr {
+      obj: ExprOrSuper::Expr(obj),
+      prop,
+      computed: false,
+      ..
+    }) => matches!(&**prop, Expr::Ident(_)) && is_static_chain(obj),
+    _ => false,
+  }
+}
+
+fn is_positive_integer(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::Num(num)) => num.value > 0.0 && num.value.fract() == 0.0,
+    _ => false,
+  }
+}
+
+impl<'c> Visit for PreferAtVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_member_expr(
+    &mut self,
+    member_expr: &MemberExpr,
+    _parent: &dyn Node,
+  ) {
+    if let Some((obj_span, n_span)) = match_length_access(member_expr) {
+      self.report(member_expr, obj_span, n_span);
+    }
+    member_expr.visit_children_with(self);
+  }
+
+  fn visit_assign_expr(
+    &mut self,
+    assign_expr: &AssignExpr,
+    _parent: &dyn Node,
+  ) {
+    match &assign_expr.left {
+      PatOrExpr::Expr(expr) => self.visit_write_target(expr, assign_expr),
+      PatOrExpr::Pat(pat) => pat.visit_with(assign_expr, self),
+    }
+    assign_expr.right.visit_with(assign_expr, self);
+  }
+
+  // Covers destructuring assignments and `for (x of xs)` loops, whose targets
+  // are expressions in patterns
+  fn visit_pat(&mut self, pat: &Pat, parent: &dyn Node) {
+    match pat {
+      Pat::Expr(expr) => self.visit_write_target(expr, parent),
+      _ => pat.visit_children_with(self),
+    }
+  }
+
+  fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr, _parent: &dyn Node) {
+    if unary_expr.op == UnaryOp::Delete {
+      self.visit_write_target(&unary_expr.arg, unary_expr);
+    } else {
+      unary_expr.visit_children_with(self);
+    }
+  }
+
+  fn visit_update_expr(
+    &mut self,
+    update_expr: &UpdateExpr,
+    _parent: &dyn Node,
+  ) {
+    self.visit_write_target(&update_expr.arg, update_expr);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn prefer_at_valid() {
+    assert_lint_ok! {
+      PreferAt,
+      "arr[0];",
+      "arr.at(-1);",
+      "arr[arr.length];",
+      "arr[arr.length - i];",
+      "arr[arr.length - 0];",
+      "arr[other.length - 1];",
+      "arr[arr.length + 1];",
+      "arr.length - 1;",
+      "arr[arr.length - 1] = 0;",
+      "arr[arr.length - 1]++;",
+      "arr[arr['length'] - 1];",
+      "[arr[arr.length - 1]] = values;",
+      "[a, ...arr[arr.length - 1]] = values;",
+      "({ a: arr[arr.length - 1] } = values);",
+      "for (arr[arr.length - 1] of values) {}",
+      "for (arr[arr.length - 1] in values) {}",
+      "delete arr[arr.length - 1];",
+      "foo()[foo().length - 1];",
+      "a[i][a[i].length - 1];",
+      "arguments[arguments.length - 1];",
+    };
+  }
+
+  #[test]
+  fn prefer_at_invalid() {
+    assert_lint_err! {
+      PreferAt,
+      "const last = arr[arr.length - 1];": [
+        {
+          col: 13,
+          message: PreferAtMessage::Unexpected,
+        }
+      ],
+      "foo.bar[foo.bar.length - 2];": [
+        {
+          col: 0,
+          message: PreferAtMessage::Unexpected,
+        }
+      ],
+      "arr[arr.length - 1] = arr[arr.length - 2];": [
+        {
+          col: 22,
+          message: PreferAtMessage::Unexpected,
+        }
+      ],
+      "[a = arr[arr.length - 1]] = values;": [
+        {
+          col: 5,
+          message: PreferAtMessage::Unexpected,
+        }
+      ],
+      "delete obj[arr[arr.length - 1]];": [
+        {
+          col: 11,
+          message: PreferAtMessage::Unexpected,
+        }
+      ],
+      "this.items[this.items.length - 1];": [
+        {
+          col: 0,
+          message: PreferAtMessage::Unexpected,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_at_fix() {
+    assert_lint_fix::<PreferAt>(
+      "const last = arr[arr.length - 1];",
+      "const last = arr.at(-1);",
+    );
+    assert_lint_fix::<PreferAt>(
+      "foo.bar[foo.bar.length - 2];",
+      "foo.bar.at(-2);",
+    );
+    assert_lint_fix::<PreferAt>(
+      "[arr[arr.length - 1]] = [arr[arr.length - 2]];",
+      "[arr[arr.length - 1]] = [arr.at(-2)];",
+    );
+    assert_lint_fix::<PreferAt>(
+      "foo()[foo().length - 1];",
+      "foo()[foo().length - 1];",
+    );
+  }
+}
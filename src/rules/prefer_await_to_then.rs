@@ -0,0 +1,266 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_ecmascript::ast::{ArrowExpr, CallExpr, Expr, ExprOrSuper, Function};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct PreferAwaitToThen {
+  options: PreferAwaitToThenOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct PreferAwaitToThenOptions {
+  /// Report promise methods outside of async functions as well.
+  pub strict: bool,
+}
+
+const CODE: &str = "prefer-await-to-then";
+
+const PROMISE_METHODS: &[&str] = &["then", "catch", "finally"];
+
+#[derive(Display)]
+enum PreferAwaitToThenMessage {
+  #[display(fmt = "Prefer `await` to `.{}()`", _0)]
+  Unexpected(String),
+}
+
+#[derive(Display)]
+enum PreferAwaitToThenHint {
+  #[display(
+    fmt = "Await the promise, using `try`/`catch`/`finally` to handle errors"
+  )]
+  UseAwait,
+}
+
+impl PreferAwaitToThen {
+  pub fn with_options(options: PreferAwaitToThenOptions) -> Box<Self> {
+    Box::new(PreferAwaitToThen { options })
+  }
+}
+
+impl LintRule for PreferAwaitToThen {
+  fn new() -> Box<Self> {
+    PreferAwaitToThen::with_options(PreferAwaitToThenOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "strict": {
+          "type": "boolean",
+          "description": "Report promise methods outside of async functions as well.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = PreferAwaitToThenVisitor::new(context, &self.options);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Prefers `await` to `.then()`, `.catch()` and `.finally()` in async functions
+
+Async functions can wait for promises with `await`, which reads top to bottom
+and handles errors with ordinary `try` statements. Mixing it with promise
+callbacks makes the flow of the function harder to follow. This rule reports
+calls to `then`, `catch` and `finally` inside async functions.
+
+Calls outside of async functions are allowed, since `await` can't be used
+there. Set the `strict` option to report them as well.
+
+### Invalid:
+```typescript
+async function load() {
+  return fetch(url).then((res) => res.json());
+}
+```
+
+### Valid:
+```typescript
+async function load() {
+  const res = await fetch(url);
+  return res.json();
+}
+function load() {
+  return fetch(url).then((res) => res.json());
+}
+```
+"#
+  }
+}
+
+struct PreferAwaitToThenVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o PreferAwaitToThenOptions,
+  /// Whether each enclosing function is async, innermost last.
+  async_functions: Vec<bool>,
+}
+
+impl<'c, 'o> PreferAwaitToThenVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o PreferAwaitToThenOptions,
+  ) -> Self {
+    Self {
+      context,
+      options,
+      async_functions: vec![],
+    }
+  }
+
+  fn in_async_function(&self) -> bool {
+    self.async_functions.last().copied().unwrap_or(false)
+  }
+}
+
+/// Returns the name of the promise method `call_expr` calls, if any.
+fn promise_method(call_expr: &CallExpr) -> Option<&'static str> {
+  let callee = match &call_expr.callee {
+    ExprOrSuper::Expr(callee) => callee,
+    ExprOrSuper::Super(_) => return None,
+  };
+  match &**callee {
+    Expr::Member(member_expr) if !member_expr.computed => {
+      match &*member_expr.prop {
+        Expr::Ident(ident) => PROMISE_METHODS
+          .iter()
+          .copied()
+          .find(|method| ident.sym == **method),
+        _ => None,
+      }
+    }
+    _ => None,
+  }
+}
+
+impl<'c, 'o> Visit for PreferAwaitToThenVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _parent: &dyn Node) {
+    self.async_functions.push(function.is_async);
+    function.visit_children_with(self);
+    self.async_functions.pop();
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    self.async_functions.push(arrow_expr.is_async);
+    arrow_expr.visit_children_with(self);
+    self.async_functions.pop();
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    if self.options.strict || self.in_async_function() {
+      if let Some(method) = promise_method(call_expr) {
+        self.context.add_diagnostic_with_hint(
+          call_expr.span,
+          CODE,
+          PreferAwaitToThenMessage::Unexpected(method.to_string()),
+          PreferAwaitToThenHint::UseAwait,
+        );
+      }
+    }
+    call_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn prefer_await_to_then_valid() {
+    assert_lint_ok! {
+      PreferAwaitToThen,
+      "fetch(url).then((res) => res.json());",
+      "function f() { return p.then(g).catch(h).finally(i); }",
+      "async function f() { const res = await fetch(url); }",
+      "async function f() { function g() { return p.then(h); } }",
+      "async function f() { return () => p.then(g); }",
+      "async function f() { p[then](g); }",
+      "async function f() { p.thenable(g); }",
+      "class A { async f() { return () => p.catch(g); } }",
+    };
+  }
+
+  #[test]
+  fn prefer_await_to_then_invalid() {
+    assert_lint_err! {
+      PreferAwaitToThen,
+      "async function f() { return p.then(g); }": [
+        {
+          col: 28,
+          message: PreferAwaitToThenMessage::Unexpected("then".to_string()),
+          hint: PreferAwaitToThenHint::UseAwait,
+        }
+      ],
+      "const f = async () => p.catch(g);": [
+        {
+          col: 22,
+          message: PreferAwaitToThenMessage::Unexpected("catch".to_string()),
+          hint: PreferAwaitToThenHint::UseAwait,
+        }
+      ],
+      "class A { async f() { p.finally(g); } }": [
+        {
+          col: 22,
+          message: PreferAwaitToThenMessage::Unexpected(
+            "finally".to_string()
+          ),
+          hint: PreferAwaitToThenHint::UseAwait,
+        }
+      ],
+      "async function f() { p.then(g).catch(h); }": [
+        {
+          col: 21,
+          message: PreferAwaitToThenMessage::Unexpected("catch".to_string()),
+          hint: PreferAwaitToThenHint::UseAwait,
+        },
+        {
+          col: 21,
+          message: PreferAwaitToThenMessage::Unexpected("then".to_string()),
+          hint: PreferAwaitToThenHint::UseAwait,
+        }
+      ],
+      "function f() { return async () => { p.then(g); }; }": [
+        {
+          col: 36,
+          message: PreferAwaitToThenMessage::Unexpected("then".to_string()),
+          hint: PreferAwaitToThenHint::UseAwait,
+        }
+      ],
+    };
+
+    let options = PreferAwaitToThenOptions { strict: true };
+    assert_lint_err_on_line_with_rule(
+      PreferAwaitToThen::with_options(options),
+      "fetch(url).then((res) => res.json());",
+      1,
+      0,
+    );
+  }
+}
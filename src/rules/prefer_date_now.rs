@@ -0,0 +1,213 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::LintFix;
+use derive_more::Display;
+use swc_common::Span;
+use swc_ecmascript::ast::{
+  CallExpr, Expr, ExprOrSuper, NewExpr, UnaryExpr, UnaryOp,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct PreferDateNow;
+
+const CODE: &str = "prefer-date-now";
+
+#[derive(Display)]
+enum PreferDateNowMessage {
+  #[display(fmt = "Use `Date.now()` to get the current timestamp")]
+  Unexpected,
+}
+
+#[derive(Display)]
+enum PreferDateNowFix {
+  #[display(fmt = "Replace with `Date.now()`")]
+  UseDateNow,
+}
+
+impl LintRule for PreferDateNow {
+  fn new() -> Box<Self> {
+    Box::new(PreferDateNow)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = PreferDateNowVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Prefers `Date.now()` for getting the current timestamp
+
+`Date.now()` returns the number of milliseconds since the epoch without
+allocating an intermediate `Date` object, and states the intent directly.
+
+### Invalid:
+```typescript
+const a = new Date().getTime();
+const b = new Date().valueOf();
+const c = +new Date();
+```
+
+### Valid:
+```typescript
+const a = Date.now();
+const b = new Date(2020, 1, 1).getTime();
+const c = +new Date(timestamp);
+```
+"#
+  }
+}
+
+struct PreferDateNowVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> PreferDateNowVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  /// Checks if `expr` is `new Date()` without arguments, where `Date` is the
+  /// global one.
+  fn is_new_date(&self, expr: &Expr) -> bool {
+    if let Expr::New(NewExpr { callee, args, .. }) = expr {
+      let has_no_args = args.as_ref().map_or(true, |args| args.is_empty());
+      if let Expr::Ident(ident) = &**callee {
+        return has_no_args
+          && ident.sym == *"Date"
+          && self.context.scope().var(&ident.to_id()).is_none();
+      }
+    }
+    false
+  }
+
+  fn report(&mut self, span: Span) {
+    let fix = LintFix {
+      description: PreferDateNowFix::UseDateNow.to_string(),
+      changes: vec![self.context.create_fix_change(span, "Date.now()")],
+      priority: 0,
+    };
+    self.context.add_diagnostic_with_fix(
+      span,
+      CODE,
+      PreferDateNowMessage::Unexpected,
+      fix,
+    );
+  }
+}
+
+impl<'c> Visit for PreferDateNowVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    if call_expr.args.is_empty() {
+      if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+        if let Expr::Member(member_expr) = &**callee {
+          if let ExprOrSuper::Expr(obj) = &member_expr.obj {
+            let is_timestamp_method = !member_expr.computed
+              && matches!(
+                &*member_expr.prop,
+                Expr::Ident(ident)
+                  if ident.sym == *"getTime" || ident.sym == *"valueOf"
+              );
+            if is_timestamp_method && self.is_new_date(obj) {
+              self.report(call_expr.span);
+            }
+          }
+        }
+      }
+    }
+    call_expr.visit_children_with(self);
+  }
+
+  fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr, _parent: &dyn Node) {
+    if unary_expr.op == UnaryOp::Plus && self.is_new_date(&unary_expr.arg) {
+      self.report(unary_expr.span);
+    }
+    unary_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn prefer_date_now_valid() {
+    assert_lint_ok! {
+      PreferDateNow,
+      "Date.now();",
+      "new Date();",
+      "new Date;",
+      "new Date(2020, 1, 1).getTime();",
+      "new Date(timestamp).valueOf();",
+      "+new Date(timestamp);",
+      "-new Date();",
+      "new Date().getFullYear();",
+      "new Date()[getTime]();",
+      "new Foo().getTime();",
+      "class Date {} new Date().getTime();",
+      "function f(Date) { return +new Date(); }",
+    };
+  }
+
+  #[test]
+  fn prefer_date_now_invalid() {
+    assert_lint_err! {
+      PreferDateNow,
+      "const now = new Date().getTime();": [
+        {
+          col: 12,
+          message: PreferDateNowMessage::Unexpected,
+        }
+      ],
+      "new Date().valueOf();": [
+        {
+          col: 0,
+          message: PreferDateNowMessage::Unexpected,
+        }
+      ],
+      "const now = + new Date();": [
+        {
+          col: 12,
+          message: PreferDateNowMessage::Unexpected,
+        }
+      ],
+      "foo(+new Date);": [
+        {
+          col: 4,
+          message: PreferDateNowMessage::Unexpected,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_date_now_fix() {
+    assert_lint_fix::<PreferDateNow>(
+      "const now = new Date().getTime();",
+      "const now = Date.now();",
+    );
+    assert_lint_fix::<PreferDateNow>(
+      "const now = + new Date();",
+      "const now = Date.now();",
+    );
+    assert_lint_fix::<PreferDateNow>(
+      "const elapsed = new Date().valueOf() - start;",
+      "const elapsed = Date.now() - start;",
+    );
+  }
+}
@@ -0,0 +1,416 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::LintFix;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{
+  AssignExpr, AssignOp, Expr, ExprOrSuper, Ident, Lit, MemberExpr, Pat,
+  PatOrExpr, VarDeclarator,
+};
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct PreferDestructuring {
+  options: PreferDestructuringOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct PreferDestructuringOptions {
+  /// Which destructuring to require in variable declarations.
+  pub variable_declarator: DestructuringKinds,
+  /// Which destructuring to require in assignments.
+  pub assignment_expression: DestructuringKinds,
+  /// Also report reading a property into a variable with a different name,
+  /// as in `const b = obj.a` or `const b = obj[key]`.
+  pub enforce_for_renamed_properties: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct DestructuringKinds {
+  /// Report reading an array element by index, as in `const a = arr[0]`.
+  pub array: bool,
+  /// Report reading a property, as in `const a = obj.a`.
+  pub object: bool,
+}
+
+impl Default for DestructuringKinds {
+  fn default() -> Self {
+    DestructuringKinds {
+      array: true,
+      object: true,
+    }
+  }
+}
+
+const CODE: &str = "prefer-destructuring";
+
+#[derive(Display)]
+enum PreferDestructuringMessage {
+  #[display(fmt = "Use array destructuring")]
+  Array,
+  #[display(fmt = "Use object destructuring")]
+  Object,
+}
+
+#[derive(Display)]
+enum PreferDestructuringFix {
+  #[display(fmt = "Destructure the property")]
+  Destructure,
+}
+
+impl PreferDestructuring {
+  pub fn with_options(options: PreferDestructuringOptions) -> Box<Self> {
+    Box::new(PreferDestructuring { options })
+  }
+}
+
+impl LintRule for PreferDestructuring {
+  fn new() -> Box<Self> {
+    PreferDestructuring::with_options(PreferDestructuringOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    let kinds = json!({
+      "type": "object",
+      "properties": {
+        "array": {
+          "type": "boolean",
+          "description": "Report reading an array element by index.",
+          "default": true
+        },
+        "object": {
+          "type": "boolean",
+          "description": "Report reading a property.",
+          "default": true
+        }
+      },
+      "additionalProperties": false
+    });
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "variableDeclarator": kinds,
+        "assignmentExpression": kinds,
+        "enforceForRenamedProperties": {
+          "type": "boolean",
+          "description": "Also report reading a property into a variable with a different name.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = PreferDestructuringVisitor::new(context, &self.options);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires destructuring when reading a property into a variable
+
+Destructuring avoids repeating the name of a property in the variable it's
+read into. This rule reports variable declarations and assignments reading a
+property of an object into a variable of the same name, or an element of an
+array by index.
+
+The `variableDeclarator` and `assignmentExpression` options each take
+`array` and `object` flags, all enabled by default, to choose what is
+reported where. With `enforceForRenamedProperties`, reading a property into a
+variable of a different name is reported as well.
+
+### Invalid:
+```typescript
+const a = obj.a;
+const first = arr[0];
+b = obj.b;
+```
+
+### Valid:
+```typescript
+const { a } = obj;
+const [first] = arr;
+const b = obj.a;
+const c = obj[key];
+```
+"#
+  }
+}
+
+/// What kind of destructuring a property read should be replaced with.
+enum DestructuringKind {
+  Array,
+  /// An object property read into a variable with the same name.
+  Object,
+  /// An object property read into a variable with another name.
+  RenamedObject,
+}
+
+struct PreferDestructuringVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o PreferDestructuringOptions,
+}
+
+impl<'c, 'o> PreferDestructuringVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o PreferDestructuringOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  /// Returns which destructuring, if any, should replace reading
+  /// `member_expr` into `name`.
+  fn check(
+    &self,
+    name: &Ident,
+    member_expr: &MemberExpr,
+    kinds: &DestructuringKinds,
+  ) -> Option<DestructuringKind> {
+    if let ExprOrSuper::Super(_) = member_expr.obj {
+      return None;
+    }
+    let kind = match &*member_expr.prop {
+      Expr::Lit(Lit::Num(_)) if member_expr.computed => {
+        DestructuringKind::Array
+      }
+      Expr::Ident(prop) if !member_expr.computed && prop.sym == name.sym => {
+        DestructuringKind::Object
+      }
+      _ => DestructuringKind::RenamedObject,
+    };
+    let enabled = match kind {
+      DestructuringKind::Array => kinds.array,
+      DestructuringKind::Object => kinds.object,
+      DestructuringKind::RenamedObject => {
+        kinds.object && self.options.enforce_for_renamed_properties
+      }
+    };
+    if enabled {
+      Some(kind)
+    } else {
+      None
+    }
+  }
+
+  fn report(&mut self, span: Span, kind: &DestructuringKind) {
+    let message = match kind {
+      DestructuringKind::Array => PreferDestructuringMessage::Array,
+      _ => PreferDestructuringMessage::Object,
+    };
+    self.context.add_diagnostic(span, CODE, message);
+  }
+}
+
+impl<'c, 'o> Visit for PreferDestructuringVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_var_declarator(
+    &mut self,
+    var_declarator: &VarDeclarator,
+    _parent: &dyn Node,
+  ) {
+    if let (Pat::Ident(name), Some(Expr::Member(member_expr))) =
+      (&var_declarator.name, var_declarator.init.as_deref())
+    {
+      let kinds = &self.options.variable_declarator;
+      match self.check(name, member_expr, kinds) {
+        // A type annotation can't be kept in the destructuring pattern
+        Some(DestructuringKind::Object) if name.type_ann.is_none() => {
+          let obj_text = match &member_expr.obj {
+            ExprOrSuper::Expr(obj) => {
+              self.context.source_map.span_to_snippet(obj.span()).ok()
+            }
+            ExprOrSuper::Super(_) => None,
+          };
+          match obj_text {
+            Some(obj_text) => {
+              let fix = LintFix {
+                description: PreferDestructuringFix::Destructure.to_string(),
+                changes: vec![self.context.create_fix_change(
+                  var_declarator.span,
+                  format!("{{ {} }} = {}", name.sym, obj_text),
+                )],
+                priority: 0,
+              };
+              self.context.add_diagnostic_with_fix(
+                var_declarator.span,
+                CODE,
+                PreferDestructuringMessage::Object,
+                fix,
+              );
+            }
+            None => {
+              self.report(var_declarator.span, &DestructuringKind::Object)
+            }
+          }
+        }
+        Some(kind) => self.report(var_declarator.span, &kind),
+        None => {}
+      }
+    }
+    var_declarator.visit_children_with(self);
+  }
+
+  fn visit_assign_expr(
+    &mut self,
+    assign_expr: &AssignExpr,
+    _parent: &dyn Node,
+  ) {
+    if assign_expr.op == AssignOp::Assign {
+      let name = match &assign_expr.left {
+        PatOrExpr::Pat(pat) => match &**pat {
+          Pat::Ident(ident) => Some(ident),
+          Pat::Expr(expr) => match &**expr {
+            Expr::Ident(ident) => Some(ident),
+            _ => None,
+          },
+          _ => None,
+        },
+        PatOrExpr::Expr(expr) => match &**expr {
+          Expr::Ident(ident) => Some(ident),
+          _ => None,
+        },
+      };
+      if let (Some(name), Expr::Member(member_expr)) =
+        (name, &*assign_expr.right)
+      {
+        let kinds = &self.options.assignment_expression;
+        if let Some(kind) = self.check(name, member_expr, kinds) {
+          self.report(assign_expr.span, &kind);
+        }
+      }
+    }
+    assign_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn prefer_destructuring_valid() {
+    assert_lint_ok! {
+      PreferDestructuring,
+      "const { a } = obj;",
+      "const [first] = arr;",
+      "const b = obj.a;",
+      "const b = obj[key];",
+      "let a = obj;",
+      "a += obj.a;",
+      "b = obj.a;",
+      "class A extends B { m() { const a = super.a; } }",
+    };
+
+    let options = PreferDestructuringOptions {
+      variable_declarator: DestructuringKinds {
+        array: false,
+        object: true,
+      },
+      assignment_expression: DestructuringKinds {
+        array: true,
+        object: false,
+      },
+      enforce_for_renamed_properties: false,
+    };
+    let sources = ["const first = arr[0];", "a = obj.a;"];
+    for src in &sources {
+      assert_lint_ok_with_rule(
+        PreferDestructuring::with_options(options.clone()),
+        src,
+      );
+    }
+  }
+
+  #[test]
+  fn prefer_destructuring_invalid() {
+    assert_lint_err! {
+      PreferDestructuring,
+      "const a = obj.a;": [
+        {
+          col: 6,
+          message: PreferDestructuringMessage::Object,
+        }
+      ],
+      "const first = arr[0];": [
+        {
+          col: 6,
+          message: PreferDestructuringMessage::Array,
+        }
+      ],
+      "let a: number = obj.a;": [
+        {
+          col: 4,
+          message: PreferDestructuringMessage::Object,
+        }
+      ],
+      "a = obj.a;": [
+        {
+          col: 0,
+          message: PreferDestructuringMessage::Object,
+        }
+      ],
+      "first = arr[0];": [
+        {
+          col: 0,
+          message: PreferDestructuringMessage::Array,
+        }
+      ],
+    };
+
+    let options = PreferDestructuringOptions {
+      enforce_for_renamed_properties: true,
+      ..Default::default()
+    };
+    let sources = [
+      ("const b = obj.a;", 6),
+      ("const b = obj[key];", 6),
+      ("b = obj['a'];", 0),
+    ];
+    for (src, col) in &sources {
+      assert_lint_err_on_line_with_rule(
+        PreferDestructuring::with_options(options.clone()),
+        src,
+        1,
+        *col,
+      );
+    }
+  }
+
+  #[test]
+  fn prefer_destructuring_fix() {
+    assert_lint_fix::<PreferDestructuring>(
+      "const a = obj.a;",
+      "const { a } = obj;",
+    );
+    assert_lint_fix::<PreferDestructuring>(
+      "let x = 1, a = foo().bar.a;",
+      "let x = 1, { a } = foo().bar;",
+    );
+    assert_lint_fix::<PreferDestructuring>(
+      "const first = arr[0];",
+      "const first = arr[0];",
+    );
+  }
+}
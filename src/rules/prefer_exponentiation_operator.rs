@@ -0,0 +1,326 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::LintFix;
+use derive_more::Display;
+use std::collections::HashSet;
+use swc_common::{Span, Spanned};
+use swc_ecmascript::ast::{
+  AwaitExpr, BinExpr, BinaryOp, CallExpr, Expr, ExprOrSpread, ExprOrSuper, Lit,
+  MemberExpr, NewExpr, TaggedTpl, UnaryExpr,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct PreferExponentiationOperator;
+
+const CODE: &str = "prefer-exponentiation-operator";
+
+#[derive(Display)]
+enum PreferExponentiationOperatorMessage {
+  #[display(fmt = "Use the `**` operator instead of `Math.pow`")]
+  UseOperator,
+}
+
+#[derive(Display)]
+enum PreferExponentiationOperatorFix {
+  #[display(fmt = "Replace with the `**` operator")]
+  ToOperator,
+}
+
+impl LintRule for PreferExponentiationOperator {
+  fn new() -> Box<Self> {
+    Box::new(PreferExponentiationOperator)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = PreferExponentiationOperatorVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires the `**` operator instead of `Math.pow`
+
+The exponentiation operator `**` is shorter than `Math.pow` and reads like
+the other arithmetic operators.
+
+### Invalid:
+```typescript
+const square = Math.pow(a, 2);
+const cube = Math.pow(a + 1, 3);
+```
+
+### Valid:
+```typescript
+const square = a ** 2;
+const cube = (a + 1) ** 3;
+```
+"#
+  }
+}
+
+struct PreferExponentiationOperatorVisitor<'c> {
+  context: &'c mut Context,
+  /// Spans of expressions whose replacement with a `**` expression would
+  /// need parentheses, such as the object of a member expression.
+  tight_spans: HashSet<Span>,
+}
+
+impl<'c> PreferExponentiationOperatorVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self {
+      context,
+      tight_spans: HashSet::new(),
+    }
+  }
+
+  /// Checks if `call_expr` calls the global `Math.pow`.
+  fn is_math_pow(&self, call_expr: &CallExpr) -> bool {
+    let member_expr = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => match &**callee {
+        Expr::Member(member_expr) => member_expr,
+        _ => return false,
+      },
+      ExprOrSuper::Super(_) => return false,
+    };
+    let is_math = match &member_expr.obj {
+      // `Math` may be shadowed by a local binding
+      ExprOrSuper::Expr(obj) => match &**obj {
+        Expr::Ident(ident) => {
+          ident.sym == *"Math"
+            && self.context.scope().var(&ident.to_id()).is_none()
+        }
+        _ => false,
+      },
+      ExprOrSuper::Super(_) => false,
+    };
+    let is_pow = match &*member_expr.prop {
+      Expr::Ident(ident) if !member_expr.computed => ident.sym == *"pow",
+      Expr::Lit(Lit::Str(s)) if member_expr.computed => s.value == *"pow",
+      _ => false,
+    };
+    is_math && is_pow
+  }
+
+  fn mark_tight(&mut self, expr: &Expr) {
+    if let Expr::Call(call_expr) = expr {
+      self.tight_spans.insert(call_expr.span);
+    }
+  }
+
+  /// Builds `base ** exponent` from the arguments of a `Math.pow` call.
+  fn to_operator(&self, call_expr: &CallExpr) -> Option<String> {
+    let (base, exponent) = match call_expr.args.as_slice() {
+      [ExprOrSpread {
+        spread: None,
+        expr: base,
+      }, ExprOrSpread {
+        spread: None,
+        expr: exponent,
+      }] => (base, exponent),
+      _ => return None,
+    };
+    let source_map = &self.context.source_map;
+    let mut base_text = source_map.span_to_snippet(base.span()).ok()?;
+    if base_needs_parens(base) {
+      base_text = format!("({})", base_text);
+    }
+    let mut exponent_text = source_map.span_to_snippet(exponent.span()).ok()?;
+    if exponent_needs_parens(exponent) {
+      exponent_text = format!("({})", exponent_text);
+    }
+    let text = format!("{} ** {}", base_text, exponent_text);
+    if self.tight_spans.contains(&call_expr.span) {
+      Some(format!("({})", text))
+    } else {
+      Some(text)
+    }
+  }
+}
+
+/// Checks if `expr` must be parenthesized as the left operand of `**`. Unary
+/// expressions aren't allowed there, and `**` is right-associative.
+fn base_needs_parens(expr: &Expr) -> bool {
+  match expr {
+    Expr::Ident(_)
+    | Expr::Lit(_)
+    | Expr::Member(_)
+    | Expr::Call(_)
+    | Expr::New(_)
+    | Expr::Paren(_)
+    | Expr::Array(_)
+    | Expr::Object(_)
+    | Expr::Tpl(_)
+    | Expr::TaggedTpl(_)
+    | Expr::This(_)
+    | Expr::Update(_)
+    | Expr::OptChain(_)
+    | Expr::MetaProp(_) => false,
+    _ => true,
+  }
+}
+
+/// Checks if `expr` must be parenthesized as the right operand of `**`.
+fn exponent_needs_parens(expr: &Expr) -> bool {
+  match expr {
+    Expr::Bin(bin_expr) => bin_expr.op != BinaryOp::Exp,
+    Expr::Cond(_)
+    | Expr::Assign(_)
+    | Expr::Seq(_)
+    | Expr::Arrow(_)
+    | Expr::Yield(_)
+    | Expr::TsAs(_) => true,
+    _ => false,
+  }
+}
+
+impl<'c> Visit for PreferExponentiationOperatorVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+      self.mark_tight(callee);
+    }
+
+    if self.is_math_pow(call_expr) {
+      match self.to_operator(call_expr) {
+        Some(new_text) => {
+          let fix = LintFix {
+            description: PreferExponentiationOperatorFix::ToOperator
+              .to_string(),
+            changes: vec![self
+              .context
+              .create_fix_change(call_expr.span, new_text)],
+            priority: 0,
+          };
+          self.context.add_diagnostic_with_fix(
+            call_expr.span,
+            CODE,
+            PreferExponentiationOperatorMessage::UseOperator,
+            fix,
+          );
+        }
+        None => self.context.add_diagnostic(
+          call_expr.span,
+          CODE,
+          PreferExponentiationOperatorMessage::UseOperator,
+        ),
+      }
+    }
+    call_expr.visit_children_with(self);
+  }
+
+  fn visit_member_expr(
+    &mut self,
+    member_expr: &MemberExpr,
+    _parent: &dyn Node,
+  ) {
+    if let ExprOrSuper::Expr(obj) = &member_expr.obj {
+      self.mark_tight(obj);
+    }
+    member_expr.visit_children_with(self);
+  }
+
+  fn visit_new_expr(&mut self, new_expr: &NewExpr, _parent: &dyn Node) {
+    self.mark_tight(&new_expr.callee);
+    new_expr.visit_children_with(self);
+  }
+
+  fn visit_tagged_tpl(&mut self, tagged_tpl: &TaggedTpl, _parent: &dyn Node) {
+    self.mark_tight(&tagged_tpl.tag);
+    tagged_tpl.visit_children_with(self);
+  }
+
+  fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr, _parent: &dyn Node) {
+    self.mark_tight(&unary_expr.arg);
+    unary_expr.visit_children_with(self);
+  }
+
+  fn visit_await_expr(&mut self, await_expr: &AwaitExpr, _parent: &dyn Node) {
+    self.mark_tight(&await_expr.arg);
+    await_expr.visit_children_with(self);
+  }
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _parent: &dyn Node) {
+    if bin_expr.op == BinaryOp::Exp {
+      self.mark_tight(&bin_expr.left);
+    }
+    bin_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn prefer_exponentiation_operator_valid() {
+    assert_lint_ok! {
+      PreferExponentiationOperator,
+      "const a = b ** c;",
+      "const a = Math.max(b, c);",
+      "const a = pow(b, c);",
+      "const a = foo.Math.pow(b, c);",
+      "const Math = { pow() {} }; Math.pow(a, b);",
+      "function f(Math) { return Math.pow(a, b); }",
+    };
+  }
+
+  #[test]
+  fn prefer_exponentiation_operator_invalid() {
+    assert_lint_err! {
+      PreferExponentiationOperator,
+      "const a = Math.pow(b, c);": [
+        {
+          col: 10,
+          message: PreferExponentiationOperatorMessage::UseOperator,
+        }
+      ],
+      "Math['pow'](b, c);": [
+        {
+          col: 0,
+          message: PreferExponentiationOperatorMessage::UseOperator,
+        }
+      ],
+      "Math.pow(...args);": [
+        {
+          col: 0,
+          message: PreferExponentiationOperatorMessage::UseOperator,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_exponentiation_operator_fix() {
+    let cases = [
+      ("Math.pow(a, b);", "a ** b;"),
+      ("Math.pow(a + 1, 2);", "(a + 1) ** 2;"),
+      ("Math.pow(2, a + 1);", "2 ** (a + 1);"),
+      ("Math.pow(-a, 2);", "(-a) ** 2;"),
+      ("Math.pow(a, -2);", "a ** -2;"),
+      ("Math.pow(a ** b, c);", "(a ** b) ** c;"),
+      ("Math.pow(a, b ** c);", "a ** b ** c;"),
+      ("Math.pow(a.b, f(c));", "a.b ** f(c);"),
+      ("x * Math.pow(a, b);", "x * a ** b;"),
+      ("Math.pow(a, b).toFixed();", "(a ** b).toFixed();"),
+      ("-Math.pow(a, b);", "-(a ** b);"),
+      ("Math.pow(...args);", "Math.pow(...args);"),
+    ];
+    for (src, expected) in &cases {
+      assert_lint_fix::<PreferExponentiationOperator>(src, expected);
+    }
+  }
+}
@@ -0,0 +1,231 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::LintFix;
+use derive_more::Display;
+use swc_ecmascript::ast::{Expr, Ident, MemberExpr};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct PreferNumberProperties;
+
+const CODE: &str = "prefer-number-properties";
+
+#[derive(Display)]
+enum PreferNumberPropertiesMessage {
+  #[display(fmt = "Use `Number.{}` instead of the global `{}`", _0, _0)]
+  Unexpected(String),
+}
+
+#[derive(Display)]
+enum PreferNumberPropertiesHint {
+  #[display(
+    fmt = "`Number.{}` doesn't coerce its argument to a number, so make sure the argument is already a number",
+    _0
+  )]
+  NoCoercion(String),
+}
+
+#[derive(Display)]
+enum PreferNumberPropertiesFix {
+  #[display(fmt = "Replace with `Number.{}`", _0)]
+  UseNumberProperty(String),
+}
+
+impl LintRule for PreferNumberProperties {
+  fn new() -> Box<Self> {
+    Box::new(PreferNumberProperties)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor = PreferNumberPropertiesVisitor::new(context);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Prefers `Number` static properties over the equivalent globals
+
+ES2015 moved `parseInt`, `parseFloat`, `isNaN` and `isFinite` to the `Number`
+namespace. Using `Number.*` consistently makes it clear where these functions
+come from.
+
+`parseInt` and `parseFloat` are fixed automatically. `Number.isNaN` and
+`Number.isFinite` don't coerce their argument to a number, unlike the globals,
+so they are reported without a fix.
+
+### Invalid:
+```typescript
+const n = parseInt("10", 10);
+const f = parseFloat(str);
+if (isNaN(n)) {}
+if (isFinite(n)) {}
+```
+
+### Valid:
+```typescript
+const n = Number.parseInt("10", 10);
+const f = Number.parseFloat(str);
+if (Number.isNaN(n)) {}
+if (Number.isFinite(n)) {}
+```
+"#
+  }
+}
+
+struct PreferNumberPropertiesVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> PreferNumberPropertiesVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+
+  fn check_ident(&mut self, ident: &Ident) {
+    let name = ident.sym.as_ref();
+    let is_fixable = match name {
+      "parseInt" | "parseFloat" => true,
+      "isNaN" | "isFinite" => false,
+      _ => return,
+    };
+    // The global may be shadowed by a local binding
+    if self.context.scope().var(&ident.to_id()).is_some() {
+      return;
+    }
+
+    let message = PreferNumberPropertiesMessage::Unexpected(name.to_string());
+    if is_fixable {
+      let fix = LintFix {
+        description: PreferNumberPropertiesFix::UseNumberProperty(
+          name.to_string(),
+        )
+        .to_string(),
+        changes: vec![self
+          .context
+          .create_fix_change(ident.span, format!("Number.{}", name))],
+        priority: 0,
+      };
+      self
+        .context
+        .add_diagnostic_with_fix(ident.span, CODE, message, fix);
+    } else {
+      self.context.add_diagnostic_with_hint(
+        ident.span,
+        CODE,
+        message,
+        PreferNumberPropertiesHint::NoCoercion(name.to_string()),
+      );
+    }
+  }
+}
+
+impl<'c> Visit for PreferNumberPropertiesVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_expr(&mut self, expr: &Expr, _parent: &dyn Node) {
+    if let Expr::Ident(ident) = expr {
+      self.check_ident(ident);
+    }
+    expr.visit_children_with(self);
+  }
+
+  fn visit_member_expr(
+    &mut self,
+    member_expr: &MemberExpr,
+    _parent: &dyn Node,
+  ) {
+    member_expr.obj.visit_with(member_expr, self);
+    // `foo.parseInt` is a property access, not a reference to the global
+    if member_expr.computed {
+      member_expr.prop.visit_with(member_expr, self);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn prefer_number_properties_valid() {
+    assert_lint_ok! {
+      PreferNumberProperties,
+      "Number.parseInt(x);",
+      "Number.parseFloat(x);",
+      "Number.isNaN(x);",
+      "Number.isFinite(x);",
+      "foo.parseInt(x);",
+      "const { parseInt } = Number; parseInt(x);",
+      "function f(isNaN) { return isNaN(x); }",
+      "const parseFloat = (s) => 0; parseFloat(x);",
+      "const o = { parseInt: 1 };",
+    };
+  }
+
+  #[test]
+  fn prefer_number_properties_invalid() {
+    assert_lint_err! {
+      PreferNumberProperties,
+      "parseInt(x);": [
+        {
+          col: 0,
+          message: variant!(PreferNumberPropertiesMessage, Unexpected, "parseInt"),
+        }
+      ],
+      "const n = parseFloat(x);": [
+        {
+          col: 10,
+          message: variant!(PreferNumberPropertiesMessage, Unexpected, "parseFloat"),
+        }
+      ],
+      "[\"1\", \"2\"].map(parseInt);": [
+        {
+          col: 15,
+          message: variant!(PreferNumberPropertiesMessage, Unexpected, "parseInt"),
+        }
+      ],
+      "if (isNaN(x)) {}": [
+        {
+          col: 4,
+          message: variant!(PreferNumberPropertiesMessage, Unexpected, "isNaN"),
+          hint: variant!(PreferNumberPropertiesHint, NoCoercion, "isNaN"),
+        }
+      ],
+      "foo[isFinite(x)];": [
+        {
+          col: 4,
+          message: variant!(PreferNumberPropertiesMessage, Unexpected, "isFinite"),
+          hint: variant!(PreferNumberPropertiesHint, NoCoercion, "isFinite"),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_number_properties_fix() {
+    assert_lint_fix::<PreferNumberProperties>(
+      "parseInt(x);",
+      "Number.parseInt(x);",
+    );
+    assert_lint_fix::<PreferNumberProperties>(
+      "const f = parseFloat(a) + parseInt(b, 10);",
+      "const f = Number.parseFloat(a) + Number.parseInt(b, 10);",
+    );
+    assert_lint_fix::<PreferNumberProperties>(
+      "if (isNaN(x)) {}",
+      "if (isNaN(x)) {}",
+    );
+  }
+}
@@ -0,0 +1,285 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::LintFix;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_ecmascript::ast::Number;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+
+pub struct PreferNumericSeparators {
+  options: PreferNumericSeparatorsOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct PreferNumericSeparatorsOptions {
+  /// Number of digits from which a run of digits needs separators.
+  pub minimum_digits: usize,
+}
+
+impl Default for PreferNumericSeparatorsOptions {
+  fn default() -> Self {
+    PreferNumericSeparatorsOptions { minimum_digits: 5 }
+  }
+}
+
+const CODE: &str = "prefer-numeric-separators";
+
+#[derive(Display)]
+enum PreferNumericSeparatorsMessage {
+  #[display(fmt = "Long numeric literals should use separators")]
+  MissingSeparators,
+}
+
+#[derive(Display)]
+enum PreferNumericSeparatorsFix {
+  #[display(fmt = "Insert numeric separators")]
+  InsertSeparators,
+}
+
+impl PreferNumericSeparators {
+  pub fn with_options(options: PreferNumericSeparatorsOptions) -> Box<Self> {
+    Box::new(PreferNumericSeparators { options })
+  }
+}
+
+impl LintRule for PreferNumericSeparators {
+  fn new() -> Box<Self> {
+    PreferNumericSeparators::with_options(
+      PreferNumericSeparatorsOptions::default(),
+    )
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "minimumDigits": {
+          "type": "integer",
+          "minimum": 1,
+          "description": "Number of digits from which a run of digits needs separators.",
+          "default": 5
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor =
+      PreferNumericSeparatorsVisitor::new(context, self.options.minimum_digits);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires separators in long numeric literals
+
+Numeric separators (`_`) make long numbers readable at a glance. This rule
+reports numeric literals with a run of at least `minimumDigits` digits
+(5 by default) and no separators. Decimal digits are grouped by thousands,
+binary, octal and hexadecimal digits by four.
+
+### Invalid:
+```typescript
+const million = 1000000;
+const mask = 0xFFFFFFFF;
+const pi = 3.14159265;
+```
+
+### Valid:
+```typescript
+const million = 1_000_000;
+const mask = 0xFFFF_FFFF;
+const pi = 3.141_592_65;
+const small = 1000;
+```
+"#
+  }
+}
+
+struct PreferNumericSeparatorsVisitor<'c> {
+  context: &'c mut Context,
+  minimum_digits: usize,
+}
+
+impl<'c> PreferNumericSeparatorsVisitor<'c> {
+  fn new(context: &'c mut Context, minimum_digits: usize) -> Self {
+    Self {
+      context,
+      minimum_digits,
+    }
+  }
+}
+
+/// Inserts separators into `raw`, the source of a numeric literal without
+/// separators. Returns `None` for literals that can't have separators.
+fn add_separators(raw: &str, minimum_digits: usize) -> Option<String> {
+  let prefix = raw.get(..2).map(|prefix| prefix.to_ascii_lowercase());
+  if let Some("0x") | Some("0b") | Some("0o") = prefix.as_deref() {
+    let digits = group_from_end(&raw[2..], 4, minimum_digits);
+    return Some(format!("{}{}", &raw[..2], digits));
+  }
+
+  // Legacy octal literals like `0755` don't allow separators
+  let is_legacy_octal =
+    raw.len() > 1 && raw.starts_with('0') && raw.as_bytes()[1].is_ascii_digit();
+  if is_legacy_octal {
+    return None;
+  }
+
+  let (mantissa, exponent) = match raw.find(|c| c == 'e' || c == 'E') {
+    Some(index) => raw.split_at(index),
+    None => (raw, ""),
+  };
+  let (integer, fraction) = match mantissa.find('.') {
+    Some(index) => mantissa.split_at(index),
+    None => (mantissa, ""),
+  };
+  let mut result = group_from_end(integer, 3, minimum_digits);
+  if !fraction.is_empty() {
+    result.push('.');
+    result.push_str(&group_from_start(&fraction[1..], 3, minimum_digits));
+  }
+  result.push_str(exponent);
+  Some(result)
+}
+
+fn group_from_end(digits: &str, size: usize, minimum_digits: usize) -> String {
+  if digits.len() < minimum_digits {
+    return digits.to_string();
+  }
+  let mut result = String::new();
+  for (i, c) in digits.chars().enumerate() {
+    if i > 0 && (digits.len() - i) % size == 0 {
+      result.push('_');
+    }
+    result.push(c);
+  }
+  result
+}
+
+fn group_from_start(
+  digits: &str,
+  size: usize,
+  minimum_digits: usize,
+) -> String {
+  if digits.len() < minimum_digits {
+    return digits.to_string();
+  }
+  let mut result = String::new();
+  for (i, c) in digits.chars().enumerate() {
+    if i > 0 && i % size == 0 {
+      result.push('_');
+    }
+    result.push(c);
+  }
+  result
+}
+
+impl<'c> Visit for PreferNumericSeparatorsVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_number(&mut self, number: &Number, _parent: &dyn Node) {
+    let raw = match self.context.source_map.span_to_snippet(number.span) {
+      Ok(raw) => raw,
+      Err(_) => return,
+    };
+    if raw.contains('_') {
+      return;
+    }
+    let fixed = match add_separators(&raw, self.minimum_digits) {
+      Some(fixed) if fixed != raw => fixed,
+      _ => return,
+    };
+    let fix = LintFix {
+      description: PreferNumericSeparatorsFix::InsertSeparators.to_string(),
+      changes: vec![self.context.create_fix_change(number.span, fixed)],
+      priority: 0,
+    };
+    self.context.add_diagnostic_with_fix(
+      number.span,
+      CODE,
+      PreferNumericSeparatorsMessage::MissingSeparators,
+      fix,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn prefer_numeric_separators_valid() {
+    assert_lint_ok! {
+      PreferNumericSeparators,
+      "const a = 1000;",
+      "const a = 1_000_000;",
+      "const a = 1.5;",
+      "const a = 0xFF;",
+      "const a = 10_00000;",
+      "const a = 1e10;",
+    };
+
+    assert_lint_ok_with_rule(
+      PreferNumericSeparators::with_options(PreferNumericSeparatorsOptions {
+        minimum_digits: 8,
+      }),
+      "const a = 1000000;",
+    );
+  }
+
+  #[test]
+  fn prefer_numeric_separators_invalid() {
+    assert_lint_err! {
+      PreferNumericSeparators,
+      "const a = 1000000;": [
+        {
+          col: 10,
+          message: PreferNumericSeparatorsMessage::MissingSeparators,
+        }
+      ],
+      "f(0xFFFFFFFF);": [
+        {
+          col: 2,
+          message: PreferNumericSeparatorsMessage::MissingSeparators,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_numeric_separators_fix() {
+    let cases = [
+      ("1000000;", "1_000_000;"),
+      ("10000;", "10_000;"),
+      ("123456.7891;", "123_456.7891;"),
+      ("3.14159265;", "3.141_592_65;"),
+      ("1234567e10;", "1_234_567e10;"),
+      ("0xFFFFFFFF;", "0xFFFF_FFFF;"),
+      ("0XABCDE;", "0XA_BCDE;"),
+      ("0b1010101010;", "0b10_1010_1010;"),
+      ("0o7777777;", "0o777_7777;"),
+    ];
+    for (src, expected) in &cases {
+      assert_lint_fix::<PreferNumericSeparators>(src, expected);
+    }
+  }
+}
@@ -0,0 +1,384 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::scopes::BindingKind;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use swc_ecmascript::ast::{
+  AssignOp, BinaryOp, CallExpr, Expr, ExprOrSuper, Lit, NewExpr, Pat,
+};
+use swc_ecmascript::utils::ident::IdentLike;
+use swc_ecmascript::utils::Id;
+use swc_ecmascript::visit::noop_visit_type;
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct PreferPromiseRejectErrors {
+  options: PreferPromiseRejectErrorsOptions,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct PreferPromiseRejectErrorsOptions {
+  /// Allow rejecting without any reason, e.g. `Promise.reject()`.
+  pub allow_empty_reject: bool,
+}
+
+const CODE: &str = "prefer-promise-reject-errors";
+
+#[derive(Display)]
+enum PreferPromiseRejectErrorsMessage {
+  #[display(fmt = "Expected the Promise rejection reason to be an Error")]
+  NotError,
+}
+
+#[derive(Display)]
+enum PreferPromiseRejectErrorsHint {
+  #[display(fmt = "Reject with an `Error` object, e.g. `new Error(\"...\")`")]
+  RejectWithError,
+}
+
+impl PreferPromiseRejectErrors {
+  pub fn with_options(options: PreferPromiseRejectErrorsOptions) -> Box<Self> {
+    Box::new(PreferPromiseRejectErrors { options })
+  }
+}
+
+impl LintRule for PreferPromiseRejectErrors {
+  fn new() -> Box<Self> {
+    PreferPromiseRejectErrors::with_options(
+      PreferPromiseRejectErrorsOptions::default(),
+    )
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "allowEmptyReject": {
+          "type": "boolean",
+          "description": "Allow rejecting without any reason, e.g. `Promise.reject()`.",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor =
+      PreferPromiseRejectErrorsVisitor::new(context, &self.options);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Requires Promises to be rejected with `Error` objects
+
+Code handling a rejected Promise usually expects an `Error`, and only errors
+carry a stack trace showing where they were created. This rule reports calls to
+`Promise.reject()` and to the `reject` parameter of a Promise executor with a
+reason that can't be an error, like a literal or `undefined`.
+
+With the `allowEmptyReject` option, rejecting without any reason is allowed.
+
+### Invalid:
+```typescript
+Promise.reject("fail");
+Promise.reject();
+new Promise((resolve, reject) => reject(404));
+```
+
+### Valid:
+```typescript
+Promise.reject(new Error("fail"));
+Promise.reject(err);
+new Promise((resolve, reject) => reject(new TypeError("fail")));
+```
+"#
+  }
+}
+
+struct PreferPromiseRejectErrorsVisitor<'c, 'o> {
+  context: &'c mut Context,
+  options: &'o PreferPromiseRejectErrorsOptions,
+  /// `reject` parameters of the Promise executors found so far.
+  reject_ids: HashSet<Id>,
+}
+
+impl<'c, 'o> PreferPromiseRejectErrorsVisitor<'c, 'o> {
+  fn new(
+    context: &'c mut Context,
+    options: &'o PreferPromiseRejectErrorsOptions,
+  ) -> Self {
+    Self {
+      context,
+      options,
+      reject_ids: HashSet::new(),
+    }
+  }
+
+  /// Checks if `expr` is the global `Promise`.
+  fn is_global_promise(&self, expr: &Expr) -> bool {
+    match expr {
+      Expr::Ident(ident) => {
+        ident.sym == *"Promise"
+          && self.context.scope().var(&ident.to_id()).is_none()
+      }
+      _ => false,
+    }
+  }
+
+  /// Checks if `callee` is `Promise.reject` or the `reject` parameter of an
+  /// executor.
+  fn is_reject(&self, callee: &Expr) -> bool {
+    match callee {
+      Expr::Ident(ident) => {
+        let id = ident.to_id();
+        // A declaration in the executor's body may shadow the parameter
+        self.reject_ids.contains(&id)
+          && self
+            .context
+            .scope()
+            .var(&id)
+            .map_or(false, |var| var.kind() == BindingKind::Param)
+      }
+      Expr::Member(member_expr) => {
+        let is_promise = match &member_expr.obj {
+          ExprOrSuper::Expr(obj) => self.is_global_promise(obj),
+          ExprOrSuper::Super(_) => false,
+        };
+        let is_reject = match &*member_expr.prop {
+          Expr::Ident(ident) if !member_expr.computed => ident.sym == *"reject",
+          Expr::Lit(Lit::Str(s)) if member_expr.computed => {
+            s.value == *"reject"
+          }
+          _ => false,
+        };
+        is_promise && is_reject
+      }
+      Expr::Paren(paren_expr) => self.is_reject(&paren_expr.expr),
+      _ => false,
+    }
+  }
+}
+
+/// Returns the `reject` parameter of `executor` if it's a plain identifier.
+fn reject_param(executor: &Expr) -> Option<&Pat> {
+  match executor {
+    Expr::Fn(fn_expr) => fn_expr.function.params.get(1).map(|param| &param.pat),
+    Expr::Arrow(arrow_expr) => arrow_expr.params.get(1),
+    Expr::Paren(paren_expr) => reject_param(&paren_expr.expr),
+    _ => None,
+  }
+}
+
+/// Checks if `expr` may evaluate to an error object.
+fn could_be_error(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(_)
+    | Expr::Object(_)
+    | Expr::Array(_)
+    | Expr::Tpl(_)
+    | Expr::Fn(_)
+    | Expr::Arrow(_)
+    | Expr::Class(_)
+    | Expr::Unary(_)
+    | Expr::Update(_) => false,
+    Expr::Ident(ident) => ident.sym != *"undefined",
+    Expr::Bin(bin_expr) => match bin_expr.op {
+      BinaryOp::LogicalAnd => could_be_error(&bin_expr.right),
+      BinaryOp::LogicalOr | BinaryOp::NullishCoalescing => {
+        could_be_error(&bin_expr.left) || could_be_error(&bin_expr.right)
+      }
+      _ => false,
+    },
+    Expr::Assign(assign_expr) => match assign_expr.op {
+      AssignOp::Assign | AssignOp::AndAssign => {
+        could_be_error(&assign_expr.right)
+      }
+      AssignOp::OrAssign | AssignOp::NullishAssign => true,
+      _ => false,
+    },
+    Expr::Seq(seq_expr) => seq_expr
+      .exprs
+      .last()
+      .map_or(true, |last| could_be_error(last)),
+    Expr::Cond(cond_expr) => {
+      could_be_error(&cond_expr.cons) || could_be_error(&cond_expr.alt)
+    }
+    Expr::Paren(paren_expr) => could_be_error(&paren_expr.expr),
+    _ => true,
+  }
+}
+
+impl<'c, 'o> Visit for PreferPromiseRejectErrorsVisitor<'c, 'o> {
+  noop_visit_type!();
+
+  fn visit_new_expr(&mut self, new_expr: &NewExpr, _parent: &dyn Node) {
+    if self.is_global_promise(&new_expr.callee) {
+      let executor = new_expr.args.as_ref().and_then(|args| args.first());
+      if let Some(executor) = executor {
+        if let Some(Pat::Ident(ident)) = reject_param(&executor.expr) {
+          self.reject_ids.insert(ident.to_id());
+        }
+      }
+    }
+    new_expr.visit_children_with(self);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    let is_reject = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => self.is_reject(callee),
+      ExprOrSuper::Super(_) => false,
+    };
+    if is_reject {
+      let is_error = match call_expr.args.first() {
+        None => self.options.allow_empty_reject,
+        // A spread may contain anything
+        Some(arg) if arg.spread.is_some() => true,
+        Some(arg) => could_be_error(&arg.expr),
+      };
+      if !is_error {
+        self.context.add_diagnostic_with_hint(
+          call_expr.span,
+          CODE,
+          PreferPromiseRejectErrorsMessage::NotError,
+          PreferPromiseRejectErrorsHint::RejectWithError,
+        );
+      }
+    }
+    call_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn prefer_promise_reject_errors_valid() {
+    assert_lint_ok! {
+      PreferPromiseRejectErrors,
+      "Promise.reject(new Error('fail'));",
+      "Promise.reject(err);",
+      "Promise.reject(createError());",
+      "Promise.reject(foo.error);",
+      "Promise.reject(cond ? new Error('a') : 'b');",
+      "Promise.reject(err || new Error('fail'));",
+      "Promise.reject(...args);",
+      "Promise.resolve('fine');",
+      "foo.reject('fail');",
+      "reject('fail');",
+      "new Promise((resolve, reject) => reject(new Error('fail')));",
+      "new Promise(function (resolve, reject) { reject(err); });",
+      "new Promise((resolve, reject) => { { const reject = f; reject('x'); } });",
+      "new Promise(function (resolve, reject) { function reject() {} reject('x'); });",
+      "new Promise((resolve, { reject }) => reject('fail'));",
+      "new Foo((resolve, reject) => reject('fail'));",
+      "const Promise = Foo; Promise.reject('fail');",
+    };
+
+    let options = PreferPromiseRejectErrorsOptions {
+      allow_empty_reject: true,
+    };
+    let sources = [
+      "Promise.reject();",
+      "new Promise((resolve, reject) => reject());",
+    ];
+    for src in &sources {
+      assert_lint_ok_with_rule(
+        PreferPromiseRejectErrors::with_options(options.clone()),
+        src,
+      );
+    }
+  }
+
+  #[test]
+  fn prefer_promise_reject_errors_invalid() {
+    assert_lint_err! {
+      PreferPromiseRejectErrors,
+      "Promise.reject('fail');": [
+        {
+          col: 0,
+          message: PreferPromiseRejectErrorsMessage::NotError,
+          hint: PreferPromiseRejectErrorsHint::RejectWithError,
+        }
+      ],
+      "Promise.reject();": [
+        {
+          col: 0,
+          message: PreferPromiseRejectErrorsMessage::NotError,
+          hint: PreferPromiseRejectErrorsHint::RejectWithError,
+        }
+      ],
+      "Promise['reject'](undefined);": [
+        {
+          col: 0,
+          message: PreferPromiseRejectErrorsMessage::NotError,
+          hint: PreferPromiseRejectErrorsHint::RejectWithError,
+        }
+      ],
+      "Promise.reject(`fail: ${reason}`);": [
+        {
+          col: 0,
+          message: PreferPromiseRejectErrorsMessage::NotError,
+          hint: PreferPromiseRejectErrorsHint::RejectWithError,
+        }
+      ],
+      "Promise.reject(a && 'fail');": [
+        {
+          col: 0,
+          message: PreferPromiseRejectErrorsMessage::NotError,
+          hint: PreferPromiseRejectErrorsHint::RejectWithError,
+        }
+      ],
+      "new Promise((_, reject) => reject('x'));": [
+        {
+          col: 27,
+          message: PreferPromiseRejectErrorsMessage::NotError,
+          hint: PreferPromiseRejectErrorsHint::RejectWithError,
+        }
+      ],
+      "new Promise(function (resolve, reject) { reject({ code: 1 }); });": [
+        {
+          col: 41,
+          message: PreferPromiseRejectErrorsMessage::NotError,
+          hint: PreferPromiseRejectErrorsHint::RejectWithError,
+        }
+      ],
+      "new Promise((resolve, reject) => { setTimeout(() => reject(), 10); });": [
+        {
+          col: 52,
+          message: PreferPromiseRejectErrorsMessage::NotError,
+          hint: PreferPromiseRejectErrorsHint::RejectWithError,
+        }
+      ],
+    };
+
+    let options = PreferPromiseRejectErrorsOptions {
+      allow_empty_reject: true,
+    };
+    assert_lint_err_on_line_with_rule(
+      PreferPromiseRejectErrors::with_options(options),
+      "Promise.reject(null);",
+      1,
+      0,
+    );
+  }
+}
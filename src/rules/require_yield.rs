@@ -11,6 +11,7 @@ use swc_ecmascript::ast::YieldExpr;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
 
 pub struct RequireYield;
 
@@ -38,6 +39,31 @@ impl LintRule for RequireYield {
     let mut visitor = RequireYieldVisitor::new(context);
     visitor.visit_program(program, program);
   }
+
+  fn docs(&self) -> &'static str {
+    r#"Disallows generator functions that have no `yield`
+
+A generator function that never yields is probably a mistake, such as a
+regular function declared with `*` by accident. Generators with an empty body
+are allowed, since they're commonly used as placeholders. A `yield` inside a
+nested generator doesn't count for the enclosing one.
+
+### Invalid:
+```typescript
+function* foo() {
+  return "foo";
+}
+```
+
+### Valid:
+```typescript
+function* foo() {
+  yield "foo";
+}
+function* bar() {}
+```
+"#
+  }
 }
 
 struct RequireYieldVisitor<'c> {
@@ -77,10 +103,12 @@ impl<'c> RequireYieldVisitor<'c> {
 impl<'c> Visit for RequireYieldVisitor<'c> {
   noop_visit_type!();
 
-  fn visit_yield_expr(&mut self, _yield_expr: &YieldExpr, _parent: &dyn Node) {
+  fn visit_yield_expr(&mut self, yield_expr: &YieldExpr, _parent: &dyn Node) {
     if let Some(last) = self.yield_stack.last_mut() {
       *last += 1;
     }
+    // The argument may contain generators of its own
+    yield_expr.visit_children_with(self);
   }
 
   fn visit_fn_decl(&mut self, fn_decl: &FnDecl, parent: &dyn Node) {
@@ -132,7 +160,7 @@ mod tests {
       RequireYield,
       r#"
 function foo() {}
-function* bar() { 
+function* bar() {
   yield "bar";
 }
 function* emptyBar() {}
@@ -163,6 +191,8 @@ const obj = {
       r#"function* bar() { return "bar"; }"#: [{ col: 0, message: MESSAGE }],
       r#"(function* foo() { return "foo"; })();"#: [{ col: 1, message: MESSAGE }],
       r#"function* nested() { function* gen() { yield "gen"; } }"#: [{ col: 0, message: MESSAGE }],
+      r#"function* outer() { yield* (function* () { return 1; })(); }"#: [{ col: 28, message: MESSAGE }],
+      r#"function* outer() { const f = () => 1; return f(); }"#: [{ col: 0, message: MESSAGE }],
       r#"const obj = { *foo() { return "foo"; } };"#: [{ col: 14, message: MESSAGE }],
       r#"
 class Fizz {
@@ -0,0 +1,279 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use super::Context;
+use super::LintRule;
+use crate::diagnostic::LintFix;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swc_common::Spanned;
+use swc_ecmascript::ast::{TsIntersectionType, TsType, TsUnionType};
+use swc_ecmascript::visit::Node;
+use swc_ecmascript::visit::Visit;
+use swc_ecmascript::visit::VisitWith;
+
+pub struct SortTypeConstituents {
+  options: SortTypeConstituentsOptions,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct SortTypeConstituentsOptions {
+  /// Places literal types (e.g. `"foo"`, `1`, `true`) after all other types.
+  pub literals_last: bool,
+}
+
+impl Default for SortTypeConstituentsOptions {
+  fn default() -> Self {
+    Self {
+      literals_last: true,
+    }
+  }
+}
+
+const CODE: &str = "sort-type-constituents";
+
+#[derive(Display)]
+enum SortTypeConstituentsMessage {
+  #[display(fmt = "Union type constituents must be sorted")]
+  Union,
+  #[display(fmt = "Intersection type constituents must be sorted")]
+  Intersection,
+}
+
+#[derive(Display)]
+enum SortTypeConstituentsFix {
+  #[display(fmt = "Sort the type constituents")]
+  Sort,
+}
+
+impl SortTypeConstituents {
+  pub fn with_options(options: SortTypeConstituentsOptions) -> Box<Self> {
+    Box::new(SortTypeConstituents { options })
+  }
+}
+
+impl LintRule for SortTypeConstituents {
+  fn new() -> Box<Self> {
+    SortTypeConstituents::with_options(SortTypeConstituentsOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options(&self) -> Option<Value> {
+    serde_json::to_value(&self.options).ok()
+  }
+
+  fn options_schema(&self) -> Option<Value> {
+    Some(json!({
+      "type": "object",
+      "properties": {
+        "literalsLast": {
+          "type": "boolean",
+          "description": "Places literal types (e.g. `\"foo\"`, `1`, `true`) after all other types.",
+          "default": true
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn lint_program(
+    &self,
+    context: &mut Context,
+    program: &swc_ecmascript::ast::Program,
+  ) {
+    let mut visitor =
+      SortTypeConstituentsVisitor::new(context, self.options.literals_last);
+    visitor.visit_program(program, program);
+  }
+
+  fn docs(&self) -> &'static str {
+    r#"Enforces sorted members of union and intersection types
+
+Keeping constituents of large union and intersection types sorted makes them
+easier to scan and reduces merge conflicts. Constituents are sorted
+alphabetically, with literal types placed last unless `literalsLast` option
+is disabled.
+
+### Invalid:
+```typescript
+type T1 = string | boolean | number;
+type T2 = "b" | "a" | Foo;
+type T3 = C & B & A;
+```
+
+### Valid:
+```typescript
+type T1 = boolean | number | string;
+type T2 = Foo | "a" | "b";
+type T3 = A & B & C;
+```
+"#
+  }
+}
+
+struct SortTypeConstituentsVisitor<'c> {
+  context: &'c mut Context,
+  literals_last: bool,
+}
+
+impl<'c> SortTypeConstituentsVisitor<'c> {
+  fn new(context: &'c mut Context, literals_last: bool) -> Self {
+    Self {
+      context,
+      literals_last,
+    }
+  }
+
+  fn check_constituents(
+    &mut self,
+    types: &[Box<TsType>],
+    message: SortTypeConstituentsMessage,
+  ) {
+    let mut texts = Vec::with_capacity(types.len());
+    for ty in types {
+      match self.context.source_map.span_to_snippet(ty.span()) {
+        Ok(text) => texts.push(text),
+        Err(_) => return,
+      }
+    }
+
+    let literals_last = self.literals_last;
+    let mut order: Vec<usize> = (0..types.len()).collect();
+    order.sort_by_key(|&i| {
+      let is_literal = matches!(&*types[i], TsType::TsLitType(_));
+      (literals_last && is_literal, texts[i].to_lowercase())
+    });
+
+    let first_unsorted =
+      match order.iter().enumerate().position(|(pos, &i)| pos != i) {
+        Some(pos) => pos,
+        None => return,
+      };
+
+    let changes = order
+      .iter()
+      .enumerate()
+      .filter(|&(pos, &i)| pos != i)
+      .map(|(pos, &i)| {
+        self
+          .context
+          .create_fix_change(types[pos].span(), texts[i].clone())
+      })
+      .collect();
+    let fix = LintFix {
+      description: SortTypeConstituentsFix::Sort.to_string(),
+      changes,
+      priority: 0,
+    };
+
+    self.context.add_diagnostic_with_fix(
+      types[first_unsorted].span(),
+      CODE,
+      message,
+      fix,
+    );
+  }
+}
+
+impl<'c> Visit for SortTypeConstituentsVisitor<'c> {
+  fn visit_ts_union_type(
+    &mut self,
+    union_type: &TsUnionType,
+    _parent: &dyn Node,
+  ) {
+    self.check_constituents(
+      &union_type.types,
+      SortTypeConstituentsMessage::Union,
+    );
+    union_type.visit_children_with(self);
+  }
+
+  fn visit_ts_intersection_type(
+    &mut self,
+    intersection_type: &TsIntersectionType,
+    _parent: &dyn Node,
+  ) {
+    self.check_constituents(
+      &intersection_type.types,
+      SortTypeConstituentsMessage::Intersection,
+    );
+    intersection_type.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+
+  #[test]
+  fn sort_type_constituents_valid() {
+    assert_lint_ok! {
+      SortTypeConstituents,
+      "type T = boolean | number | string;",
+      "type T = Foo | \"a\" | \"b\";",
+      "type T = A & B & C;",
+      "function f(a: A | B, b: C & D) {}",
+    };
+  }
+
+  #[test]
+  fn sort_type_constituents_invalid() {
+    assert_lint_err! {
+      SortTypeConstituents,
+      "type T = string | boolean | number;": [
+        {
+          col: 9,
+          message: SortTypeConstituentsMessage::Union,
+        }
+      ],
+      "type T = \"a\" | Foo;": [
+        {
+          col: 9,
+          message: SortTypeConstituentsMessage::Union,
+        }
+      ],
+      "let x: A & C & B;": [
+        {
+          col: 11,
+          message: SortTypeConstituentsMessage::Intersection,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn sort_type_constituents_fix() {
+    assert_lint_fix::<SortTypeConstituents>(
+      "type T = string | boolean | number;",
+      "type T = boolean | number | string;",
+    );
+    assert_lint_fix::<SortTypeConstituents>(
+      "type T = \"b\" | \"a\" | Foo;",
+      "type T = Foo | \"a\" | \"b\";",
+    );
+    assert_lint_fix::<SortTypeConstituents>(
+      "let x: A & C & B;",
+      "let x: A & B & C;",
+    );
+  }
+
+  #[test]
+  fn sort_type_constituents_literals_not_last() {
+    let options = SortTypeConstituentsOptions {
+      literals_last: false,
+    };
+    assert_lint_ok_with_rule(
+      SortTypeConstituents::with_options(options.clone()),
+      "type T = \"a\" | Foo;",
+    );
+    assert_lint_fix_with_rule(
+      SortTypeConstituents::with_options(options),
+      "type T = Foo | \"a\";",
+      "type T = \"a\" | Foo;",
+    );
+  }
+}
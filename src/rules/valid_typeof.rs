@@ -1,5 +1,6 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
 use swc_common::Spanned;
 use swc_ecmascript::ast::BinaryOp::{EqEq, EqEqEq, NotEq, NotEqEq};
 use swc_ecmascript::ast::Expr::{Lit, Unary};
@@ -13,6 +14,26 @@ pub struct ValidTypeof;
 const CODE: &str = "valid-typeof";
 const MESSAGE: &str = "Invalid typeof comparison value";
 
+/// The strings the `typeof` operator can return.
+const TYPEOF_STRINGS: &[&str] = &[
+  "undefined",
+  "object",
+  "boolean",
+  "number",
+  "string",
+  "function",
+  "symbol",
+  "bigint",
+];
+
+fn get_hint(suggestion: &str) -> String {
+  format!("Did you mean \"{}\"?", suggestion)
+}
+
+fn get_fix_description(suggestion: &str) -> String {
+  format!("Replace with \"{}\"", suggestion)
+}
+
 impl LintRule for ValidTypeof {
   fn new() -> Box<Self> {
     Box::new(ValidTypeof)
@@ -96,6 +117,42 @@ impl<'c> ValidTypeofVisitor<'c> {
   fn new(context: &'c mut Context) -> Self {
     Self { context }
   }
+
+  fn check_str(&mut self, str: &swc_ecmascript::ast::Str) {
+    if is_valid_typeof_string(&str.value) {
+      return;
+    }
+    let suggestion = match suggest_typeof_string(&str.value) {
+      Some(suggestion) => suggestion,
+      None => {
+        self.context.add_diagnostic(str.span, CODE, MESSAGE);
+        return;
+      }
+    };
+    // Keep the quotes the string was written with
+    let quote = self
+      .context
+      .source_map
+      .span_to_snippet(str.span)
+      .ok()
+      .and_then(|snippet| snippet.chars().next())
+      .unwrap_or('"');
+    let fix = LintFix {
+      description: get_fix_description(suggestion),
+      changes: vec![self.context.create_fix_change(
+        str.span,
+        format!("{}{}{}", quote, suggestion, quote),
+      )],
+      priority: 0,
+    };
+    self.context.add_diagnostic_with_hint_and_fix(
+      str.span,
+      CODE,
+      MESSAGE,
+      get_hint(suggestion),
+      fix,
+    );
+  }
 }
 
 impl<'c> Visit for ValidTypeofVisitor<'c> {
@@ -112,11 +169,7 @@ impl<'c> Visit for ValidTypeofVisitor<'c> {
       {
         match operand {
           Unary(unary) if unary.op == TypeOf => {}
-          Lit(Str(str)) => {
-            if !is_valid_typeof_string(&str.value) {
-              self.context.add_diagnostic(str.span, CODE, MESSAGE);
-            }
-          }
+          Lit(Str(str)) => self.check_str(str),
           _ => {
             self.context.add_diagnostic(operand.span(), CODE, MESSAGE);
           }
@@ -128,17 +181,34 @@ impl<'c> Visit for ValidTypeofVisitor<'c> {
 }
 
 fn is_valid_typeof_string(str: &str) -> bool {
-  matches!(
-    str,
-    "undefined"
-      | "object"
-      | "boolean"
-      | "number"
-      | "string"
-      | "function"
-      | "symbol"
-      | "bigint"
-  )
+  TYPEOF_STRINGS.contains(&str)
+}
+
+/// Returns the `typeof` string closest to `str`, if it's close enough to be
+/// a likely typo.
+fn suggest_typeof_string(str: &str) -> Option<&'static str> {
+  TYPEOF_STRINGS
+    .iter()
+    .map(|candidate| (levenshtein_distance(str, candidate), *candidate))
+    .filter(|(distance, _)| *distance <= 2)
+    .min_by_key(|(distance, _)| *distance)
+    .map(|(_, candidate)| candidate)
+}
+
+/// Number of single character insertions, deletions and substitutions
+/// needed to turn `a` into `b`.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+  let b: Vec<char> = b.chars().collect();
+  let mut prev_row: Vec<usize> = (0..=b.len()).collect();
+  for (i, a_char) in a.chars().enumerate() {
+    let mut row = vec![i + 1; b.len() + 1];
+    for (j, b_char) in b.iter().enumerate() {
+      let substitution = prev_row[j] + if a_char == *b_char { 0 } else { 1 };
+      row[j + 1] = substitution.min(prev_row[j + 1] + 1).min(row[j] + 1);
+    }
+    prev_row = row;
+  }
+  prev_row[b.len()]
 }
 
 trait EqExpr {
@@ -154,6 +224,7 @@ impl EqExpr for BinExpr {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::test_util::*;
 
   #[test]
   fn valid_typeof_valid() {
@@ -173,20 +244,33 @@ typeof bar == "undefined"
       ValidTypeof,
       r#"typeof foo === "strnig""#: [{
         col: 15,
-        message: MESSAGE
+        message: MESSAGE,
+        hint: get_hint("string"),
       }],
       r#"typeof foo == "undefimed""#: [{
         col: 14,
-        message: MESSAGE
+        message: MESSAGE,
+        hint: get_hint("undefined"),
       }],
       r#"typeof bar != "nunber""#: [{
         col: 14,
-        message: MESSAGE
+        message: MESSAGE,
+        hint: get_hint("number"),
       }],
       r#"typeof bar !== "fucntion""#: [{
+        col: 15,
+        message: MESSAGE,
+        hint: get_hint("function"),
+      }],
+      r#"typeof bar === "zzz""#: [{
         col: 15,
         message: MESSAGE
       }],
+      r#"typeof bar === "Object""#: [{
+        col: 15,
+        message: MESSAGE,
+        hint: get_hint("object"),
+      }],
       r#"typeof foo === undefined"#: [{
         col: 15,
         message: MESSAGE
@@ -201,4 +285,33 @@ typeof bar == "undefined"
       }],
     }
   }
+
+  #[test]
+  fn valid_typeof_fix() {
+    assert_lint_fix::<ValidTypeof>(
+      "typeof foo === 'strnig'",
+      "typeof foo === 'string'",
+    );
+    assert_lint_fix::<ValidTypeof>(
+      r#"if ("fucntion" !== typeof bar) {}"#,
+      r#"if ("function" !== typeof bar) {}"#,
+    );
+  }
+
+  #[test]
+  fn valid_typeof_suggestion() {
+    assert_eq!(suggest_typeof_string("strnig"), Some("string"));
+    assert_eq!(suggest_typeof_string("bigInt"), Some("bigint"));
+    assert_eq!(suggest_typeof_string("zzz"), None);
+    assert_eq!(suggest_typeof_string(""), None);
+  }
+
+  #[test]
+  fn levenshtein_distance_test() {
+    assert_eq!(levenshtein_distance("", ""), 0);
+    assert_eq!(levenshtein_distance("abc", ""), 3);
+    assert_eq!(levenshtein_distance("", "abc"), 3);
+    assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+    assert_eq!(levenshtein_distance("fucntion", "function"), 2);
+  }
 }
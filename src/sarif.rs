@@ -0,0 +1,282 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+
+//! Conversion of lint results to the SARIF 2.1.0 format, which is understood
+//! by GitHub code scanning and other static analysis dashboards.
+use crate::diagnostic::{FileDiagnostics, LintDiagnostic, Range, Severity};
+use crate::rules::{get_all_rules, LintRule};
+use serde_json::{json, Value};
+
+const SARIF_SCHEMA: &str = "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+pub fn to_sarif(results: &[FileDiagnostics]) -> Value {
+  let all_rules = get_all_rules();
+  // Rule codes in order of first appearance, `ruleIndex` of each result
+  // points into this list.
+  let mut rule_codes: Vec<&str> = vec![];
+  let mut sarif_results = vec![];
+
+  for file in results {
+    for diagnostic in &file.diagnostics {
+      let rule_index =
+        match rule_codes.iter().position(|c| *c == diagnostic.code) {
+          Some(index) => index,
+          None => {
+            rule_codes.push(&diagnostic.code);
+            rule_codes.len() - 1
+          }
+        };
+      sarif_results.push(to_result(&file.filename, diagnostic, rule_index));
+    }
+  }
+
+  let rules: Vec<Value> = rule_codes
+    .iter()
+    .map(|code| {
+      let maybe_rule = all_rules
+        .iter()
+        .find(|r| r.code() == *code)
+        .map(|r| r.as_ref());
+      to_reporting_descriptor(code, maybe_rule)
+    })
+    .collect();
+
+  json!({
+    "$schema": SARIF_SCHEMA,
+    "version": SARIF_VERSION,
+    "runs": [{
+      "tool": {
+        "driver": {
+          "name": "deno_lint",
+          "version": env!("CARGO_PKG_VERSION"),
+          "informationUri": "https://lint.deno.land",
+          "rules": rules
+        }
+      },
+      "results": sarif_results
+    }]
+  })
+}
+
+fn to_reporting_descriptor(
+  code: &str,
+  maybe_rule: Option<&dyn LintRule>,
+) -> Value {
+  // Codes reported by plugins have no builtin rule to describe them.
+  let rule = match maybe_rule {
+    Some(rule) => rule,
+    None => return json!({ "id": code }),
+  };
+
+  let mut descriptor = json!({
+    "id": code,
+    "helpUri": format!("https://lint.deno.land/#{}", code),
+    "defaultConfiguration": {
+      "level": to_level(rule.severity())
+    }
+  });
+  if !rule.docs().is_empty() {
+    descriptor["help"] = json!({
+      "text": rule.docs(),
+      "markdown": rule.docs()
+    });
+  }
+  descriptor
+}
+
+fn to_result(
+  filename: &str,
+  diagnostic: &LintDiagnostic,
+  rule_index: usize,
+) -> Value {
+  let message = match &diagnostic.hint {
+    Some(hint) => format!("{}\n{}", diagnostic.message, hint),
+    None => diagnostic.message.clone(),
+  };
+
+  let mut result = json!({
+    "ruleId": diagnostic.code,
+    "ruleIndex": rule_index,
+    "level": to_level(diagnostic.severity),
+    "message": {
+      "text": message
+    },
+    "locations": [{
+      "physicalLocation": to_physical_location(filename, &diagnostic.range)
+    }]
+  });
+  if !diagnostic.related.is_empty() {
+    let related_locations: Vec<Value> = diagnostic
+      .related
+      .iter()
+      .enumerate()
+      .map(|(id, related)| {
+        json!({
+          "id": id,
+          "physicalLocation": to_physical_location(filename, &related.range),
+          "message": {
+            "text": related.message
+          }
+        })
+      })
+      .collect();
+    result["relatedLocations"] = json!(related_locations);
+  }
+  result
+}
+
+fn to_physical_location(filename: &str, range: &Range) -> Value {
+  let start = &range.start;
+  let end = &range.end;
+  json!({
+    "artifactLocation": {
+      "uri": filename
+    },
+    "region": {
+      "startLine": start.line,
+      // SARIF columns are 1-based, deno_lint columns are 0-based.
+      "startColumn": start.col + 1,
+      "endLine": end.line,
+      "endColumn": end.col + 1,
+      "byteOffset": start.byte_pos,
+      "byteLength": end.byte_pos - start.byte_pos
+    }
+  })
+}
+
+fn to_level(severity: Severity) -> &'static str {
+  match severity {
+    Severity::Error => "error",
+    Severity::Warning => "warning",
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::diagnostic::{Position, RelatedInformation};
+
+  fn diagnostic(
+    code: &str,
+    start: (usize, usize, usize),
+    end: (usize, usize, usize),
+  ) -> LintDiagnostic {
+    let (line, col, byte_pos) = start;
+    let start = Position {
+      line,
+      col,
+      byte_pos,
+    };
+    let (line, col, byte_pos) = end;
+    let end = Position {
+      line,
+      col,
+      byte_pos,
+    };
+    LintDiagnostic {
+      range: Range { start, end },
+      filename: "foo.ts".to_string(),
+      message: "`debugger` statement is not allowed".to_string(),
+      code: code.to_string(),
+      hint: None,
+      severity: Severity::Error,
+      fix: None,
+      related: vec![],
+    }
+  }
+
+  #[test]
+  fn sarif_document() {
+    let results = vec![FileDiagnostics {
+      filename: "foo.ts".to_string(),
+      diagnostics: vec![diagnostic("no-debugger", (2, 2, 14), (2, 11, 23))],
+    }];
+    let sarif = to_sarif(&results);
+
+    assert_eq!(sarif["$schema"], SARIF_SCHEMA);
+    assert_eq!(sarif["version"], "2.1.0");
+
+    let run = &sarif["runs"][0];
+    assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "no-debugger");
+
+    let result = &run["results"][0];
+    assert_eq!(result["ruleId"], "no-debugger");
+    assert_eq!(result["ruleIndex"], 0);
+    assert_eq!(result["level"], "error");
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "foo.ts");
+    let region = &location["region"];
+    assert_eq!(region["startLine"], 2);
+    assert_eq!(region["startColumn"], 3);
+    assert_eq!(region["endLine"], 2);
+    assert_eq!(region["endColumn"], 12);
+    assert_eq!(region["byteOffset"], 14);
+    assert_eq!(region["byteLength"], 9);
+  }
+
+  #[test]
+  fn sarif_rules_are_deduplicated() {
+    let results = vec![
+      FileDiagnostics {
+        filename: "foo.ts".to_string(),
+        diagnostics: vec![diagnostic("no-debugger", (1, 0, 0), (1, 9, 9))],
+      },
+      FileDiagnostics {
+        filename: "bar.ts".to_string(),
+        diagnostics: vec![
+          diagnostic("my-plugin-rule", (1, 0, 0), (1, 3, 3)),
+          diagnostic("no-debugger", (2, 0, 4), (2, 9, 13)),
+        ],
+      },
+    ];
+    let sarif = to_sarif(&results);
+
+    let run = &sarif["runs"][0];
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[1], json!({ "id": "my-plugin-rule" }));
+    assert_eq!(run["results"][2]["ruleIndex"], 0);
+    assert_eq!(
+      run["results"][2]["locations"][0]["physicalLocation"]["artifactLocation"]
+        ["uri"],
+      "bar.ts"
+    );
+  }
+
+  #[test]
+  fn sarif_related_locations() {
+    let mut diagnostic = diagnostic("no-redeclare", (3, 4, 30), (3, 7, 33));
+    diagnostic.related.push(RelatedInformation {
+      range: Range {
+        start: Position {
+          line: 1,
+          col: 4,
+          byte_pos: 4,
+        },
+        end: Position {
+          line: 1,
+          col: 7,
+          byte_pos: 7,
+        },
+      },
+      message: "first declared here".to_string(),
+    });
+    let results = vec![FileDiagnostics {
+      filename: "foo.ts".to_string(),
+      diagnostics: vec![diagnostic],
+    }];
+    let sarif = to_sarif(&results);
+
+    let result = &sarif["runs"][0]["results"][0];
+    let region = &result["locations"][0]["physicalLocation"]["region"];
+    assert_eq!(region["startLine"], 3);
+    assert_eq!(region["startColumn"], 5);
+    let related = &result["relatedLocations"][0];
+    assert_eq!(related["id"], 0);
+    assert_eq!(related["message"]["text"], "first declared here");
+    let region = &related["physicalLocation"]["region"];
+    assert_eq!(region["startLine"], 1);
+    assert_eq!(region["startColumn"], 5);
+    assert_eq!(region["byteLength"], 3);
+  }
+}
@@ -1,10 +1,14 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use crate::linter::Context;
 use crate::scopes::Scope;
+use swc_common::{BytePos, Span};
 use swc_ecmascript::ast::{
-  ComputedPropName, Expr, ExprOrSpread, Ident, Lit, MemberExpr, PatOrExpr,
-  PrivateName, Prop, PropName, PropOrSpread, Str, Tpl,
+  BlockStmt, ComputedPropName, Expr, ExprOrSpread, Ident, Lit, MemberExpr,
+  Module, ModuleItem, PatOrExpr, PrivateName, Program, Prop, PropName,
+  PropOrSpread, Script, Stmt, Str, SwitchCase, Tpl,
 };
 use swc_ecmascript::utils::{find_ids, ident::IdentLike};
+use swc_ecmascript::visit::{noop_visit_type, Node, Visit, VisitWith};
 
 /// Extracts regex string from an expression, using ScopeManager.
 /// If the passed expression is not regular expression, this will return `None`.
@@ -161,3 +165,121 @@ where
     PatOrExpr::Pat(p) => find_ids(p),
   }
 }
+
+/// Spans of all comments in the file, sorted by position.
+pub(crate) fn comment_spans(context: &Context) -> Vec<Span> {
+  let mut spans: Vec<Span> = context
+    .leading_comments
+    .values()
+    .chain(context.trailing_comments.values())
+    .flatten()
+    .map(|comment| comment.span)
+    .collect();
+  spans.sort_by_key(|span| span.lo());
+  spans.dedup();
+  spans
+}
+
+/// Returns the offsets in `text` of the lines that count toward a line limit,
+/// where `text` starts at `start` in the source. Blank lines and lines that
+/// only contain parts of the comments at `comment_spans` are optionally left
+/// out.
+pub(crate) fn counted_lines(
+  text: &str,
+  start: BytePos,
+  comment_spans: &[Span],
+  skip_blank_lines: bool,
+  skip_comments: bool,
+) -> Vec<usize> {
+  let end = start + BytePos(text.len() as u32);
+
+  // Marks bytes of `text` that belong to a comment
+  let mut in_comment = vec![false; text.len()];
+  if skip_comments {
+    for comment_span in comment_spans {
+      if comment_span.hi() <= start || comment_span.lo() >= end {
+        continue;
+      }
+      let lo = comment_span.lo().0.saturating_sub(start.0) as usize;
+      let hi = (comment_span.hi().0 - start.0) as usize;
+      for flag in &mut in_comment[lo..hi.min(text.len())] {
+        *flag = true;
+      }
+    }
+  }
+
+  let mut lines = vec![];
+  let mut offset = 0;
+  // A trailing newline doesn't start another line
+  let text = text.strip_suffix('\n').unwrap_or(text);
+  for line in text.split('\n') {
+    let is_blank = line.trim().is_empty();
+    let is_comment = !is_blank
+      && line
+        .char_indices()
+        .all(|(i, c)| c.is_whitespace() || in_comment[offset + i]);
+
+    if !(skip_blank_lines && is_blank) && !(skip_comments && is_comment) {
+      lines.push(offset);
+    }
+    offset += line.len() + 1;
+  }
+  lines
+}
+
+/// Calls `f` with every list of statements in `program`, outer lists first:
+/// the body of the module or script, of each block and of each switch case.
+/// Items that aren't statements, like imports, are passed as `None`.
+pub(crate) fn visit_stmt_lists<F>(program: &Program, f: F)
+where
+  F: FnMut(&[Option<&Stmt>]),
+{
+  let mut visitor = StmtListVisitor { f };
+  visitor.visit_program(program, program);
+}
+
+struct StmtListVisitor<F> {
+  f: F,
+}
+
+impl<F> Visit for StmtListVisitor<F>
+where
+  F: FnMut(&[Option<&Stmt>]),
+{
+  noop_visit_type!();
+
+  fn visit_module(&mut self, module: &Module, _parent: &dyn Node) {
+    let stmts: Vec<_> = module
+      .body
+      .iter()
+      .map(|item| match item {
+        ModuleItem::Stmt(stmt) => Some(stmt),
+        ModuleItem::ModuleDecl(_) => None,
+      })
+      .collect();
+    (self.f)(&stmts);
+    module.visit_children_with(self);
+  }
+
+  fn visit_script(&mut self, script: &Script, _parent: &dyn Node) {
+    let stmts: Vec<_> = script.body.iter().map(Some).collect();
+    (self.f)(&stmts);
+    script.visit_children_with(self);
+  }
+
+  fn visit_block_stmt(&mut self, block_stmt: &BlockStmt, _parent: &dyn Node) {
+    let stmts: Vec<_> = block_stmt.stmts.iter().map(Some).collect();
+    (self.f)(&stmts);
+    block_stmt.visit_children_with(self);
+  }
+
+  fn visit_switch_case(
+    &mut self,
+    switch_case: &SwitchCase,
+    _parent: &dyn Node,
+  ) {
+    let stmts: Vec<_> = switch_case.cons.iter().map(Some).collect();
+    (self.f)(&stmts);
+    switch_case.visit_children_with(self);
+  }
+}
@@ -1,11 +1,17 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
 
+//! Assertions for testing lint rules, used by the built-in rules and
+//! available to rules implemented in other crates. Each assertion lints the
+//! source as TypeScript with only the given rule enabled and panics on
+//! mismatch. The assertions ending with `_tsx` enable JSX as well.
+
 use crate::ast_parser;
-use crate::diagnostic::LintDiagnostic;
+use crate::diagnostic::{LintDiagnostic, LintFixChange};
 use crate::linter::LinterBuilder;
 use crate::rules::LintRule;
 use std::marker::PhantomData;
 use swc_ecmascript::ast::Program;
+use swc_ecmascript::parser::Syntax;
 
 #[macro_export]
 macro_rules! assert_lint_ok {
@@ -159,10 +165,18 @@ impl<T: LintRule + 'static> LintErrTester<T> {
 }
 
 fn lint(rule: Box<dyn LintRule>, source: &str) -> Vec<LintDiagnostic> {
+  lint_with_syntax(rule, source, ast_parser::get_default_ts_config())
+}
+
+fn lint_with_syntax(
+  rule: Box<dyn LintRule>,
+  source: &str,
+  syntax: Syntax,
+) -> Vec<LintDiagnostic> {
   let mut linter = LinterBuilder::default()
     .lint_unused_ignore_directives(false)
     .lint_unknown_rules(false)
-    .syntax(ast_parser::get_default_ts_config())
+    .syntax(syntax)
     .rules(vec![rule])
     .build();
 
@@ -236,9 +250,24 @@ fn assert_diagnostic_2(
   );
 }
 
+/// Asserts that the rule reports nothing for `source`.
 pub fn assert_lint_ok<T: LintRule + 'static>(source: &str) {
-  let rule = T::new();
-  let diagnostics = lint(rule, source);
+  assert_lint_ok_with_rule(T::new(), source)
+}
+
+/// Same as `assert_lint_ok`, but runs the given rule instance. Useful for
+/// rules constructed with non-default options.
+pub fn assert_lint_ok_with_rule(rule: Box<dyn LintRule>, source: &str) {
+  assert_no_diagnostics(lint(rule, source), source)
+}
+
+/// Same as `assert_lint_ok`, but parses `source` with JSX enabled.
+pub fn assert_lint_ok_tsx<T: LintRule + 'static>(source: &str) {
+  let syntax = ast_parser::get_default_tsx_config();
+  assert_no_diagnostics(lint_with_syntax(T::new(), source, syntax), source)
+}
+
+fn assert_no_diagnostics(diagnostics: Vec<LintDiagnostic>, source: &str) {
   if !diagnostics.is_empty() {
     panic!(
       "Unexpected diagnostics found:\n{:#?}\n\nsource:\n{}\n",
@@ -247,14 +276,29 @@ pub fn assert_lint_ok<T: LintRule + 'static>(source: &str) {
   }
 }
 
+/// Asserts that the rule reports exactly one diagnostic, on the first line
+/// at column `col`.
 pub fn assert_lint_err<T: LintRule + 'static>(source: &str, col: usize) {
   assert_lint_err_on_line::<T>(source, 1, col)
 }
 
+/// Asserts that the rule reports exactly one diagnostic, at `line` (1-based)
+/// and column `col` (0-based).
 pub fn assert_lint_err_on_line<T: LintRule + 'static>(
   source: &str,
   line: usize,
   col: usize,
+) {
+  assert_lint_err_on_line_with_rule(T::new(), source, line, col)
+}
+
+/// Same as `assert_lint_err_on_line`, but also checks the message of the
+/// diagnostic.
+pub fn assert_lint_err_with_message<T: LintRule + 'static>(
+  source: &str,
+  line: usize,
+  col: usize,
+  message: &str,
 ) {
   let rule = T::new();
   let rule_code = rule.code();
@@ -267,6 +311,53 @@ pub fn assert_lint_err_on_line<T: LintRule + 'static>(
     source
   );
   assert_diagnostic(&diagnostics[0], rule_code, line, col, source);
+  assert_eq!(
+    message, &diagnostics[0].message,
+    "Diagnostic message is expected to be \"{}\", but got \"{}\"\n\nsource:\n{}\n",
+    message, &diagnostics[0].message, source
+  );
+}
+
+/// Same as `assert_lint_err_on_line`, but runs the given rule instance.
+pub fn assert_lint_err_on_line_with_rule(
+  rule: Box<dyn LintRule>,
+  source: &str,
+  line: usize,
+  col: usize,
+) {
+  let rule_code = rule.code();
+  let diagnostics = lint(rule, source);
+  assert_one_diagnostic(&diagnostics, rule_code, source, line, col);
+}
+
+/// Same as `assert_lint_err_on_line`, but parses `source` with JSX enabled.
+pub fn assert_lint_err_on_line_tsx<T: LintRule + 'static>(
+  source: &str,
+  line: usize,
+  col: usize,
+) {
+  let rule = T::new();
+  let rule_code = rule.code();
+  let syntax = ast_parser::get_default_tsx_config();
+  let diagnostics = lint_with_syntax(rule, source, syntax);
+  assert_one_diagnostic(&diagnostics, rule_code, source, line, col);
+}
+
+fn assert_one_diagnostic(
+  diagnostics: &[LintDiagnostic],
+  rule_code: &str,
+  source: &str,
+  line: usize,
+  col: usize,
+) {
+  assert_eq!(
+    diagnostics.len(),
+    1,
+    "1 diagnostic expected, but got {}.\n\nsource:\n{}\n",
+    diagnostics.len(),
+    source
+  );
+  assert_diagnostic(&diagnostics[0], rule_code, line, col, source);
 }
 
 pub fn assert_lint_err_n<T: LintRule + 'static>(
@@ -301,6 +392,60 @@ pub fn assert_lint_err_on_line_n<T: LintRule + 'static>(
   }
 }
 
+/// Asserts that applying the fixes of all diagnostics reported by the rule
+/// turns `source` into `expected`.
+pub fn assert_lint_fix<T: LintRule + 'static>(source: &str, expected: &str) {
+  assert_lint_fix_with_rule(T::new(), source, expected)
+}
+
+/// Same as `assert_lint_fix`, but runs the given rule instance.
+pub fn assert_lint_fix_with_rule(
+  rule: Box<dyn LintRule>,
+  source: &str,
+  expected: &str,
+) {
+  assert_fixed(&lint(rule, source), source, expected)
+}
+
+/// Same as `assert_lint_fix`, but parses `source` with JSX enabled.
+pub fn assert_lint_fix_tsx<T: LintRule + 'static>(
+  source: &str,
+  expected: &str,
+) {
+  let syntax = ast_parser::get_default_tsx_config();
+  let diagnostics = lint_with_syntax(T::new(), source, syntax);
+  assert_fixed(&diagnostics, source, expected)
+}
+
+fn assert_fixed(diagnostics: &[LintDiagnostic], source: &str, expected: &str) {
+  let mut changes: Vec<&LintFixChange> = diagnostics
+    .iter()
+    .filter_map(|d| d.fix.as_ref())
+    .flat_map(|fix| fix.changes.iter())
+    .collect();
+  changes.sort_by_key(|c| c.range.start.byte_pos);
+
+  let mut fixed = String::new();
+  let mut last_pos = 0;
+  for change in changes {
+    assert!(
+      last_pos <= change.range.start.byte_pos,
+      "Overlapping fixes found.\n\nsource:\n{}\n",
+      source
+    );
+    fixed.push_str(&source[last_pos..change.range.start.byte_pos]);
+    fixed.push_str(&change.new_text);
+    last_pos = change.range.end.byte_pos;
+  }
+  fixed.push_str(&source[last_pos..]);
+
+  assert_eq!(
+    expected, fixed,
+    "Fixed source is expected to be \"{}\", but got \"{}\"\n\nsource:\n{}\n",
+    expected, fixed, source
+  );
+}
+
 pub fn parse(source_code: &str) -> Program {
   let ast_parser = ast_parser::AstParser::new();
   let syntax = ast_parser::get_default_ts_config();
@@ -309,3 +454,47 @@ pub fn parse(source_code: &str) -> Program {
     .unwrap();
   program
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::rules::no_debugger::NoDebugger;
+
+  #[test]
+  fn lint_err_on_line() {
+    assert_lint_err_on_line::<NoDebugger>("debugger;", 1, 0);
+    assert_lint_err_on_line::<NoDebugger>(
+      "function f() {\n  if (x) {\n    debugger;\n  }\n}",
+      3,
+      4,
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "to be no-debugger at 1:4")]
+  fn lint_err_on_line_wrong_line() {
+    assert_lint_err_on_line::<NoDebugger>("let a;\n    debugger;", 1, 4);
+  }
+
+  #[test]
+  #[should_panic(expected = "1 diagnostic expected, but got 0")]
+  fn lint_err_on_line_no_diagnostic() {
+    assert_lint_err_on_line::<NoDebugger>("let a;", 1, 0);
+  }
+
+  #[test]
+  fn lint_err_with_message() {
+    assert_lint_err_with_message::<NoDebugger>(
+      "let a;\ndebugger;",
+      2,
+      0,
+      "`debugger` statement is not allowed",
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "Diagnostic message is expected to be")]
+  fn lint_err_with_wrong_message() {
+    assert_lint_err_with_message::<NoDebugger>("debugger;", 1, 0, "foo");
+  }
+}
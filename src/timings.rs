@@ -0,0 +1,57 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+
+//! Time spent in each rule, for finding the rules that slow a lint run down.
+use std::time::Duration;
+
+/// Code under which the time spent running plugins is recorded.
+pub const PLUGIN_TIMING_CODE: &str = "plugin";
+
+/// Total time spent in each rule, summed over every file linted by the
+/// linters it's set on with `LinterBuilder::rule_timings`.
+#[derive(Clone, Debug, Default)]
+pub struct RuleTimings {
+  /// In the order the rules were first timed.
+  timings: Vec<(String, Duration)>,
+}
+
+impl RuleTimings {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds `duration` to the time spent in the rule with `code`.
+  pub fn add(&mut self, code: &str, duration: Duration) {
+    match self.timings.iter_mut().find(|(c, _)| c == code) {
+      Some((_, total)) => *total += duration,
+      None => self.timings.push((code.to_string(), duration)),
+    }
+  }
+
+  /// Returns the code and total time of every timed rule, slowest first.
+  /// Plugins are listed under `PLUGIN_TIMING_CODE`.
+  pub fn report(&self) -> Vec<(String, Duration)> {
+    let mut report = self.timings.clone();
+    report.sort_by(|(_, a), (_, b)| b.cmp(a));
+    report
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn rule_timings_report() {
+    let mut timings = RuleTimings::new();
+    timings.add("no-var", Duration::from_millis(2));
+    timings.add("no-debugger", Duration::from_millis(3));
+    timings.add("no-var", Duration::from_millis(2));
+    assert_eq!(
+      timings.report(),
+      vec![
+        ("no-var".to_string(), Duration::from_millis(4)),
+        ("no-debugger".to_string(), Duration::from_millis(3)),
+      ]
+    );
+  }
+}
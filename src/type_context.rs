@@ -0,0 +1,147 @@
+// Copyright 2020 the Deno authors. All rights reserved. MIT license.
+use swc_common::{Span, Spanned, DUMMY_SP};
+use swc_ecmascript::ast::{
+  Invalid, Program, TsExprWithTypeArgs, TsInterfaceDecl, TsType,
+  TsTypeAliasDecl, TsTypeParamDecl, TsTypeParamInstantiation,
+};
+use swc_ecmascript::visit::{Node, Visit, VisitWith};
+
+/// Spans of the TypeScript type-only parts of a program, like type
+/// annotations, type arguments and interface declarations.
+#[derive(Debug, Clone)]
+pub(crate) struct TypeContext {
+  /// Outermost type-only spans, which don't overlap, sorted by position.
+  spans: Vec<Span>,
+}
+
+impl TypeContext {
+  pub(crate) fn analyze(program: &Program) -> Self {
+    let mut collector = TypeSpanCollector { spans: Vec::new() };
+    program.visit_with(&Invalid { span: DUMMY_SP }, &mut collector);
+    let mut spans = collector.spans;
+    spans.sort_by_key(|span| span.lo);
+    TypeContext { spans }
+  }
+
+  /// Returns true if `span` is inside a type-only part of the program.
+  pub(crate) fn contains(&self, span: Span) -> bool {
+    // The last span starting at or before `span` is the only one that can
+    // contain it
+    let index = match self.spans.binary_search_by_key(&span.lo, |s| s.lo) {
+      Ok(index) => index,
+      Err(0) => return false,
+      Err(index) => index - 1,
+    };
+    span.hi <= self.spans[index].hi
+  }
+}
+
+struct TypeSpanCollector {
+  spans: Vec<Span>,
+}
+
+// Nested types are covered by the span of the outermost one, so the children
+// of the nodes recorded here aren't visited
+impl Visit for TypeSpanCollector {
+  fn visit_ts_type(&mut self, ts_type: &TsType, _parent: &dyn Node) {
+    self.spans.push(ts_type.span());
+  }
+
+  // `implements` clauses of classes
+  fn visit_ts_expr_with_type_args(
+    &mut self,
+    expr: &TsExprWithTypeArgs,
+    _parent: &dyn Node,
+  ) {
+    self.spans.push(expr.span);
+  }
+
+  fn visit_ts_interface_decl(
+    &mut self,
+    interface_decl: &TsInterfaceDecl,
+    _parent: &dyn Node,
+  ) {
+    self.spans.push(interface_decl.span);
+  }
+
+  fn visit_ts_type_alias_decl(
+    &mut self,
+    type_alias_decl: &TsTypeAliasDecl,
+    _parent: &dyn Node,
+  ) {
+    self.spans.push(type_alias_decl.span);
+  }
+
+  fn visit_ts_type_param_decl(
+    &mut self,
+    type_param_decl: &TsTypeParamDecl,
+    _parent: &dyn Node,
+  ) {
+    self.spans.push(type_param_decl.span);
+  }
+
+  fn visit_ts_type_param_instantiation(
+    &mut self,
+    type_args: &TsTypeParamInstantiation,
+    _parent: &dyn Node,
+  ) {
+    self.spans.push(type_args.span);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::*;
+  use swc_ecmascript::ast::Ident;
+
+  /// Collects the spans of the identifiers named `Foo`.
+  struct FooFinder {
+    spans: Vec<Span>,
+  }
+
+  impl Visit for FooFinder {
+    fn visit_ident(&mut self, ident: &Ident, _parent: &dyn Node) {
+      if ident.sym == *"Foo" {
+        self.spans.push(ident.span);
+      }
+      ident.visit_children_with(self);
+    }
+  }
+
+  /// Returns whether each `Foo` in `src` is in a type, in source order.
+  fn analyze(src: &str) -> Vec<bool> {
+    let program = parse(src);
+    let type_context = TypeContext::analyze(&program);
+    let mut finder = FooFinder { spans: Vec::new() };
+    program.visit_with(&Invalid { span: DUMMY_SP }, &mut finder);
+    // Nodes aren't always visited in source order, e.g. the type parameters
+    // of a function come after its body
+    finder.spans.sort_by_key(|span| span.lo());
+    finder
+      .spans
+      .into_iter()
+      .map(|span| type_context.contains(span))
+      .collect()
+  }
+
+  #[test]
+  fn type_and_value_positions() {
+    assert_eq!(
+      analyze("const Foo = 1;\nlet a: Foo = Foo;"),
+      vec![false, true, false]
+    );
+    assert_eq!(
+      analyze("function f<T extends Foo>(x: Foo): Foo { return Foo; }"),
+      vec![true, true, true, false]
+    );
+    assert_eq!(
+      analyze("interface A { a: Foo }\ntype B = Foo[];\nf<Foo>(Foo);"),
+      vec![true, true, true, false]
+    );
+    assert_eq!(
+      analyze("class A extends Foo implements Foo {}"),
+      vec![false, true]
+    );
+  }
+}